@@ -0,0 +1,379 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::vec::IntoIter;
+
+use crate::node::{drop_tree_iteratively, OwnedChildren};
+
+type ByHeapNode<T> = Option<Box<ByNode<T>>>;
+type Comparator<T> = Box<dyn Fn(&T, &T) -> Ordering>;
+
+#[derive(Debug)]
+struct ByNode<T> {
+    value: T,
+    left: ByHeapNode<T>,
+    right: ByHeapNode<T>,
+}
+
+/// Binary Search Tree ordered by a **user-supplied comparator** instead of [Ord].
+///
+/// This allows elements that do not implement [Ord] to be stored, or elements that do to be
+/// ordered differently, e.g. strings compared case-insensitively or structs compared by a single
+/// field. Two elements are considered duplicates whenever the comparator returns
+/// [Ordering::Equal] for them.
+///
+/// Comparing two trees through [PartialEq] only considers the elements in the order of their
+/// own comparators, so comparing trees built with **different comparators** is unspecified.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::BinarySearchTreeBy;
+///
+/// let mut bst = BinarySearchTreeBy::new_by(|a: &&str, b: &&str| {
+///     a.to_lowercase().cmp(&b.to_lowercase())
+/// });
+/// bst.insert("banana");
+/// bst.insert("Apple");
+/// bst.insert("BANANA"); // Element is not inserted
+///
+/// assert_eq!(bst.size(), 2);
+/// assert!(bst.contains(&"apple"));
+/// assert_eq!(bst.asc_order_vec(), vec![&"Apple", &"banana"]);
+/// ```
+pub struct BinarySearchTreeBy<T> {
+    root: ByHeapNode<T>,
+    size: usize,
+    compare: Comparator<T>,
+}
+
+impl<T> BinarySearchTreeBy<T> {
+    /// Creates an empty `BinarySearchTreeBy<T>` ordered by the given comparator.
+    ///
+    /// No nodes are allocated on the heap yet
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bst_rs::BinarySearchTreeBy;
+    ///
+    /// // Empty tree is created, ordering the elements in descending order
+    /// let bst = BinarySearchTreeBy::new_by(|a: &i32, b: &i32| b.cmp(a));
+    /// assert!(bst.is_empty())
+    /// ```
+    pub fn new_by<F>(compare: F) -> BinarySearchTreeBy<T>
+    where
+        F: Fn(&T, &T) -> Ordering + 'static,
+    {
+        BinarySearchTreeBy {
+            root: None,
+            size: 0,
+            compare: Box::new(compare),
+        }
+    }
+
+    /// Returns the total **number of elements** within the tree.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the tree contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Inserts the given value into the tree.
+    ///
+    /// If the comparator considers the value equal to an existing element, the tree is left
+    /// unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BinarySearchTreeBy;
+    ///
+    /// let mut bst = BinarySearchTreeBy::new_by(|a: &(i32, char), b: &(i32, char)| a.0.cmp(&b.0));
+    /// bst.insert((1, 'a'));
+    /// bst.insert((1, 'b')); // Element is not inserted
+    ///
+    /// assert_eq!(bst.retrieve(&(1, 'z')), Some(&(1, 'a')));
+    /// ```
+    pub fn insert(&mut self, value: T) {
+        let mut root = &mut self.root;
+        while let Some(ref mut node) = root {
+            match (self.compare)(&value, &node.value) {
+                Ordering::Equal => return,
+                Ordering::Less => root = &mut node.left,
+                Ordering::Greater => root = &mut node.right,
+            }
+        }
+
+        *root = Some(Box::new(ByNode {
+            value,
+            left: None,
+            right: None,
+        }));
+        self.size += 1;
+    }
+
+    /// Returns `true` if the tree contains an element the comparator considers equal to the
+    /// given value.
+    pub fn contains(&self, value: &T) -> bool {
+        self.retrieve(value).is_some()
+    }
+
+    /// Removes the element the comparator considers equal to the given value and returns `true`
+    /// if it existed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BinarySearchTreeBy;
+    ///
+    /// let mut bst = BinarySearchTreeBy::new_by(|a: &i32, b: &i32| b.cmp(a));
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// assert!(bst.remove(&1));
+    /// assert!(!bst.remove(&1));
+    /// assert_eq!(bst.size(), 1);
+    /// ```
+    pub fn remove(&mut self, value: &T) -> bool {
+        let mut root = &mut self.root;
+        while let Some(ref mut current) = root {
+            match (self.compare)(value, &current.value) {
+                Ordering::Less => root = &mut root.as_mut().unwrap().left,
+                Ordering::Greater => root = &mut root.as_mut().unwrap().right,
+                Ordering::Equal => {
+                    match (current.left.as_mut(), current.right.as_mut()) {
+                        (None, None) => *root = None,
+                        (Some(_), None) => *root = current.left.take(),
+                        (None, Some(_)) => *root = current.right.take(),
+                        (Some(_), Some(_)) => {
+                            current.value = ByNode::remove_min(&mut current.right)
+                        }
+                    }
+                    self.size -= 1;
+
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Returns a reference to the element the comparator considers equal to the given value or
+    /// `None` if no such element exists.
+    pub fn retrieve(&self, value: &T) -> Option<&T> {
+        let mut root = &self.root;
+        while let Some(node) = root {
+            match (self.compare)(value, &node.value) {
+                Ordering::Equal => return Some(&node.value),
+                Ordering::Less => root = &node.left,
+                Ordering::Greater => root = &node.right,
+            }
+        }
+
+        None
+    }
+
+    /// Returns a reference to the minimum element of the tree, according to the comparator, or
+    /// `None` if tree is empty.
+    pub fn min(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(left) = node.left.as_ref() {
+            node = left;
+        }
+
+        Some(&node.value)
+    }
+
+    /// Returns a reference to the maximum element of the tree, according to the comparator, or
+    /// `None` if tree is empty.
+    pub fn max(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(right) = node.right.as_ref() {
+            node = right;
+        }
+
+        Some(&node.value)
+    }
+
+    /// Returns references to the elements of the tree in the **ascending order** of the
+    /// comparator.
+    pub fn asc_order_vec(&self) -> Vec<&T> {
+        let mut elements = Vec::with_capacity(self.size);
+        let mut stack = Vec::new();
+        let mut root = &self.root;
+
+        while root.is_some() || !stack.is_empty() {
+            while let Some(node) = root {
+                stack.push(node);
+                root = &node.left;
+            }
+            let node = stack.pop().unwrap();
+            elements.push(&node.value);
+            root = &node.right;
+        }
+
+        elements
+    }
+
+    /// Returns an iterator over the elements of the tree in the **ascending order** of the
+    /// comparator.
+    pub fn asc_order_iter(&self) -> IntoIter<&T> {
+        self.asc_order_vec().into_iter()
+    }
+}
+
+impl<T> ByNode<T> {
+    fn remove_min(mut root: &mut ByHeapNode<T>) -> T {
+        while root.as_ref().unwrap().left.is_some() {
+            root = &mut root.as_mut().unwrap().left
+        }
+
+        let node = root.take().unwrap();
+        *root = node.right;
+        node.value
+    }
+}
+
+impl<T> OwnedChildren for ByNode<T> {
+    fn take_children(&mut self) -> (ByHeapNode<T>, ByHeapNode<T>) {
+        (self.left.take(), self.right.take())
+    }
+}
+
+impl<T> Drop for BinarySearchTreeBy<T> {
+    fn drop(&mut self) {
+        drop_tree_iteratively(self.root.take());
+    }
+}
+
+impl<T: PartialEq> PartialEq for BinarySearchTreeBy<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.asc_order_iter().eq(other.asc_order_iter())
+    }
+}
+
+impl<T> Extend<T> for BinarySearchTreeBy<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter.into_iter() {
+            self.insert(value)
+        }
+    }
+}
+
+impl<T: Debug> Debug for BinarySearchTreeBy<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BinarySearchTreeBy")
+            .field("root", &self.root)
+            .field("size", &self.size)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Debug> Display for BinarySearchTreeBy<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.asc_order_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use super::BinarySearchTreeBy;
+
+    #[derive(Debug, PartialEq)]
+    struct Version {
+        major: u32,
+        label: &'static str,
+    }
+
+    fn by_major(a: &Version, b: &Version) -> Ordering {
+        a.major.cmp(&b.major)
+    }
+
+    #[test]
+    fn successfully_insert_with_comparator() {
+        let mut bst = BinarySearchTreeBy::new_by(by_major);
+        bst.insert(Version {
+            major: 2,
+            label: "two",
+        });
+        bst.insert(Version {
+            major: 1,
+            label: "one",
+        });
+        bst.insert(Version {
+            major: 3,
+            label: "three",
+        });
+        bst.insert(Version {
+            major: 2,
+            label: "duplicate",
+        });
+
+        assert_eq!(bst.size(), 3);
+        assert_eq!(
+            bst.asc_order_iter()
+                .map(|version| version.label)
+                .collect::<Vec<&str>>(),
+            vec!["one", "two", "three"]
+        );
+        assert_eq!(bst.min().map(|version| version.major), Some(1));
+        assert_eq!(bst.max().map(|version| version.major), Some(3));
+    }
+
+    #[test]
+    fn successfully_retrieve_and_remove_with_comparator() {
+        let mut bst = BinarySearchTreeBy::new_by(by_major);
+        bst.extend((1..=7).map(|major| Version {
+            major,
+            label: "release",
+        }));
+        let probe = Version {
+            major: 4,
+            label: "probe",
+        };
+
+        assert!(bst.contains(&probe));
+        assert_eq!(
+            bst.retrieve(&probe).map(|version| version.label),
+            Some("release")
+        );
+
+        assert!(bst.remove(&probe));
+        assert!(!bst.contains(&probe));
+        assert!(!bst.remove(&probe));
+        assert_eq!(bst.size(), 6);
+        assert_eq!(
+            bst.asc_order_iter()
+                .map(|version| version.major)
+                .collect::<Vec<u32>>(),
+            vec![1, 2, 3, 5, 6, 7]
+        );
+    }
+
+    #[test]
+    fn successfully_order_by_reversed_comparator() {
+        let mut bst = BinarySearchTreeBy::new_by(|a: &i32, b: &i32| b.cmp(a));
+        bst.extend(vec![3, 1, 2, 5, 4]);
+
+        assert_eq!(bst.asc_order_vec(), vec![&5, &4, &3, &2, &1]);
+        assert_eq!(bst.min(), Some(&5));
+        assert_eq!(bst.max(), Some(&1));
+        assert_eq!(bst.to_string(), "[5, 4, 3, 2, 1]");
+    }
+
+    #[test]
+    fn successfully_compare_trees_with_same_comparator() {
+        let mut bst = BinarySearchTreeBy::new_by(|a: &i32, b: &i32| a.cmp(b));
+        let mut other_bst = BinarySearchTreeBy::new_by(|a: &i32, b: &i32| a.cmp(b));
+        bst.extend(vec![2, 1, 3]);
+        other_bst.extend(vec![1, 2, 3]);
+
+        assert_eq!(bst, other_bst);
+    }
+}
@@ -0,0 +1,450 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::vec::IntoIter;
+
+use crate::node::{drop_tree_iteratively, OwnedChildren};
+
+type CountingHeapNode<T> = Option<Box<CountingNode<T>>>;
+
+#[derive(Debug, Clone)]
+struct CountingNode<T: Ord> {
+    value: T,
+    count: usize,
+    left: CountingHeapNode<T>,
+    right: CountingHeapNode<T>,
+}
+
+/// Multiset variant of a Binary Search Tree which keeps track of **how many times** every
+/// element has been inserted.
+///
+/// Every distinct element is stored within a single node alongside its multiplicity, therefore
+/// inserting an element that already exists only increments its count instead of being ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::CountingBST;
+///
+/// let mut counting_bst = CountingBST::new();
+/// counting_bst.insert('a');
+/// counting_bst.insert('b');
+/// counting_bst.insert('a');
+///
+/// assert_eq!(counting_bst.count(&'a'), 2);
+/// assert_eq!(counting_bst.count(&'b'), 1);
+/// assert_eq!(counting_bst.size(), 3);
+/// assert_eq!(counting_bst.distinct_size(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CountingBST<T: Ord> {
+    root: CountingHeapNode<T>,
+    size: usize,
+    distinct_size: usize,
+}
+
+impl<T: Ord> CountingBST<T> {
+    /// Creates an empty `CountingBST<T>`
+    ///
+    /// No nodes are allocated on the heap yet
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bst_rs::CountingBST;
+    ///
+    /// // Empty tree is created
+    /// let counting_bst: CountingBST<i32> = CountingBST::new();
+    /// assert!(counting_bst.is_empty())
+    /// ```
+    pub fn new() -> CountingBST<T> {
+        CountingBST {
+            root: None,
+            size: 0,
+            distinct_size: 0,
+        }
+    }
+
+    /// Returns the total **number of elements** within the tree, counting repeated elements
+    /// once for every time they were inserted.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the number of **distinct** elements within the tree.
+    pub fn distinct_size(&self) -> usize {
+        self.distinct_size
+    }
+
+    /// Returns `true` if the tree contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Inserts one occurrence of the given value.
+    ///
+    /// If the value already exists, its count is incremented.
+    pub fn insert(&mut self, value: T) {
+        self.insert_count(value, 1);
+    }
+
+    /// Removes **one occurrence** of the given value and returns `true` if it existed.
+    ///
+    /// The element is only removed from the tree once its count drops to zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::CountingBST;
+    ///
+    /// let mut counting_bst = CountingBST::from(vec![5, 5, 3]);
+    ///
+    /// assert!(counting_bst.remove(&5));
+    /// assert_eq!(counting_bst.count(&5), 1);
+    /// assert!(counting_bst.remove(&5));
+    /// assert!(!counting_bst.contains(&5));
+    /// assert!(!counting_bst.remove(&5));
+    /// ```
+    pub fn remove(&mut self, value: &T) -> bool {
+        let mut root = &mut self.root;
+        while let Some(ref mut current) = root {
+            match value.cmp(&current.value) {
+                Ordering::Less => root = &mut root.as_mut().unwrap().left,
+                Ordering::Greater => root = &mut root.as_mut().unwrap().right,
+                Ordering::Equal => {
+                    self.size -= 1;
+                    if current.count > 1 {
+                        current.count -= 1;
+                        return true;
+                    }
+
+                    match (current.left.as_mut(), current.right.as_mut()) {
+                        (None, None) => *root = None,
+                        (Some(_), None) => *root = current.left.take(),
+                        (None, Some(_)) => *root = current.right.take(),
+                        (Some(_), Some(_)) => {
+                            let (value, count) = CountingNode::remove_min(&mut current.right);
+                            current.value = value;
+                            current.count = count;
+                        }
+                    }
+                    self.distinct_size -= 1;
+
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Returns the **number of times** the given value has been inserted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::CountingBST;
+    ///
+    /// let counting_bst = CountingBST::from(vec![1, 2, 2, 2]);
+    ///
+    /// assert_eq!(counting_bst.count(&2), 3);
+    /// assert_eq!(counting_bst.count(&4), 0);
+    /// ```
+    pub fn count(&self, value: &T) -> usize {
+        let mut root = &self.root;
+        while let Some(node) = root {
+            match value.cmp(&node.value) {
+                Ordering::Equal => return node.count,
+                Ordering::Less => root = &node.left,
+                Ordering::Greater => root = &node.right,
+            }
+        }
+
+        0
+    }
+
+    /// Returns `true` if the tree contains at least one occurrence of the given value.
+    pub fn contains(&self, value: &T) -> bool {
+        self.count(value) > 0
+    }
+
+    /// Returns an iterator over the distinct elements and their counts in **ascending order.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::CountingBST;
+    ///
+    /// let counting_bst = CountingBST::from(vec![3, 1, 3]);
+    ///
+    /// assert_eq!(counting_bst.iter().collect::<Vec<(&i32, usize)>>(), vec![(&1, 1), (&3, 2)]);
+    /// ```
+    pub fn iter(&self) -> IntoIter<(&T, usize)> {
+        let mut elements = Vec::with_capacity(self.distinct_size);
+        let mut stack = Vec::new();
+        let mut root = &self.root;
+
+        while root.is_some() || !stack.is_empty() {
+            while let Some(node) = root {
+                stack.push(node);
+                root = &node.left;
+            }
+            let node = stack.pop().unwrap();
+            elements.push((&node.value, node.count));
+            root = &node.right;
+        }
+
+        elements.into_iter()
+    }
+
+    /// Returns the number of **distinct** elements within the inclusive range `[low, high]`.
+    ///
+    /// Repeated elements are only counted once, regardless of how many times they were
+    /// inserted. An empty range counts `0` elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::CountingBST;
+    ///
+    /// let counting_bst = CountingBST::from(vec![5, 1, 3, 3, 3, 9, 7, 7]);
+    ///
+    /// assert_eq!(counting_bst.distinct_count_in_range(&2, &7), 3);
+    /// assert_eq!(counting_bst.distinct_count_in_range(&7, &2), 0);
+    /// ```
+    pub fn distinct_count_in_range(&self, low: &T, high: &T) -> usize {
+        let mut count = 0;
+        let mut stack = vec![&self.root];
+
+        while let Some(root) = stack.pop() {
+            if let Some(node) = root {
+                if node.value > *low {
+                    stack.push(&node.left);
+                }
+                if node.value < *high {
+                    stack.push(&node.right);
+                }
+                if node.value >= *low && node.value <= *high {
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+
+    /// Adds the counts of every element within `other` into this tree.
+    ///
+    /// Combining two trees this way sums the counts of the elements that exist within both,
+    /// unlike a set union which would keep every element once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::CountingBST;
+    ///
+    /// let mut counting_bst = CountingBST::from(vec!['b', 'b', 'b', 'c']);
+    /// let other_counting_bst = CountingBST::from(vec!['a', 'a', 'b']);
+    ///
+    /// counting_bst.merge_counts(&other_counting_bst);
+    ///
+    /// assert_eq!(
+    ///     counting_bst.iter().collect::<Vec<(&char, usize)>>(),
+    ///     vec![(&'a', 2), (&'b', 4), (&'c', 1)]
+    /// );
+    /// ```
+    pub fn merge_counts(&mut self, other: &CountingBST<T>)
+    where
+        T: Clone,
+    {
+        for (value, count) in other.iter() {
+            self.insert_count(value.clone(), count);
+        }
+    }
+
+    fn insert_count(&mut self, value: T, count: usize) {
+        let mut root = &mut self.root;
+        while let Some(ref mut node) = root {
+            match value.cmp(&node.value) {
+                Ordering::Equal => {
+                    node.count += count;
+                    self.size += count;
+                    return;
+                }
+                Ordering::Less => root = &mut node.left,
+                Ordering::Greater => root = &mut node.right,
+            }
+        }
+
+        *root = Some(Box::new(CountingNode {
+            value,
+            count,
+            left: None,
+            right: None,
+        }));
+        self.size += count;
+        self.distinct_size += 1;
+    }
+}
+
+impl<T: Ord> CountingNode<T> {
+    fn remove_min(mut root: &mut CountingHeapNode<T>) -> (T, usize) {
+        while root.as_ref().unwrap().left.is_some() {
+            root = &mut root.as_mut().unwrap().left
+        }
+
+        let node = root.take().unwrap();
+        *root = node.right;
+        (node.value, node.count)
+    }
+}
+
+impl<T: Ord> OwnedChildren for CountingNode<T> {
+    fn take_children(&mut self) -> (CountingHeapNode<T>, CountingHeapNode<T>) {
+        (self.left.take(), self.right.take())
+    }
+}
+
+impl<T: Ord> Drop for CountingBST<T> {
+    fn drop(&mut self) {
+        drop_tree_iteratively(self.root.take());
+    }
+}
+
+impl<T: Ord> Default for CountingBST<T> {
+    /// Creates an empty `CountingBST<T>`
+    fn default() -> CountingBST<T> {
+        CountingBST::new()
+    }
+}
+
+impl<T: Ord> PartialEq for CountingBST<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Ord> Extend<T> for CountingBST<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter.into_iter() {
+            self.insert(value)
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for CountingBST<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut counting_bst = CountingBST::new();
+        counting_bst.extend(iter);
+        counting_bst
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for CountingBST<T> {
+    fn from(vec: Vec<T>) -> Self {
+        let mut counting_bst = CountingBST::new();
+        for value in vec.into_iter() {
+            counting_bst.insert(value);
+        }
+        counting_bst
+    }
+}
+
+impl<T: Ord + Debug> Display for CountingBST<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.iter().collect::<Vec<(&T, usize)>>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CountingBST, CountingNode};
+
+    #[test]
+    fn successfully_insert_repeated_elements() {
+        let mut counting_bst = CountingBST::new();
+        counting_bst.insert(2);
+        counting_bst.insert(1);
+        counting_bst.insert(2);
+        counting_bst.insert(3);
+        counting_bst.insert(2);
+
+        assert_eq!(counting_bst.size(), 5);
+        assert_eq!(counting_bst.distinct_size(), 3);
+        assert_eq!(counting_bst.count(&1), 1);
+        assert_eq!(counting_bst.count(&2), 3);
+        assert_eq!(counting_bst.count(&4), 0);
+        assert_eq!(counting_bst.to_string(), "[(1, 1), (2, 3), (3, 1)]");
+    }
+
+    #[test]
+    fn successfully_remove_repeated_elements() {
+        let mut counting_bst = CountingBST::from(vec![4, 2, 6, 1, 3, 5, 7, 4, 4]);
+
+        assert!(counting_bst.remove(&4));
+        assert_eq!(counting_bst.count(&4), 2);
+        assert_eq!(counting_bst.distinct_size(), 7);
+
+        assert!(counting_bst.remove(&4));
+        assert!(counting_bst.remove(&4));
+        assert!(!counting_bst.remove(&4));
+        assert!(!counting_bst.contains(&4));
+        assert_eq!(counting_bst.size(), 6);
+        assert_eq!(counting_bst.distinct_size(), 6);
+        assert_eq!(
+            counting_bst.iter().collect::<Vec<(&i32, usize)>>(),
+            vec![(&1, 1), (&2, 1), (&3, 1), (&5, 1), (&6, 1), (&7, 1)]
+        );
+    }
+
+    #[test]
+    fn successfully_get_distinct_count_in_range() {
+        let counting_bst = CountingBST::from(vec![5, 5, 5, 1, 3, 3, 9, 7]);
+
+        assert_eq!(counting_bst.distinct_count_in_range(&1, &9), 5);
+        assert_eq!(counting_bst.distinct_count_in_range(&3, &5), 2);
+        assert_eq!(counting_bst.distinct_count_in_range(&6, &6), 0);
+        assert_eq!(counting_bst.distinct_count_in_range(&9, &1), 0);
+        assert_eq!(CountingBST::new().distinct_count_in_range(&1, &9), 0);
+    }
+
+    #[test]
+    fn successfully_drop_degenerate_tree_without_overflowing() {
+        // Chains the nodes manually, as inserting sequential integers one by one is quadratic
+        let mut root = None;
+        for value in (0..100_000).rev() {
+            root = Some(Box::new(CountingNode {
+                value,
+                count: 1,
+                left: None,
+                right: root,
+            }));
+        }
+        let counting_bst = CountingBST {
+            root,
+            size: 100_000,
+            distinct_size: 100_000,
+        };
+        assert_eq!(counting_bst.count(&99_999), 1);
+
+        drop(counting_bst);
+    }
+
+    #[test]
+    fn successfully_merge_counts() {
+        let mut counting_bst = CountingBST::from(vec!["b", "b", "b", "c"]);
+        let other_counting_bst = CountingBST::from(vec!["a", "b", "a"]);
+
+        counting_bst.merge_counts(&other_counting_bst);
+
+        assert_eq!(
+            counting_bst,
+            CountingBST::from(vec!["a", "a", "b", "b", "b", "b", "c"])
+        );
+        assert_eq!(counting_bst.count(&"a"), 2);
+        assert_eq!(counting_bst.count(&"b"), 4);
+        assert_eq!(counting_bst.count(&"c"), 1);
+        assert_eq!(counting_bst.size(), 7);
+        assert_eq!(counting_bst.distinct_size(), 3);
+        assert_eq!(other_counting_bst.size(), 3);
+    }
+}
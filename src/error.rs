@@ -0,0 +1,82 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The error returned when a tree cannot be reconstructed from its traversals.
+///
+/// See [IterativeBST::from_inorder_preorder()](crate::IterativeBST::from_inorder_preorder()).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReconstructError {
+    /// The traversals contain a different number of elements.
+    LengthMismatch,
+    /// The in-order traversal is not in strictly **ascending order.**
+    UnsortedInOrder,
+    /// The traversals do not contain the same elements.
+    ElementMismatch,
+    /// The pre-order traversal does not describe a binary search tree of the given elements.
+    InvalidPreOrder,
+}
+
+impl Display for ReconstructError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReconstructError::LengthMismatch => {
+                write!(f, "traversals contain a different number of elements")
+            }
+            ReconstructError::UnsortedInOrder => {
+                write!(f, "in-order traversal is not in strictly ascending order")
+            }
+            ReconstructError::ElementMismatch => {
+                write!(f, "traversals do not contain the same elements")
+            }
+            ReconstructError::InvalidPreOrder => {
+                write!(
+                    f,
+                    "pre-order traversal does not describe a binary search tree"
+                )
+            }
+        }
+    }
+}
+
+impl Error for ReconstructError {}
+
+/// The error returned when a tree cannot be rebuilt with the requested height.
+///
+/// A tree with `n` elements can only have a height between `floor(log2(n))` and `n - 1`, an
+/// empty tree has no height at all.
+///
+/// See [rebalance_to_height()](crate::BinarySearchTree::rebalance_to_height()).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooTall;
+
+impl Display for TooTall {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "target height is not achievable with the number of elements"
+        )
+    }
+}
+
+impl Error for TooTall {}
+
+/// The error returned when a sequence of elements is not in strictly **ascending order.**
+///
+/// See [IterativeBST::from_strictly_increasing()](crate::IterativeBST::from_strictly_increasing()).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotSorted {
+    /// The index of the first element that is not greater than the element before it.
+    pub position: usize,
+}
+
+impl Display for NotSorted {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "element at position {} is not greater than the previous element",
+            self.position
+        )
+    }
+}
+
+impl Error for NotSorted {}
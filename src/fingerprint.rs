@@ -0,0 +1,59 @@
+/// Types with a fixed, **platform-independent** byte representation.
+///
+/// This is used by [fingerprint()](crate::BinarySearchTree::fingerprint()) to produce hashes
+/// that are stable across process runs and machines, unlike the (possibly randomised)
+/// [DefaultHasher](std::collections::hash_map::DefaultHasher).
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::StableBytes;
+///
+/// assert_eq!(1u16.stable_bytes(), vec![1, 0]);
+/// assert_eq!(String::from("bst").stable_bytes(), vec![3, 0, 0, 0, 0, 0, 0, 0, 98, 115, 116]);
+/// ```
+pub trait StableBytes {
+    /// Returns the bytes representing the value.
+    fn stable_bytes(&self) -> Vec<u8>;
+}
+
+macro_rules! impl_stable_bytes {
+    ($($t:ty => $as:ty),* $(,)?) => {
+        $(
+            impl StableBytes for $t {
+                fn stable_bytes(&self) -> Vec<u8> {
+                    (*self as $as).to_le_bytes().to_vec()
+                }
+            }
+        )*
+    };
+}
+
+impl_stable_bytes!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => u64,
+    i8 => i8, i16 => i16, i32 => i32, i64 => i64, i128 => i128, isize => i64,
+    char => u32, bool => u8,
+);
+
+impl StableBytes for String {
+    fn stable_bytes(&self) -> Vec<u8> {
+        let mut bytes = (self.len() as u64).to_le_bytes().to_vec();
+        bytes.extend_from_slice(self.as_bytes());
+        bytes
+    }
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+pub(crate) fn fnv1a<'a, T, I>(elements: I) -> u64
+where
+    T: StableBytes + 'a,
+    I: Iterator<Item = &'a T>,
+{
+    elements
+        .flat_map(|element| element.stable_bytes())
+        .fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+        })
+}
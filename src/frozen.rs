@@ -0,0 +1,269 @@
+use std::cmp::Ordering;
+use std::vec::IntoIter;
+
+use crate::IterativeBST;
+
+/// Immutable Binary Search Tree stored within a single contiguous `Vec<T>`.
+///
+/// The elements are laid out in **Eytzinger** (level-order) order of a complete binary search
+/// tree, where the children of the element at index `i` live at indices `2i + 1` and `2i + 2`.
+/// Lookups therefore walk through the array without chasing any pointers, which makes this
+/// layout well suited for read-heavy workloads once a tree has stopped changing.
+///
+/// A `FrozenBST<T>` is created by calling [freeze()](crate::BinarySearchTree::freeze()) on an
+/// existing tree and can be turned back into a mutable tree with [thaw()](FrozenBST::thaw()).
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::{BinarySearchTree, IterativeBST};
+///
+/// let frozen_bst = IterativeBST::from(vec![3, 1, 2]).freeze();
+///
+/// assert!(frozen_bst.contains(&2));
+/// assert_eq!(frozen_bst.min(), Some(&1));
+/// assert_eq!(frozen_bst.max(), Some(&3));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrozenBST<T: Ord> {
+    elements: Vec<T>,
+}
+
+impl<T: Ord> FrozenBST<T> {
+    pub(crate) fn from_sorted(sorted: Vec<T>) -> FrozenBST<T> {
+        let mut slots: Vec<Option<T>> = (0..sorted.len()).map(|_| None).collect();
+        for (index, value) in in_order_indices(sorted.len()).into_iter().zip(sorted) {
+            slots[index] = Some(value);
+        }
+
+        FrozenBST {
+            elements: slots.into_iter().map(|slot| slot.unwrap()).collect(),
+        }
+    }
+
+    /// Consumes the frozen tree and returns a balanced, mutable [IterativeBST].
+    ///
+    /// As the elements are already laid out in level-order of a balanced tree, the nodes are
+    /// rebuilt directly from the array without sorting the elements again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::from_iter(1..=7).freeze().thaw();
+    /// assert_eq!(bst.height(), Some(2));
+    ///
+    /// bst.insert(8);
+    /// assert_eq!(bst.max(), Some(&8));
+    /// ```
+    pub fn thaw(self) -> IterativeBST<T> {
+        IterativeBST::from_eytzinger(self.elements)
+    }
+
+    /// Returns the total **number of elements** within the tree.
+    pub fn size(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns `true` if the tree contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns `true` if the tree contains an element with the given value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let frozen_bst = IterativeBST::from(vec![5, 2, 8]).freeze();
+    ///
+    /// assert!(frozen_bst.contains(&8));
+    /// assert!(!frozen_bst.contains(&4));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool {
+        let mut index = 0;
+        while let Some(current) = self.elements.get(index) {
+            match value.cmp(current) {
+                Ordering::Equal => return true,
+                Ordering::Less => index = 2 * index + 1,
+                Ordering::Greater => index = 2 * index + 2,
+            }
+        }
+
+        false
+    }
+
+    /// Returns a reference to the minimum element of the tree or `None` if tree is empty.
+    pub fn min(&self) -> Option<&T> {
+        let mut index = 0;
+        while 2 * index + 1 < self.elements.len() {
+            index = 2 * index + 1;
+        }
+
+        self.elements.get(index)
+    }
+
+    /// Returns a reference to the maximum element of the tree or `None` if tree is empty.
+    pub fn max(&self) -> Option<&T> {
+        let mut index = 0;
+        while 2 * index + 2 < self.elements.len() {
+            index = 2 * index + 2;
+        }
+
+        self.elements.get(index)
+    }
+
+    /// Returns an iterator over the elements of the tree in **ascending order.**
+    pub fn asc_order_iter(&self) -> IntoIter<&T> {
+        in_order_indices(self.elements.len())
+            .into_iter()
+            .map(|index| &self.elements[index])
+            .collect::<Vec<&T>>()
+            .into_iter()
+    }
+
+    /// Returns an iterator over the elements of the tree in **level order.**
+    ///
+    /// This is the order in which the elements are stored.
+    pub fn level_order_iter(&self) -> IntoIter<&T> {
+        self.elements.iter().collect::<Vec<&T>>().into_iter()
+    }
+}
+
+/// Owning iterator over a copy of the elements of a tree in **ascending order.**
+///
+/// Created by [frozen_iter()](crate::BinarySearchTree::frozen_iter()). As the elements are owned
+/// by the iterator, it is not tied to the lifetime of the tree it was created from.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::{BinarySearchTree, IterativeBST};
+///
+/// let bst = IterativeBST::from(vec![2, 1]);
+/// let mut frozen_iter = bst.frozen_iter();
+/// drop(bst);
+///
+/// assert_eq!(frozen_iter.next(), Some(1));
+/// assert_eq!(frozen_iter.next(), Some(2));
+/// assert_eq!(frozen_iter.next(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrozenIter<T> {
+    elements: IntoIter<T>,
+}
+
+impl<T> FrozenIter<T> {
+    pub(crate) fn new(elements: Vec<T>) -> FrozenIter<T> {
+        FrozenIter {
+            elements: elements.into_iter(),
+        }
+    }
+}
+
+impl<T> Iterator for FrozenIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.elements.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.elements.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for FrozenIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.elements.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for FrozenIter<T> {}
+
+/// Returns the indices of a complete binary tree with `size` nodes in **in-order**.
+fn in_order_indices(size: usize) -> Vec<usize> {
+    let mut indices = Vec::with_capacity(size);
+    let mut stack = Vec::new();
+    let mut index = 0;
+
+    while index < size || !stack.is_empty() {
+        while index < size {
+            stack.push(index);
+            index = 2 * index + 1;
+        }
+        let current = stack.pop().unwrap();
+        indices.push(current);
+        index = 2 * current + 2;
+    }
+
+    indices
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BinarySearchTree, IterativeBST, RecursiveBST};
+
+    #[test]
+    fn successfully_freeze_tree() {
+        let frozen_bst = IterativeBST::from_iter(1..=10).freeze();
+
+        assert_eq!(frozen_bst.size(), 10);
+        assert_eq!(
+            frozen_bst.level_order_iter().collect::<Vec<&i32>>(),
+            vec![&7, &4, &9, &2, &6, &8, &10, &1, &3, &5]
+        );
+        assert_eq!(
+            frozen_bst.asc_order_iter().collect::<Vec<&i32>>(),
+            (1..=10).collect::<Vec<i32>>().iter().collect::<Vec<&i32>>()
+        );
+    }
+
+    #[test]
+    fn successfully_freeze_empty_tree() {
+        let frozen_bst = RecursiveBST::<i32>::new().freeze();
+
+        assert!(frozen_bst.is_empty());
+        assert!(!frozen_bst.contains(&1));
+        assert_eq!(frozen_bst.min(), None);
+        assert_eq!(frozen_bst.max(), None);
+        assert_eq!(frozen_bst.asc_order_iter().next(), None);
+    }
+
+    #[test]
+    fn successfully_query_frozen_tree() {
+        let bst = RecursiveBST::from(vec![15, 3, 42, 8, 23, 4, 16, 1, 99, 50, 7]);
+        let frozen_bst = bst.clone().freeze();
+
+        for value in bst.asc_order_iter() {
+            assert!(frozen_bst.contains(value));
+        }
+        for value in [0, 2, 5, 20, 45, 100] {
+            assert_eq!(frozen_bst.contains(&value), bst.contains(&value));
+        }
+        assert_eq!(frozen_bst.min(), bst.min());
+        assert_eq!(frozen_bst.max(), bst.max());
+        assert_eq!(
+            frozen_bst.asc_order_iter().collect::<Vec<&i32>>(),
+            bst.asc_order_vec()
+        );
+    }
+
+    #[test]
+    fn successfully_thaw_frozen_tree() {
+        let bst = RecursiveBST::from_iter(1..=20);
+        let thawed_bst = IterativeBST::from_iter(1..=20).freeze().thaw();
+
+        assert_eq!(thawed_bst.size(), 20);
+        assert_eq!(thawed_bst.asc_order_vec(), bst.asc_order_vec());
+        assert_eq!(thawed_bst.height(), Some(4));
+        assert_eq!(thawed_bst.skew_score(), Some(1.0));
+
+        let empty_bst = IterativeBST::<i32>::new().freeze().thaw();
+        assert!(empty_bst.is_empty());
+        assert_eq!(empty_bst.height(), None);
+    }
+}
@@ -6,6 +6,14 @@
 //! For more information on why that is the case, please have a look at
 //! [The Story of Tail Call Optimizations in Rust.](https://seanchen1991.github.io/posts/tco-story/)
 //!
+//! A self-adjusting [SplayBST] is also provided, which moves accessed elements to the root,
+//! alongside a [CountingBST] which keeps track of how many times every element was inserted.
+//! Elements that should not be ordered through [Ord] can be stored within a [BinarySearchTreeBy]
+//! using a custom comparator.
+//!
+//! Enabling the optional `rayon` feature adds `par_from_iter()` constructors, which sort the
+//! elements in parallel and build balanced trees directly from them.
+//!
 //! ## Author Notes
 //!
 //! I have made this library with the personal goals of learning and solidifying concepts such
@@ -15,8 +23,6 @@
 //! That being said, there are some areas I would love to improve upon which include:
 //! - Write idiomatic code.
 //! - Effectively use **macro_rules!** to reduce large portions of repetitive code.
-//! - Implement a **pretty_print()** function to display the binary search trees nicely.
-//! - Implement [Drop] trait for iterative node cleanup.
 //! - Pre-allocate space on the heap for nodes to reduce inefficiency of inserts.
 //!
 //! I'm more than happy to accept (and encourage) contributions if anyone is kind enough to do so.
@@ -84,13 +90,35 @@
 //! ```
 
 use crate::node::{HeapNode, Node};
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::ops::{Add, Sub};
+use std::sync::Arc;
 use std::vec::IntoIter;
 
+mod error;
 mod node;
+mod comparator;
+mod counting;
+mod fingerprint;
+mod frozen;
 mod iterative;
 mod recursive;
+mod report;
+mod rotation;
+mod splay;
+pub use comparator::BinarySearchTreeBy;
+pub use counting::CountingBST;
+pub use error::{NotSorted, ReconstructError, TooTall};
+pub use fingerprint::StableBytes;
+pub use frozen::{FrozenBST, FrozenIter};
 pub use recursive::RecursiveBST;
+pub use report::BuildReport;
 pub use iterative::IterativeBST;
+pub use node::{InOrderIter, InOrderIterMut};
+pub use rotation::Rotation;
+pub use splay::SplayBST;
 
 /// Creates a [`IterativeBST`] containing the arguments.
 ///
@@ -126,25 +154,57 @@ macro_rules! bst {
     );
 }
 
-#[cfg(test)]
-mod tests {
-    use super::{bst, BinarySearchTree, IterativeBST};
+/// Removes trees that contain the same elements as an earlier tree, preserving the order of the
+/// remaining trees.
+///
+/// Trees are compared using [PartialEq] which only considers the elements and **not** the shape
+/// of the trees.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::{bst, dedup_trees};
+///
+/// let trees = vec![bst![1, 2], bst![2, 1], bst![3]];
+///
+/// let unique_trees = dedup_trees(trees);
+///
+/// assert_eq!(unique_trees, vec![bst![1, 2], bst![3]]);
+/// ```
+pub fn dedup_trees<T: Ord, B: BinarySearchTree<T> + PartialEq>(trees: Vec<B>) -> Vec<B> {
+    let mut unique_trees: Vec<B> = Vec::with_capacity(trees.len());
 
-    #[test]
-    fn successfully_construct_bst_from_macro() {
-        let mut actual_bst = IterativeBST::new();
-        actual_bst.insert(3);
-        actual_bst.insert(2);
-        let expected_bst = bst![3, 2];
-        assert_eq!(actual_bst, expected_bst);
+    for tree in trees {
+        if !unique_trees.contains(&tree) {
+            unique_trees.push(tree);
+        }
     }
 
-    #[test]
-    fn verify_permutations_produce_same_tree() {
-        let actual_bst = bst![2, 3];
-        let expected_bst = bst![3, 2];
-        assert_eq!(actual_bst, expected_bst);
-    }
+    unique_trees
+}
+
+/// Returns the total number of **key comparisons** performed when inserting the given values,
+/// in order, into an initially empty tree.
+///
+/// Every node visited while descending to the position of a value counts as one comparison,
+/// including the node holding an equal value when a duplicate is rejected. The tree is only
+/// built for counting and is dropped afterwards, which makes this useful for comparing the
+/// cost of different insertion orders.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::build_comparison_count;
+///
+/// assert_eq!(build_comparison_count(&[1, 2, 3]), 3);
+/// assert_eq!(build_comparison_count(&[2, 1, 3]), 2);
+/// ```
+pub fn build_comparison_count<T: Ord + Clone>(values: &[T]) -> usize {
+    let mut root = None;
+    values
+        .iter()
+        .map(|value| Node::insert_comparisons(&mut root, value.clone()))
+        .sum()
 }
 
 /// A trait containing all the common operations of Binary Search Trees.
@@ -363,24 +423,24 @@ pub trait BinarySearchTree<T: Ord> {
     /// The post_order_vec is: **[&4, &2, &6, &1, &3, &5, &7].**
     fn level_order_vec(&self) -> Vec<&T>;
 
-    /// Returns an iterator over [asc_order_vec](Self::asc_order_vec()).
+    /// Returns a lazy, double-ended iterator over [asc_order_vec](Self::asc_order_vec()).
     ///
     /// # Important
     ///
     /// This function is analogous to [in_order_iter](Self::in_order_iter()) as the underlying
     /// behaviour is **_exactly the same_.**
-    fn asc_order_iter(&self) -> IntoIter<&T>;
+    fn asc_order_iter(&self) -> InOrderIter<'_, T>;
 
     /// Returns an iterator over [pre_order_vec](Self::pre_order_vec()).
     fn pre_order_iter(&self) -> IntoIter<&T>;
 
-    /// Returns an iterator over [in_order_vec](Self::in_order_vec()).
+    /// Returns a lazy, double-ended iterator over [in_order_vec](Self::in_order_vec()).
     ///
     /// # Important
     ///
     /// This function is analogous to [asc_order_iter](Self::asc_order_iter()) as the underlying
     /// behaviour is **_exactly the same_.**
-    fn in_order_iter(&self) -> IntoIter<&T>;
+    fn in_order_iter(&self) -> InOrderIter<'_, T>;
 
     /// Returns an iterator over [post_order_vec](Self::post_order_vec()).
     fn post_order_iter(&self) -> IntoIter<&T>;
@@ -412,4 +472,902 @@ pub trait BinarySearchTree<T: Ord> {
 
     /// Returns [level_order_iter](Self::level_order_iter()) **AND** consumes the tree.
     fn into_level_order_iter(self) -> IntoIter<T>;
+
+    /// Returns the **canonical key** of the tree, which is the sorted sequence of its elements.
+    ///
+    /// Two trees are considered equal if and only if their canonical keys are equal, regardless
+    /// of the order in which elements were inserted or the resulting shape of the tree. This is
+    /// the representation used by [PartialEq] to compare trees.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [asc_order_vec](Self::asc_order_vec()) as the underlying
+    /// behaviour is **_exactly the same_.**
+    fn canonical_key(&self) -> Vec<&T>;
+
+    /// Inserts given value as a node and returns the **depth** at which the new node was placed
+    /// or `None` if the value already exists.
+    ///
+    /// The depth is the number of edges between the root and the newly inserted node, so a large
+    /// depth indicates that the tree is becoming skewed.
+    ///
+    /// **Duplicate values are _not allowed_**.
+    fn insert_with_depth(&mut self, value: T) -> Option<usize>;
+
+    /// Returns the **average depth** of all nodes or `None` if tree is empty.
+    ///
+    /// This is the internal path length (the sum of the depths of all nodes) divided by the
+    /// [size](Self::size()) of the tree, which predicts the average cost of a successful search.
+    ///
+    /// # Example
+    ///
+    /// Given a tree that looks like:
+    ///
+    /// ```text
+    ///           4
+    ///         /  \
+    ///        2    6
+    ///       / \  / \
+    ///      1  3 5   7
+    /// ```
+    ///
+    /// The average search depth is: **10 / 7**
+    fn average_search_depth(&self) -> Option<f64>;
+
+    /// Returns `true` if **every** element of the tree satisfies the given predicate.
+    ///
+    /// The traversal stops as soon as an element that does not satisfy the predicate is found.
+    /// An empty tree always returns `true`.
+    fn all<F: Fn(&T) -> bool>(&self, predicate: F) -> bool
+    where
+        Self: Sized;
+
+    /// Returns `true` if **any** element of the tree satisfies the given predicate.
+    ///
+    /// The traversal stops as soon as an element that satisfies the predicate is found.
+    /// An empty tree always returns `false`.
+    fn any<F: Fn(&T) -> bool>(&self, predicate: F) -> bool
+    where
+        Self: Sized;
+
+    /// Returns the running `(minimum, maximum)` for every prefix of the elements in
+    /// **ascending order.**
+    ///
+    /// The element at position `i` is the minimum and maximum over the first `i + 1` elements.
+    /// As the elements are sorted, this doubles as a sanity check on the ordering of the tree.
+    ///
+    /// # Example
+    ///
+    /// Given a tree that looks like:
+    /// ```text
+    ///           4
+    ///         /  \
+    ///        2    6
+    /// ```
+    /// The prefix_extremes is: **[(&2, &2), (&2, &4), (&2, &6)].**
+    fn prefix_extremes(&self) -> Vec<(&T, &T)>;
+
+    /// Rebalances the tree **in-place** so that it has the minimum possible height.
+    ///
+    /// This uses the [Day-Stout-Warren](https://en.wikipedia.org/wiki/Day%E2%80%93Stout%E2%80%93Warren_algorithm)
+    /// algorithm which first flattens the tree into a sorted "vine" and then rotates the vine
+    /// back into a balanced tree. No nodes are allocated or de-allocated during this process.
+    fn rebalance(&mut self);
+
+    /// Returns the number of **rotations** that [rebalance](Self::rebalance()) would perform
+    /// on the tree **without** modifying it.
+    ///
+    /// The count consists of the right rotations needed to flatten the tree into a vine (every
+    /// node that is not already on the rightmost path from the root) plus the left rotations
+    /// needed to turn the vine back into a balanced tree (which only depends on the size).
+    fn rebalance_cost(&self) -> usize;
+
+    /// Returns an **indented outline** of the tree with one element per line in the order of a
+    /// **pre-order traversal.**
+    ///
+    /// Every element is indented by two spaces per level of depth. An empty tree returns an
+    /// empty string.
+    ///
+    /// # Example
+    ///
+    /// Given a tree that looks like:
+    /// ```text
+    ///           4
+    ///         /  \
+    ///        2    6
+    ///       / \  / \
+    ///      1  3 5   7
+    /// ```
+    /// The outline is:
+    /// ```text
+    /// 4
+    ///   2
+    ///     1
+    ///     3
+    ///   6
+    ///     5
+    ///     7
+    /// ```
+    fn to_outline(&self) -> String
+    where
+        T: Display;
+
+    /// Returns the number of elements `(less than, greater than)` the given value or `None` if
+    /// the value does not exist within the tree.
+    ///
+    /// # Example
+    ///
+    /// Given a tree that looks like:
+    /// ```text
+    ///           4
+    ///         /  \
+    ///        2    6
+    ///       / \  / \
+    ///      1  3 5   7
+    /// ```
+    /// The side_counts of `&2` is: **(1, 5).**
+    fn side_counts(&self, value: &T) -> Option<(usize, usize)>;
+
+    /// Returns an iterator over the elements of **both** trees in **ascending order.**
+    ///
+    /// The trees are merged lazily and elements that exist within both trees are only
+    /// yielded once.
+    fn merge_iter<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = &'a T>
+    where
+        Self: Sized,
+        T: 'a;
+
+    /// Returns a histogram mapping every **balance factor** within the tree to the number of
+    /// nodes with that balance factor.
+    ///
+    /// The balance factor of a node is the height of its left subtree minus the height of its
+    /// right subtree (where an empty subtree has a height of `-1`). A well balanced tree only
+    /// contains balance factors of `-1`, `0` and `1`.
+    ///
+    /// # Example
+    ///
+    /// Given a tree that looks like:
+    /// ```text
+    ///           4
+    ///         /  \
+    ///        2    6
+    ///       /
+    ///      1
+    /// ```
+    /// The balance_factor_histogram is: **{0: 2, 1: 2}.**
+    fn balance_factor_histogram(&self) -> HashMap<isize, usize>;
+
+    /// Returns the tree as a reference to [Any] so that it can be downcast back into its
+    /// concrete type.
+    ///
+    /// This is useful when different implementations are stored behind a
+    /// `Box<dyn BinarySearchTree<T>>` but implementation specific functionality is required.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST, RecursiveBST};
+    ///
+    /// let trees: Vec<Box<dyn BinarySearchTree<i32>>> = vec![
+    ///     Box::new(IterativeBST::from(vec![1, 2, 3])),
+    ///     Box::new(RecursiveBST::from(vec![4, 5])),
+    /// ];
+    ///
+    /// assert!(trees[0].as_any().downcast_ref::<IterativeBST<i32>>().is_some());
+    /// assert!(trees[1].as_any().downcast_ref::<IterativeBST<i32>>().is_none());
+    /// ```
+    fn as_any(&self) -> &dyn Any
+    where
+        T: 'static;
+
+    /// Returns the ratio between the actual **height** and the optimal height of the tree or
+    /// `None` if tree is empty.
+    ///
+    /// The optimal height of a tree with `n` nodes is `floor(log2(n))`, therefore a score of
+    /// `1.0` means the tree is perfectly efficient and larger scores mean the tree is
+    /// degenerating into a linked list. A tree with a single node always has a score of `1.0`.
+    fn skew_score(&self) -> Option<f64>;
+
+    /// Returns the number of nodes with **exactly one** child.
+    ///
+    /// These are neither leaves nor full internal nodes; a tree with many of them is on its way
+    /// to degenerating into a linked list.
+    fn half_nodes(&self) -> usize;
+
+    /// Returns references to the elements on the **boundary** of the tree in anti-clockwise order.
+    ///
+    /// The boundary starts at the root, followed by the left boundary top-down (excluding leaves),
+    /// then all leaves from left to right and finally the right boundary bottom-up (excluding
+    /// leaves). Every element appears exactly once.
+    fn boundary_vec(&self) -> Vec<&T>;
+
+    /// Returns references to the elements grouped by **vertical column** from left to right.
+    ///
+    /// The root is in column `0`, a left child is one column to the left of its parent and a
+    /// right child is one column to the right. Each column lists its elements top-to-bottom, with
+    /// elements on the same row ordered from left to right.
+    fn vertical_order(&self) -> Vec<Vec<&T>>;
+
+    /// Returns references to the elements grouped by **diagonal**.
+    ///
+    /// Moving to a right child stays on the same diagonal whereas moving to a left child starts
+    /// the next diagonal. The first diagonal therefore contains the root and its chain of right
+    /// children.
+    fn diagonal_vec(&self) -> Vec<Vec<&T>>;
+
+    /// Returns the **maximum sum** over all root-to-leaf paths together with the elements on that
+    /// path or `None` if tree is empty.
+    ///
+    /// If several paths share the maximum sum, the leftmost one is returned.
+    fn max_path_sum_to_leaf(&self) -> Option<(T, Vec<&T>)>
+    where
+        T: Add<Output = T> + Copy;
+
+    /// Returns a reference to the element at the **center** of the tree or `None` if tree is empty.
+    ///
+    /// The center is the element with the smallest eccentricity, i.e. the smallest greatest
+    /// distance to any other element when edges are treated as undirected. A tree has at most two
+    /// such elements, in which case the smaller one is returned.
+    fn center(&self) -> Option<&T>;
+
+    /// Returns a new **balanced** tree containing clones of the elements within the inclusive range
+    /// `[low, high]`.
+    ///
+    /// An empty tree is returned if `low` is greater than `high`.
+    fn clone_range(&self, low: &T, high: &T) -> Self
+    where
+        Self: Sized,
+        T: Clone;
+
+    /// Returns the elements of **both** trees aligned by value in **ascending order.**
+    ///
+    /// Elements that exist within both trees are paired as `(Some, Some)` whereas elements that
+    /// only exist within one tree are paired with `None` on the side of the other tree.
+    fn zip_compare<'a>(&'a self, other: &'a Self) -> Vec<(Option<&'a T>, Option<&'a T>)>
+    where
+        Self: Sized;
+
+    /// Consumes the tree and returns a read-only [FrozenBST] storing all elements within a
+    /// single contiguous array in **Eytzinger** layout.
+    fn freeze(self) -> FrozenBST<T>;
+
+    /// Returns the number of elements whose rank would **increase by one** if the given value was
+    /// inserted, i.e. the number of elements strictly greater than the value.
+    ///
+    /// The tree is not modified.
+    fn rank_shift_on_insert(&self, value: &T) -> usize;
+
+    /// Returns a **deterministic fingerprint** of the elements within the tree.
+    ///
+    /// The fingerprint is computed with FNV-1a over the [StableBytes] of every element in
+    /// ascending order, therefore it only depends on the contents (not the shape) of the tree and
+    /// is stable across process runs and machines.
+    fn fingerprint(&self) -> u64
+    where
+        T: StableBytes;
+
+    /// Inserts the elements of a **sorted** slice by linking them into the tree as a single
+    /// balanced subtree.
+    ///
+    /// The subtree can only be linked in if the slice is in strictly ascending order and no
+    /// existing element lies within the range of the slice. Neither is required though: if the
+    /// slice is not strictly ascending or overlaps with the tree, the elements are inserted one at
+    /// a time instead, in both debug and release builds.
+    fn splice_sorted(&mut self, sorted: &[T])
+    where
+        T: Clone;
+
+    /// Returns the number of nodes at the **deepest level** of the tree.
+    ///
+    /// This indicates how filled out the bottom level of the tree is, an empty tree returns `0`.
+    fn deepest_level_width(&self) -> usize;
+
+    /// Returns `true` if the cached [size](Self::size()) matches the actual number of nodes
+    /// within the tree.
+    ///
+    /// The nodes are counted through a full traversal, therefore this is intended as a defensive
+    /// check within tests and debug builds.
+    fn verify_size(&self) -> bool;
+
+    /// Counts the actual number of nodes within the tree and repairs the cached
+    /// [size](Self::size()) if it has drifted.
+    fn recount(&mut self);
+
+    /// Returns references to the elements grouped into buckets by the result of the given `key`
+    /// function.
+    ///
+    /// The elements within every bucket are in **ascending order.**
+    fn group_by<K: Ord, F: Fn(&T) -> K>(&self, key: F) -> BTreeMap<K, Vec<&T>>
+    where
+        Self: Sized;
+
+    /// Returns the number of element removals needed to bring the **height** of the tree down
+    /// to its optimal height, given the current shape of the tree.
+    ///
+    /// This is the number of nodes beyond the optimal-height frontier, i.e. nodes with a depth
+    /// greater than `floor(log2(n))`. Removing exactly these nodes (deepest first) leaves a tree
+    /// whose height is the optimal height of the current tree, whereas a balanced tree requires
+    /// no removals.
+    fn removals_to_balance(&self) -> usize;
+
+    /// Returns the number of edges on the **longest zigzag path** within the tree.
+    ///
+    /// A zigzag path travels downwards and alternates between left and right children at
+    /// every step. An empty tree or a tree with a single node returns `0`.
+    fn longest_zigzag(&self) -> usize;
+
+    /// Returns the **cumulative sums** of the elements in ascending order.
+    ///
+    /// The element at index `i` is the sum of the `i + 1` smallest elements within the tree.
+    fn prefix_sums(&self) -> Vec<T>
+    where
+        T: Add<Output = T> + Copy;
+
+    /// Returns the **sum** of all elements within the inclusive range `[low, high]`, given the
+    /// [prefix_sums](Self::prefix_sums()) of the tree.
+    ///
+    /// The bounds of the range are located through rank lookups and the sum is taken as the
+    /// difference between two of the prefix sums, so every query only takes `O(h)` once the prefix
+    /// sums have been computed. `None` is returned if the range is empty or if `prefix_sums` is
+    /// too short to cover it, e.g. because it was computed before elements were inserted.
+    fn range_sum(&self, prefix_sums: &[T], low: &T, high: &T) -> Option<T>
+    where
+        T: Sub<Output = T> + Copy;
+
+    /// Returns `true` if inserting the given value would **increase the height** of the tree.
+    ///
+    /// The depth at which the value would be inserted is found through a dry-run descent and
+    /// compared against the current height, without modifying the tree. Inserting into an empty
+    /// tree counts as an increase whereas a value that already exists never increases the height.
+    fn would_grow_height(&self, value: &T) -> bool;
+
+    /// Returns a **read-only snapshot** of the elements in ascending order.
+    ///
+    /// The elements are cloned once into an `Arc<[T]>` which can be shared (and cheaply cloned)
+    /// across threads, independently of any later changes to the tree.
+    fn snapshot(&self) -> Arc<[T]>
+    where
+        T: Clone;
+
+    /// Returns the number of **distinct** elements within the inclusive range `[low, high]`.
+    ///
+    /// As duplicates are not stored, this is the number of elements within the range, found in
+    /// `O(h)` as the difference between two rank lookups. See
+    /// [CountingBST::distinct_count_in_range()](crate::CountingBST::distinct_count_in_range())
+    /// for a tree that keeps track of repeated elements.
+    fn distinct_count_in_range(&self, low: &T, high: &T) -> usize;
+
+    /// Rebuilds the tree so that it has **exactly** the given height.
+    ///
+    /// A tree with `n` elements can only have a height between `floor(log2(n))` and `n - 1`,
+    /// [TooTall] is returned (and the tree is left untouched) for any other target.
+    fn rebalance_to_height(&mut self, target: usize) -> Result<(), TooTall>;
+
+    /// Returns an iterator over the elements in **in-order** paired with `true` if the element
+    /// is a leaf (a node without any children).
+    fn iter_annotated<'a>(&'a self) -> impl Iterator<Item = (&'a T, bool)>
+    where
+        Self: Sized,
+        T: 'a;
+
+    /// Rotates the subtree rooted at the given **pivot** element in the given direction.
+    ///
+    /// Returns `true` if the rotation was applied or `false` if the pivot does not exist or it
+    /// lacks the child that would take its place.
+    fn rotate(&mut self, pivot: &T, rotation: Rotation) -> bool;
+
+    /// Returns the ordered list of `(pivot, direction)` rotations that
+    /// [rebalance](Self::rebalance()) would apply, without modifying the tree.
+    ///
+    /// Applying these rotations in order through [rotate](Self::rotate()) balances the tree.
+    fn rotations_to_balance(&self) -> Vec<(T, Rotation)>
+    where
+        T: Clone;
+
+    /// Returns a reference to the element or `None` if element does not exist.
+    ///
+    /// The element is **splayed** to the root through rotations, so frequently accessed elements
+    /// become shallow over time. If the element does not exist, the last node reached while
+    /// searching for it is splayed instead.
+    fn find_splay(&mut self, value: &T) -> Option<&T>;
+
+    /// Returns the **Jaccard similarity** between the elements of both trees.
+    ///
+    /// This is the size of the intersection divided by the size of the union, a value within
+    /// `[0.0, 1.0]`. Both sizes are counted within a single pass over the ascending elements.
+    /// Two empty trees are considered identical and therefore have a similarity of `1.0`.
+    fn jaccard(&self, other: &Self) -> f64
+    where
+        Self: Sized;
+
+    /// Returns an **owning iterator** over a copy of the elements in ascending order.
+    ///
+    /// The elements are cloned once into a buffer owned by the [FrozenIter], which therefore
+    /// keeps yielding the original elements even if the tree is later modified or dropped.
+    fn frozen_iter(&self) -> FrozenIter<T>
+    where
+        T: Clone;
+
+    /// Returns a reference to the element at the given **quantile** or `None` if tree is empty.
+    ///
+    /// The quantile `q` is converted into the rank `round(q * (size - 1))`, so `0.0` returns the
+    /// minimum, `0.5` the median and `1.0` the maximum. Quantiles outside of `[0.0, 1.0]` are
+    /// clamped into the range whereas `NaN` returns `None`.
+    fn quantile(&self, q: f64) -> Option<&T>;
+
+    /// Returns an iterator over the elements **strictly greater** than the given value in
+    /// **ascending order.**
+    ///
+    /// The iterator starts at the successor of the value, which does not need to exist within
+    /// the tree, making it suitable for paging through the elements after the last one seen.
+    fn iter_after<'a>(&'a self, value: &T) -> impl Iterator<Item = &'a T>
+    where
+        Self: Sized,
+        T: 'a;
+
+    /// Returns every element paired with the **fill ratio** of its subtree in ascending order.
+    ///
+    /// The fill ratio is the number of elements within the subtree divided by the number of
+    /// elements a perfect tree of the same height could hold, so `1.0` marks a perfectly dense
+    /// subtree (such as a leaf) and lower scores mark increasingly sparse or skewed subtrees.
+    fn subtree_balance_scores(&self) -> Vec<(&T, f64)>;
+
+    /// Returns the **expected number of nodes visited** by a successful search or `None` if tree
+    /// is empty.
+    ///
+    /// Every element is assumed to be searched for with the same probability. As finding a node
+    /// visits the node itself and all of its ancestors, this is the
+    /// [average search depth](Self::average_search_depth()) plus one.
+    ///
+    /// # Example
+    ///
+    /// Given a tree that looks like:
+    ///
+    /// ```text
+    ///           4
+    ///         /  \
+    ///        2    6
+    ///       / \  / \
+    ///      1  3 5   7
+    /// ```
+    ///
+    /// The expected number of nodes visited is: **17 / 7**
+    fn expected_search_visits(&self) -> Option<f64>;
+
+    /// Returns every `step`-th element by rank (ranks `0`, `step`, `2 * step`, ...) in
+    /// ascending order, giving an evenly spaced sample of the tree.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is `0`.
+    fn sample_by_step(&self, step: usize) -> Vec<&T>;
+
+    /// Returns `true` if `other` can be obtained from this tree through a sequence of
+    /// **rotations** only.
+    ///
+    /// Rotations preserve the in-order sequence of a binary search tree, so this holds exactly
+    /// when both trees are valid binary search trees containing the same elements, regardless
+    /// of their shapes.
+    fn is_rotation_of(&self, other: &Self) -> bool
+    where
+        Self: Sized;
+
+    /// Inserts every value of the given iterator, [rebalancing](Self::rebalance()) the tree
+    /// after every `rebalance_every` insertions.
+    ///
+    /// This prevents the tree from degenerating into a vine while loading long (for example
+    /// sorted) streams of elements. A `rebalance_every` of `0` never rebalances.
+    fn insert_stream_autobalance<I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+        rebalance_every: usize,
+    ) where
+        Self: Sized;
+
+    /// Returns a reference to the **positional median** of the tree or `None` if tree is empty.
+    ///
+    /// For an odd number of elements this is the middle element by rank, whereas for an even
+    /// number of elements the **lower** of both middle elements is returned, i.e. the element
+    /// at rank `(size - 1) / 2`.
+    fn element_nearest_median(&self) -> Option<&T>;
+
+    /// Returns the number of elements `(only_in_self, in_both, only_in_other)` when comparing
+    /// the elements of both trees.
+    ///
+    /// All three counts are computed within a single pass over the ascending elements of both
+    /// trees, without building any intermediate trees.
+    fn diff_sizes(&self, other: &Self) -> (usize, usize, usize)
+    where
+        Self: Sized;
+
+    /// Returns an [IterativeBST] with the **exact same structure** as this tree, where every
+    /// element has been replaced by the result of calling the given function on it.
+    ///
+    /// **The function is _not_ required to preserve the ordering of the elements, therefore the
+    /// returned tree may violate the ordering of a binary search tree.** Such a tree is only
+    /// suitable for inspecting its structure (for example through traversals), as searching
+    /// within it or modifying it gives unspecified results.
+    fn remap_shape<U: Ord, F: FnMut(&T) -> U>(&self, f: F) -> IterativeBST<U>
+    where
+        Self: Sized;
+
+    /// Consumes the tree and returns the `k` **smallest** elements in ascending order.
+    ///
+    /// The consuming in-order traversal stops as soon as `k` elements have been collected
+    /// instead of draining the whole tree. All elements are returned if `k` is greater than the
+    /// size of the tree.
+    fn into_k_smallest(self, k: usize) -> Vec<T>;
+
+    /// Returns the **shared prefix** of the paths from the root to both given values, which ends
+    /// at their lowest common ancestor.
+    ///
+    /// An empty vector is returned if either value does not exist within the tree.
+    fn common_path(&self, a: &T, b: &T) -> Vec<&T>;
+
+    /// Returns the number of **completely filled** levels, counting downwards from the root.
+    ///
+    /// The level at depth `d` is full when it contains exactly `2^d` nodes and the count stops
+    /// at the first level that is not full. An empty tree has no full levels.
+    fn full_levels(&self) -> usize;
+
+    /// Returns up to `radius` elements on **each side** of the given value by rank, alongside
+    /// the value itself, in ascending order.
+    ///
+    /// Fewer elements are returned on one side when the value is close to the minimum or the
+    /// maximum of the tree. An empty vector is returned if the value does not exist.
+    fn take_window_around(&self, center: &T, radius: usize) -> Vec<&T>;
+
+    /// Returns the number of **duplicate insertions** that have been rejected since the tree
+    /// was created.
+    ///
+    /// Every call to [insert()](Self::insert()), [insert_with_depth()](Self::insert_with_depth())
+    /// or [try_insert()](Self::try_insert()) with a value that already exists increments this
+    /// counter.
+    fn rejected_duplicates(&self) -> usize;
+
+    /// Returns the single `(pivot, direction)` rotation that would reduce the **internal path
+    /// length** of the tree the most, or `None` if no rotation would reduce it.
+    ///
+    /// This allows a tree to be improved incrementally, one [rotate](Self::rotate()) at a time,
+    /// instead of through a full [rebalance](Self::rebalance()).
+    fn best_single_rotation(&self) -> Option<(T, Rotation)>
+    where
+        T: Clone;
+
+    /// Returns the elements grouped into one row per level, each paired with its **positional
+    /// slot** within that level.
+    ///
+    /// The level `l` has `2^l` slots, so that the children of the node at slot `i` are always
+    /// found at slots `2i` and `2i + 1` of the next row. Only the slots holding a node are
+    /// returned, in ascending order, therefore a gap between two consecutive slots marks missing
+    /// nodes and every row is only as long as the number of nodes on its level.
+    ///
+    /// `None` is returned if a slot does not fit into a `usize`, which can only happen for nodes
+    /// at a depth greater than `usize::BITS`.
+    fn render_rows(&self) -> Option<Vec<Vec<(usize, &T)>>>;
+
+    /// Returns a reference to the **in-order predecessor** of the given value, the largest
+    /// element strictly smaller than it, or `None` if there is no such element.
+    ///
+    /// The value itself does not need to exist within the tree.
+    fn predecessor(&self, value: &T) -> Option<&T>;
+
+    /// Returns a reference to the **in-order successor** of the given value, the smallest
+    /// element strictly greater than it, or `None` if there is no such element.
+    ///
+    /// The value itself does not need to exist within the tree.
+    fn successor(&self, value: &T) -> Option<&T>;
+
+    /// Consumes the tree and returns the elements **strictly greater** than the threshold in
+    /// ascending order.
+    ///
+    /// Subtrees that only hold elements smaller than or equal to the threshold are dropped
+    /// during the consuming in-order traversal without being collected.
+    fn into_greater_than(self, threshold: &T) -> Vec<T>;
+
+    /// Returns the size of the **largest subtree** directly below the root as a fraction of the
+    /// size of the tree.
+    ///
+    /// A value close to `0.5` means removing the root splits the tree evenly, whereas a value
+    /// close to `1.0` means the tree hangs off to one side. Empty and single element trees
+    /// return `0.0`.
+    fn max_subtree_fraction(&self) -> f64;
+
+    /// Returns a **multi-line diagram** of the tree, connecting every element to its children
+    /// through `├──` and `└──` branches.
+    ///
+    /// Children are listed left before right, a node with a single child connects it through
+    /// `└──`. An empty tree returns an empty string.
+    ///
+    /// # Example
+    ///
+    /// Given a tree that looks like:
+    /// ```text
+    ///           8
+    ///         /  \
+    ///        3    10
+    ///       / \     \
+    ///      1   6     14
+    /// ```
+    /// The diagram is:
+    /// ```text
+    /// 8
+    /// ├── 3
+    /// │   ├── 1
+    /// │   └── 6
+    /// └── 10
+    ///     └── 14
+    /// ```
+    fn pretty_print(&self) -> String
+    where
+        T: Display;
+
+    /// Returns the **ancestors** of the given value, ordered from its parent up to the root, or
+    /// `None` if the value does not exist within the tree.
+    ///
+    /// The root has no ancestors, so an empty vector is returned for it.
+    fn ancestors(&self, value: &T) -> Option<Vec<&T>>;
+
+    /// Returns the **descendants** of the given value in ascending order, every element within
+    /// the subtree rooted at it apart from the value itself, or `None` if the value does not
+    /// exist within the tree.
+    ///
+    /// A leaf has no descendants, so an empty vector is returned for it.
+    fn descendants(&self, value: &T) -> Option<Vec<&T>>;
+
+    /// Returns the **rank** of the given value, the number of elements strictly smaller than it.
+    ///
+    /// The value itself does not need to exist within the tree. As every node keeps track of the
+    /// size of its subtree, this only walks a single path down from the root.
+    fn rank(&self, value: &T) -> usize;
+
+    /// Returns a reference to the `k`-th **smallest** element (starting from `0`) or `None` if
+    /// `k` is not smaller than the size of the tree.
+    ///
+    /// As every node keeps track of the size of its subtree, this only walks a single path down
+    /// from the root.
+    fn select(&self, k: usize) -> Option<&T>;
+
+    /// Rebalances only the **subtree** rooted at the given value, leaving the rest of the tree
+    /// untouched. Returns `true` if the value exists within the tree.
+    ///
+    /// This is cheaper than [rebalance](Self::rebalance()) when the imbalance is known to be
+    /// local to a single subtree.
+    fn rebalance_subtree(&mut self, value: &T) -> bool;
+
+    /// Returns an iterator over the elements within the inclusive range `[low, high]` in
+    /// **ascending order.**
+    ///
+    /// Subtrees lying entirely outside the range are never visited. Neither bound needs to exist
+    /// within the tree and an empty iterator is returned if `low` is greater than `high`.
+    fn range(&self, low: &T, high: &T) -> IntoIter<&T>;
+
+    /// Returns the number of elements falling into each of the **buckets** separated by the
+    /// given sorted boundaries.
+    ///
+    /// For `k` boundaries `k + 1` counts are returned, one for each of the half-open buckets
+    /// `(-inf, b0), [b0, b1), ..., [b(k-1), +inf)`. The counts are gathered within a single
+    /// in-order sweep of the tree.
+    fn bucket_counts(&self, boundaries: &[T]) -> Vec<usize>;
+
+    /// Removes and returns every element satisfying the given predicate in **ascending order**,
+    /// similar to `Vec::extract_if`.
+    ///
+    /// The tree is drained, split by the predicate and rebuilt as a **balanced** tree from the
+    /// remaining elements.
+    fn extract_if<F: FnMut(&T) -> bool>(&mut self, predicate: F) -> Vec<T>
+    where
+        Self: Sized;
+
+    /// Returns a reference to the element equal to the given value, inserting the value first
+    /// if it does not exist within the tree yet.
+    ///
+    /// The element is located or created within a **single** walk down from the root. Unlike
+    /// [insert()](Self::insert()), finding an existing element does not count towards
+    /// [rejected_duplicates()](Self::rejected_duplicates()).
+    fn get_or_insert(&mut self, value: T) -> &T;
+
+    /// Returns the **expected number of nodes visited** by a successful search when the elements
+    /// are accessed according to a Zipf distribution with exponent `s`, or `None` if the tree is
+    /// empty or `s` is `NaN`.
+    ///
+    /// The element of rank `r` (starting from `1` for the minimum) is accessed with a weight of
+    /// `1 / r^s`, so smaller elements are accessed more often. A lower cost means frequently
+    /// accessed elements sit closer to the root, whereas an exponent of `0` weights every element
+    /// equally and matches [expected_search_visits()](Self::expected_search_visits()).
+    fn zipf_expected_cost(&self, s: f64) -> Option<f64>;
+
+    /// Returns a new **balanced** tree containing clones of the elements that exist within
+    /// **either** tree.
+    ///
+    /// As both trees are traversed in ascending order, the elements are merged in a single pass
+    /// without any comparisons against the new tree.
+    fn union(&self, other: &Self) -> Self
+    where
+        Self: Sized,
+        T: Clone;
+
+    /// Returns a new **balanced** tree containing clones of the elements that exist within
+    /// **both** trees.
+    fn intersection(&self, other: &Self) -> Self
+    where
+        Self: Sized,
+        T: Clone;
+
+    /// Returns a new **balanced** tree containing clones of the elements that exist within this
+    /// tree but **not** within `other`.
+    fn difference(&self, other: &Self) -> Self
+    where
+        Self: Sized,
+        T: Clone;
+
+    /// Returns a compact **histogram** of the number of nodes on every level of the tree.
+    ///
+    /// Every level is rendered on its own line, starting from the root, as a bar containing one
+    /// `#` per node on that level. An empty string is returned for an empty tree.
+    fn level_width_bars(&self) -> String;
+
+    /// Returns `true` if the tree is **height-balanced**, i.e. the heights of the left and right
+    /// subtrees of every node differ by at most `1`.
+    ///
+    /// An empty tree is always balanced.
+    fn is_balanced(&self) -> bool;
+
+    /// Rebuilds the tree into a **height-balanced** shape, keeping the same elements.
+    ///
+    /// Unlike [rebalance](Self::rebalance()), the nodes are drained into a sorted list and the
+    /// tree is rebuilt by recursively picking the median of every sublist as the root of its
+    /// subtree.
+    fn balance(&mut self);
+
+    /// Returns the **diameter** of the tree, the number of edges on the longest path between any
+    /// two nodes.
+    ///
+    /// The longest path does not necessarily pass through the root. An empty tree or a tree with
+    /// a single element has a diameter of `0`.
+    ///
+    /// Given a tree that looks like:
+    /// ```text
+    ///           4
+    ///         /  \
+    ///        2    6
+    ///       / \  / \
+    ///      1  3 5   7
+    /// ```
+    /// The diameter is: **4**
+    fn diameter(&self) -> usize;
+
+    /// Returns a reference to the **lowest common ancestor** of both given values or `None` if
+    /// either value does not exist within the tree.
+    ///
+    /// The lowest common ancestor is the deepest element that has both values within its
+    /// subtree, where an element counts as being within its own subtree.
+    fn lowest_common_ancestor(&self, a: &T, b: &T) -> Option<&T>;
+
+    /// Removes every given value from the tree and returns the number of elements that were
+    /// actually removed.
+    ///
+    /// Values that do not exist within the tree are ignored and a value given more than once is
+    /// only removed (and counted) once.
+    fn remove_all(&mut self, values: &[T]) -> usize;
+
+    /// Inserts the given value into the tree, returning `Ok(())` if it was newly inserted.
+    ///
+    /// Unlike [insert()](Self::insert()), an `Err` handing the value back is returned if an equal
+    /// element already exists, so the value can be reused. Rejected values count towards
+    /// [rejected_duplicates()](Self::rejected_duplicates()).
+    fn try_insert(&mut self, value: T) -> Result<(), T>;
+
+    /// Returns the **width** of the tree, the largest number of nodes on any single level.
+    ///
+    /// An empty tree has a width of `0`.
+    ///
+    /// Given a tree that looks like:
+    /// ```text
+    ///           4
+    ///         /  \
+    ///        2    6
+    ///       / \  / \
+    ///      1  3 5   7
+    /// ```
+    /// The width is: **4**
+    fn width(&self) -> usize;
+
+    /// Returns a lazy iterator over **mutable** references to the elements in ascending order.
+    ///
+    /// # Important
+    ///
+    /// The elements are modified in place without being moved within the tree, so it is the
+    /// responsibility of the caller to keep them in the same relative order.
+    fn in_order_iter_mut(&mut self) -> InOrderIterMut<'_, T>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        build_comparison_count, dedup_trees, BinarySearchTree, IterativeBST, RecursiveBST,
+    };
+
+    #[test]
+    fn successfully_construct_bst_from_macro() {
+        let mut actual_bst = IterativeBST::new();
+        actual_bst.insert(3);
+        actual_bst.insert(2);
+        let expected_bst = bst![3, 2];
+        assert_eq!(actual_bst, expected_bst);
+    }
+
+    #[test]
+    fn verify_permutations_produce_same_tree() {
+        let actual_bst = bst![2, 3];
+        let expected_bst = bst![3, 2];
+        assert_eq!(actual_bst, expected_bst);
+    }
+
+    #[test]
+    fn successfully_dedup_trees() {
+        let trees = vec![
+            bst![1, 2],
+            bst![2, 1],
+            bst![3],
+            IterativeBST::new(),
+            bst![1, 2],
+        ];
+
+        let unique_trees = dedup_trees(trees);
+
+        assert_eq!(unique_trees.len(), 3);
+        assert_eq!(unique_trees, vec![bst![1, 2], bst![3], IterativeBST::new()]);
+    }
+
+    #[test]
+    fn successfully_dedup_recursive_trees() {
+        let trees = vec![
+            RecursiveBST::from(vec![3, 1]),
+            RecursiveBST::from(vec![1, 3]),
+            RecursiveBST::from(vec![2]),
+        ];
+
+        let unique_trees = dedup_trees(trees);
+
+        assert_eq!(
+            unique_trees,
+            vec![RecursiveBST::from(vec![1, 3]), RecursiveBST::from(vec![2])]
+        );
+    }
+
+    #[test]
+    fn successfully_downcast_trait_objects() {
+        let trees: Vec<Box<dyn BinarySearchTree<i32>>> = vec![
+            Box::new(bst![1, 2, 3]),
+            Box::new(RecursiveBST::from(vec![4, 5])),
+        ];
+        assert_eq!(trees.iter().map(|tree| tree.size()).sum::<usize>(), 5);
+
+        let iterative_bst = trees[0].as_any().downcast_ref::<IterativeBST<i32>>();
+        let recursive_bst = trees[1].as_any().downcast_ref::<RecursiveBST<i32>>();
+
+        assert_eq!(iterative_bst, Some(&bst![1, 2, 3]));
+        assert_eq!(recursive_bst, Some(&RecursiveBST::from(vec![4, 5])));
+        assert!(trees[0]
+            .as_any()
+            .downcast_ref::<RecursiveBST<i32>>()
+            .is_none());
+        assert!(trees[1]
+            .as_any()
+            .downcast_ref::<IterativeBST<i32>>()
+            .is_none());
+    }
+
+    #[test]
+    fn successfully_get_build_comparison_count() {
+        let ascending: Vec<i32> = (1..=7).collect();
+        let balanced = [4, 2, 6, 1, 3, 5, 7];
+
+        assert_eq!(build_comparison_count(&ascending), 21);
+        assert_eq!(build_comparison_count(&balanced), 10);
+        assert!(build_comparison_count(&ascending) > build_comparison_count(&balanced));
+        assert_eq!(build_comparison_count(&[1, 1, 1]), 2);
+        assert_eq!(build_comparison_count::<i32>(&[]), 0);
+    }
 }
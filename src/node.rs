@@ -1,5 +1,12 @@
 use std::cmp::{max, Ordering};
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt::Display;
+use std::iter::FusedIterator;
+use std::ops::{Add, Sub};
+
+use crate::NotSorted;
+use crate::ReconstructError;
+use crate::Rotation;
 
 pub(crate) type HeapNode<T> = Option<Box<Node<T>>>;
 
@@ -8,6 +15,8 @@ pub(crate) struct Node<T: Ord> {
     value: T,
     left: HeapNode<T>,
     right: HeapNode<T>,
+    // number of nodes within the subtree rooted at this node, including itself
+    size: usize,
 }
 
 impl<T: Ord> Node<T> {
@@ -16,40 +25,85 @@ impl<T: Ord> Node<T> {
             value,
             left: None,
             right: None,
+            size: 1,
+        }
+    }
+
+    fn with_children(value: T, left: HeapNode<T>, right: HeapNode<T>) -> Node<T> {
+        let size = 1 + Node::size_of(&left) + Node::size_of(&right);
+        Node {
+            value,
+            left,
+            right,
+            size,
         }
     }
 
-    pub(crate) fn iterative_insert(mut root: &mut HeapNode<T>, value: T) -> Result<(), ()> {
+    fn size_of(root: &HeapNode<T>) -> usize {
+        root.as_ref().map_or(0, |node| node.size)
+    }
+
+    fn update_size(&mut self) {
+        self.size = 1 + Node::size_of(&self.left) + Node::size_of(&self.right);
+    }
+
+    /// Adjusts the subtree sizes along the path to the given value by one, up to and including
+    /// the node holding the value, to undo the changes of an insertion or removal that failed.
+    fn adjust_path_sizes(mut root: &mut HeapNode<T>, value: &T, increase: bool) {
         while let Some(ref mut node) = root {
+            match increase {
+                true => node.size += 1,
+                false => node.size -= 1,
+            }
             match value.cmp(&node.value) {
-                Ordering::Equal => return Err(()),
+                Ordering::Equal => return,
                 Ordering::Less => root = &mut node.left,
                 Ordering::Greater => root = &mut node.right,
             }
         }
-        *root = Some(Box::new(Node::new(value)));
+    }
 
-        Ok(())
+    pub(crate) fn iterative_insert(root: &mut HeapNode<T>, value: T) -> Result<usize, T> {
+        let mut depth = 0;
+        let mut current = &mut *root;
+        while let Some(ref mut node) = current {
+            node.size += 1;
+            match value.cmp(&node.value) {
+                Ordering::Equal => {
+                    Node::adjust_path_sizes(root, &value, false);
+                    return Err(value);
+                }
+                Ordering::Less => current = &mut node.left,
+                Ordering::Greater => current = &mut node.right,
+            }
+            depth += 1;
+        }
+        *current = Some(Box::new(Node::new(value)));
+
+        Ok(depth)
     }
 
-    pub(crate) fn recursive_insert(&mut self, value: T) -> Result<(), ()> {
-        match value.cmp(&self.value) {
-            Ordering::Equal => Err(()),
+    pub(crate) fn recursive_insert(&mut self, value: T) -> Result<usize, T> {
+        let depth = match value.cmp(&self.value) {
+            Ordering::Equal => return Err(value),
             Ordering::Less => match self.left {
                 None => {
                     self.left = Some(Box::from(Node::new(value)));
-                    Ok(())
+                    1
                 }
-                Some(ref mut node) => node.recursive_insert(value),
+                Some(ref mut node) => node.recursive_insert(value)? + 1,
             },
             Ordering::Greater => match self.right {
                 None => {
                     self.right = Some(Box::from(Node::new(value)));
-                    Ok(())
+                    1
                 }
-                Some(ref mut node) => node.recursive_insert(value),
+                Some(ref mut node) => node.recursive_insert(value)? + 1,
             },
-        }
+        };
+
+        self.size += 1;
+        Ok(depth)
     }
 
     pub(crate) fn iterative_contains(mut root: &HeapNode<T>, value: &T) -> bool {
@@ -168,8 +222,10 @@ impl<T: Ord> Node<T> {
         }
     }
 
-    pub(crate) fn iterative_remove(mut root: &mut HeapNode<T>, value: &T) -> Result<(), ()> {
+    pub(crate) fn iterative_remove(tree: &mut HeapNode<T>, value: &T) -> Result<(), ()> {
+        let mut root = &mut *tree;
         while let Some(ref mut current) = root {
+            current.size -= 1;
             match value.cmp(&current.value) {
                 Ordering::Less => root = &mut root.as_mut().unwrap().left,
                 Ordering::Greater => root = &mut root.as_mut().unwrap().right,
@@ -189,27 +245,29 @@ impl<T: Ord> Node<T> {
             }
         }
 
+        Node::adjust_path_sizes(tree, value, true);
         Err(())
     }
 
     pub(crate) fn recursive_remove(root: &mut HeapNode<T>, value: &T) -> Result<(), ()> {
         if let Some(ref mut node) = root {
-            return match value.cmp(&node.value) {
-                Ordering::Less => Node::recursive_remove(&mut node.left, value),
-                Ordering::Greater => Node::recursive_remove(&mut node.right, value),
-                Ordering::Equal => {
-                    match (&node.left, &node.right) {
-                        (None, None) => *root = None,
-                        (Some(_), None) => *root = node.left.take(),
-                        (None, Some(_)) => *root = node.right.take(),
-                        (Some(_), Some(_)) => {
-                            node.value = Node::recursive_remove_min(&mut node.right).unwrap()
-                        }
+            match value.cmp(&node.value) {
+                Ordering::Less => Node::recursive_remove(&mut node.left, value)?,
+                Ordering::Greater => Node::recursive_remove(&mut node.right, value)?,
+                Ordering::Equal => match (&node.left, &node.right) {
+                    (None, None) => *root = None,
+                    (Some(_), None) => *root = node.left.take(),
+                    (None, Some(_)) => *root = node.right.take(),
+                    (Some(_), Some(_)) => {
+                        node.value = Node::recursive_remove_min(&mut node.right).unwrap()
                     }
+                },
+            }
+            if let Some(node) = root {
+                node.update_size();
+            }
 
-                    Ok(())
-                }
-            };
+            return Ok(());
         }
 
         Err(())
@@ -254,6 +312,7 @@ impl<T: Ord> Node<T> {
     pub(crate) fn iterative_remove_min(mut root: &mut HeapNode<T>) -> Option<T> {
         if root.is_some() {
             while root.as_ref().unwrap().left.is_some() {
+                root.as_mut().unwrap().size -= 1;
                 root = &mut root.as_mut().unwrap().left
             }
 
@@ -267,7 +326,9 @@ impl<T: Ord> Node<T> {
 
     pub(crate) fn recursive_remove_min(root: &mut HeapNode<T>) -> Option<T> {
         if root.as_ref().unwrap().left.is_some() {
-            Node::recursive_remove_min(&mut root.as_mut().unwrap().left)
+            let node = root.as_mut().unwrap();
+            node.size -= 1;
+            Node::recursive_remove_min(&mut node.left)
         } else {
             let node = root.take().unwrap();
             *root = node.right;
@@ -278,6 +339,7 @@ impl<T: Ord> Node<T> {
     pub(crate) fn iterative_remove_max(mut root: &mut HeapNode<T>) -> Option<T> {
         if root.is_some() {
             while root.as_ref().unwrap().right.is_some() {
+                root.as_mut().unwrap().size -= 1;
                 root = &mut root.as_mut().unwrap().right
             }
 
@@ -291,7 +353,9 @@ impl<T: Ord> Node<T> {
 
     pub(crate) fn recursive_remove_max(root: &mut HeapNode<T>) -> Option<T> {
         if root.as_ref().unwrap().right.is_some() {
-            Node::recursive_remove_max(&mut root.as_mut().unwrap().right)
+            let node = root.as_mut().unwrap();
+            node.size -= 1;
+            Node::recursive_remove_max(&mut node.right)
         } else {
             let node = root.take().unwrap();
             *root = node.left;
@@ -559,11 +623,2627 @@ impl<T: Ord> Node<T> {
     fn dealloc_boxes(root: HeapNode<T>) {
         if let Some(node) = root {
             // move out of the box by de-referencing to drop it and destructure the `Node`
-            let Node { value, left, right } = *node;
+            let Node {
+                value, left, right, ..
+            } = *node;
             // ensure that the value is not dropped again by forgetting it
             std::mem::forget(value);
             Node::dealloc_boxes(left);
             Node::dealloc_boxes(right);
         }
     }
-}
+
+    pub(crate) fn iterative_internal_path_length(root: &HeapNode<T>) -> usize {
+        let mut path_length = 0;
+        let mut stack = vec![(root, 0)];
+
+        while let Some((node, depth)) = stack.pop() {
+            if let Some(current) = node {
+                path_length += depth;
+                stack.push((&current.left, depth + 1));
+                stack.push((&current.right, depth + 1));
+            }
+        }
+
+        path_length
+    }
+
+    pub(crate) fn recursive_internal_path_length(root: &HeapNode<T>, depth: usize) -> usize {
+        match root {
+            None => 0,
+            Some(node) => {
+                depth
+                    + Node::recursive_internal_path_length(&node.left, depth + 1)
+                    + Node::recursive_internal_path_length(&node.right, depth + 1)
+            }
+        }
+    }
+
+    pub(crate) fn iterative_all<F: Fn(&T) -> bool>(root: &HeapNode<T>, predicate: F) -> bool {
+        let mut stack = vec![root];
+
+        while let Some(node) = stack.pop() {
+            if let Some(current) = node {
+                if !predicate(&current.value) {
+                    return false;
+                }
+                stack.push(&current.right);
+                stack.push(&current.left);
+            }
+        }
+
+        true
+    }
+
+    pub(crate) fn recursive_all<F: Fn(&T) -> bool>(root: &HeapNode<T>, predicate: &F) -> bool {
+        match root {
+            None => true,
+            Some(node) => {
+                predicate(&node.value)
+                    && Node::recursive_all(&node.left, predicate)
+                    && Node::recursive_all(&node.right, predicate)
+            }
+        }
+    }
+
+    pub(crate) fn iterative_any<F: Fn(&T) -> bool>(root: &HeapNode<T>, predicate: F) -> bool {
+        let mut stack = vec![root];
+
+        while let Some(node) = stack.pop() {
+            if let Some(current) = node {
+                if predicate(&current.value) {
+                    return true;
+                }
+                stack.push(&current.right);
+                stack.push(&current.left);
+            }
+        }
+
+        false
+    }
+
+    pub(crate) fn recursive_any<F: Fn(&T) -> bool>(root: &HeapNode<T>, predicate: &F) -> bool {
+        match root {
+            None => false,
+            Some(node) => {
+                predicate(&node.value)
+                    || Node::recursive_any(&node.left, predicate)
+                    || Node::recursive_any(&node.right, predicate)
+            }
+        }
+    }
+
+    pub(crate) fn build_balanced<I: Iterator<Item = T>>(
+        values: &mut I,
+        size: usize,
+    ) -> HeapNode<T> {
+        if size == 0 {
+            return None;
+        }
+
+        let left = Node::build_balanced(values, size / 2);
+        let value = values.next().unwrap();
+        let right = Node::build_balanced(values, size - size / 2 - 1);
+
+        Some(Box::new(Node::with_children(value, left, right)))
+    }
+
+    pub(crate) fn missing_in_range(elements: Vec<&T>, low: T, high: T) -> Vec<T>
+    where
+        T: Copy + Add<Output = T> + From<u8>,
+    {
+        let mut missing = Vec::new();
+        if low > high {
+            return missing;
+        }
+
+        let mut elements = elements
+            .into_iter()
+            .filter(|value| **value >= low)
+            .peekable();
+        let mut current = low;
+        loop {
+            match elements.peek() {
+                Some(value) if **value == current => {
+                    elements.next();
+                }
+                _ => missing.push(current),
+            }
+            if current == high {
+                break;
+            }
+            current = current + T::from(1);
+        }
+
+        missing
+    }
+
+    pub(crate) fn prefix_extremes(elements: Vec<&T>) -> Vec<(&T, &T)> {
+        let mut extremes: Vec<(&T, &T)> = Vec::with_capacity(elements.len());
+
+        for value in elements {
+            let extreme = match extremes.last() {
+                None => (value, value),
+                Some(&(min, max)) => (std::cmp::min(min, value), std::cmp::max(max, value)),
+            };
+            extremes.push(extreme);
+        }
+
+        extremes
+    }
+
+    pub(crate) fn to_ranges(elements: Vec<&T>) -> Vec<(T, T)>
+    where
+        T: Copy + Add<Output = T> + From<u8>,
+    {
+        let mut ranges: Vec<(T, T)> = Vec::new();
+
+        for &value in elements {
+            match ranges.last_mut() {
+                Some((_, end)) if *end + T::from(1) == value => *end = value,
+                _ => ranges.push((value, value)),
+            }
+        }
+
+        ranges
+    }
+
+    pub(crate) fn expand_ranges(ranges: &[(T, T)]) -> Vec<T>
+    where
+        T: Copy + Add<Output = T> + From<u8>,
+    {
+        let mut values = Vec::new();
+
+        for &(low, high) in ranges {
+            if low > high {
+                continue;
+            }
+            let mut current = low;
+            loop {
+                values.push(current);
+                if current == high {
+                    break;
+                }
+                current = current + T::from(1);
+            }
+        }
+        values.sort();
+        values.dedup();
+
+        values
+    }
+
+    pub(crate) fn rotate_left(root: &mut HeapNode<T>) {
+        let mut node = root.take().unwrap();
+        let mut right_node = node.right.take().unwrap();
+        node.right = right_node.left.take();
+        node.update_size();
+        right_node.left = Some(node);
+        right_node.update_size();
+        *root = Some(right_node);
+    }
+
+    pub(crate) fn rotate_right(root: &mut HeapNode<T>) {
+        let mut node = root.take().unwrap();
+        let mut left_node = node.left.take().unwrap();
+        node.left = left_node.right.take();
+        node.update_size();
+        left_node.right = Some(node);
+        left_node.update_size();
+        *root = Some(left_node);
+    }
+
+    pub(crate) fn dsw_rebalance(root: &mut HeapNode<T>, size: usize) -> usize {
+        let mut rotations = 0;
+        Node::dsw_rebalance_with(root, size, &mut |_, _| rotations += 1);
+        rotations
+    }
+
+    pub(crate) fn dsw_rebalance_with<F: FnMut(&T, Rotation)>(
+        root: &mut HeapNode<T>,
+        size: usize,
+        on_rotation: &mut F,
+    ) {
+        Node::tree_to_vine(root, on_rotation);
+        Node::vine_to_tree(root, size, on_rotation);
+    }
+
+    pub(crate) fn dsw_rotation_count(root: &HeapNode<T>, size: usize) -> usize {
+        let mut right_spine_length = 0;
+        let mut current = root;
+        while let Some(node) = current {
+            right_spine_length += 1;
+            current = &node.right;
+        }
+
+        let leaves = Node::<T>::vine_to_tree_leaves(size);
+        let mut vine_rotations = leaves;
+        let mut backbone = size - leaves;
+        while backbone > 1 {
+            backbone /= 2;
+            vine_rotations += backbone;
+        }
+
+        (size - right_spine_length) + vine_rotations
+    }
+
+    fn tree_to_vine<F: FnMut(&T, Rotation)>(mut root: &mut HeapNode<T>, on_rotation: &mut F) {
+        while let Some(node) = root {
+            if node.left.is_some() {
+                on_rotation(&node.value, Rotation::Right);
+                Node::rotate_right(root);
+            } else {
+                root = &mut root.as_mut().unwrap().right;
+            }
+        }
+    }
+
+    fn vine_to_tree<F: FnMut(&T, Rotation)>(
+        root: &mut HeapNode<T>,
+        size: usize,
+        on_rotation: &mut F,
+    ) {
+        let leaves = Node::<T>::vine_to_tree_leaves(size);
+        Node::compress(root, leaves, on_rotation);
+
+        let mut backbone = size - leaves;
+        while backbone > 1 {
+            backbone /= 2;
+            Node::compress(root, backbone, on_rotation);
+        }
+    }
+
+    fn vine_to_tree_leaves(size: usize) -> usize {
+        if size == 0 {
+            return 0;
+        }
+        // Nodes that do not fit into the largest perfect tree (of 2^k - 1 nodes) within `size`
+        size + 1 - (1 << (usize::BITS - 1 - (size + 1).leading_zeros()))
+    }
+
+    fn compress<F: FnMut(&T, Rotation)>(
+        mut root: &mut HeapNode<T>,
+        count: usize,
+        on_rotation: &mut F,
+    ) {
+        for _ in 0..count {
+            on_rotation(&root.as_ref().unwrap().value, Rotation::Left);
+            Node::rotate_left(root);
+            root = &mut root.as_mut().unwrap().right;
+        }
+    }
+
+    pub(crate) fn iterative_outline(root: &HeapNode<T>) -> String
+    where
+        T: Display,
+    {
+        let mut lines = Vec::new();
+        let mut stack = vec![(root, 0)];
+
+        while let Some((node, depth)) = stack.pop() {
+            if let Some(current) = node {
+                lines.push(format!("{}{}", "  ".repeat(depth), current.value));
+                stack.push((&current.right, depth + 1));
+                stack.push((&current.left, depth + 1));
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    pub(crate) fn recursive_outline(root: &HeapNode<T>, depth: usize, lines: &mut Vec<String>)
+    where
+        T: Display,
+    {
+        if let Some(node) = root {
+            lines.push(format!("{}{}", "  ".repeat(depth), node.value));
+            Node::recursive_outline(&node.left, depth + 1, lines);
+            Node::recursive_outline(&node.right, depth + 1, lines);
+        }
+    }
+
+    pub(crate) fn iterative_count(root: &HeapNode<T>) -> usize {
+        let mut count = 0;
+        let mut stack = vec![root];
+
+        while let Some(node) = stack.pop() {
+            if let Some(current) = node {
+                count += 1;
+                stack.push(&current.left);
+                stack.push(&current.right);
+            }
+        }
+
+        count
+    }
+
+    pub(crate) fn recursive_count(root: &HeapNode<T>) -> usize {
+        match root {
+            None => 0,
+            Some(node) => {
+                1 + Node::recursive_count(&node.left) + Node::recursive_count(&node.right)
+            }
+        }
+    }
+
+    pub(crate) fn iterative_rank(mut root: &HeapNode<T>, value: &T) -> usize {
+        let mut rank = 0;
+
+        while let Some(current) = root {
+            match value.cmp(&current.value) {
+                Ordering::Greater => {
+                    rank += Node::size_of(&current.left) + 1;
+                    root = &current.right;
+                }
+                _ => root = &current.left,
+            }
+        }
+
+        rank
+    }
+
+    pub(crate) fn recursive_rank(root: &HeapNode<T>, value: &T) -> usize {
+        match root {
+            None => 0,
+            Some(node) => match value.cmp(&node.value) {
+                Ordering::Greater => {
+                    Node::size_of(&node.left) + 1 + Node::recursive_rank(&node.right, value)
+                }
+                _ => Node::recursive_rank(&node.left, value),
+            },
+        }
+    }
+
+    pub(crate) fn iterative_select(mut root: &HeapNode<T>, mut k: usize) -> Option<&T> {
+        while let Some(current) = root {
+            let left_size = Node::size_of(&current.left);
+            match k.cmp(&left_size) {
+                Ordering::Equal => return Some(&current.value),
+                Ordering::Less => root = &current.left,
+                Ordering::Greater => {
+                    k -= left_size + 1;
+                    root = &current.right;
+                }
+            }
+        }
+
+        None
+    }
+
+    pub(crate) fn recursive_select(root: &HeapNode<T>, k: usize) -> Option<&T> {
+        let node = root.as_ref()?;
+        let left_size = Node::size_of(&node.left);
+        match k.cmp(&left_size) {
+            Ordering::Equal => Some(&node.value),
+            Ordering::Less => Node::recursive_select(&node.left, k),
+            Ordering::Greater => Node::recursive_select(&node.right, k - left_size - 1),
+        }
+    }
+
+    fn is_consecutive(parent: &T, child: &T) -> bool
+    where
+        T: Copy + Add<Output = T> + From<u8>,
+    {
+        child > parent && *child == *parent + T::from(1)
+    }
+
+    pub(crate) fn iterative_longest_consecutive_path(root: &HeapNode<T>) -> usize
+    where
+        T: Copy + Add<Output = T> + From<u8>,
+    {
+        let mut longest = 0;
+        let mut stack = vec![(root, None::<&T>, 0)];
+
+        while let Some((node, parent, run)) = stack.pop() {
+            if let Some(current) = node {
+                let run = match parent {
+                    Some(parent) if Node::is_consecutive(parent, &current.value) => run + 1,
+                    _ => 1,
+                };
+                longest = max(longest, run);
+                stack.push((&current.left, Some(&current.value), run));
+                stack.push((&current.right, Some(&current.value), run));
+            }
+        }
+
+        longest
+    }
+
+    pub(crate) fn recursive_longest_consecutive_path(
+        root: &HeapNode<T>,
+        parent: Option<&T>,
+        run: usize,
+    ) -> usize
+    where
+        T: Copy + Add<Output = T> + From<u8>,
+    {
+        match root {
+            None => 0,
+            Some(node) => {
+                let run = match parent {
+                    Some(parent) if Node::is_consecutive(parent, &node.value) => run + 1,
+                    _ => 1,
+                };
+                max(
+                    run,
+                    max(
+                        Node::recursive_longest_consecutive_path(
+                            &node.left,
+                            Some(&node.value),
+                            run,
+                        ),
+                        Node::recursive_longest_consecutive_path(
+                            &node.right,
+                            Some(&node.value),
+                            run,
+                        ),
+                    ),
+                )
+            }
+        }
+    }
+
+    pub(crate) fn sort_by_weight(weighted_keys: &[(T, f64)]) -> Vec<&(T, f64)> {
+        let mut sorted_keys: Vec<&(T, f64)> = weighted_keys.iter().collect();
+        sorted_keys.sort_by(|(_, weight), (_, other_weight)| other_weight.total_cmp(weight));
+        sorted_keys
+    }
+
+    pub(crate) fn merge_iter<'a, I, J>(left: I, right: J) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+        I: Iterator<Item = &'a T>,
+        J: Iterator<Item = &'a T>,
+    {
+        let mut left = left.peekable();
+        let mut right = right.peekable();
+
+        std::iter::from_fn(move || match (left.peek(), right.peek()) {
+            (Some(left_value), Some(right_value)) => match left_value.cmp(right_value) {
+                Ordering::Less => left.next(),
+                Ordering::Greater => right.next(),
+                Ordering::Equal => {
+                    right.next();
+                    left.next()
+                }
+            },
+            (Some(_), None) => left.next(),
+            (None, _) => right.next(),
+        })
+    }
+
+    pub(crate) fn iterative_balance_factor_histogram(root: &HeapNode<T>) -> HashMap<isize, usize> {
+        let mut histogram = HashMap::new();
+        let mut heights = Vec::new();
+        let mut stack = vec![(root, false)];
+
+        while let Some((node, visited)) = stack.pop() {
+            match node {
+                None => heights.push(-1),
+                Some(current) if visited => {
+                    let right_height: isize = heights.pop().unwrap();
+                    let left_height: isize = heights.pop().unwrap();
+                    *histogram.entry(left_height - right_height).or_insert(0) += 1;
+                    heights.push(1 + max(left_height, right_height));
+                }
+                Some(current) => {
+                    stack.push((node, true));
+                    stack.push((&current.right, false));
+                    stack.push((&current.left, false));
+                }
+            }
+        }
+
+        histogram
+    }
+
+    pub(crate) fn recursive_balance_factor_histogram(
+        root: &HeapNode<T>,
+        histogram: &mut HashMap<isize, usize>,
+    ) -> isize {
+        match root {
+            None => -1,
+            Some(node) => {
+                let left_height = Node::recursive_balance_factor_histogram(&node.left, histogram);
+                let right_height = Node::recursive_balance_factor_histogram(&node.right, histogram);
+                *histogram.entry(left_height - right_height).or_insert(0) += 1;
+                1 + max(left_height, right_height)
+            }
+        }
+    }
+
+    pub(crate) fn optimal_height(size: usize) -> usize {
+        match size {
+            0 => 0,
+            size => size.ilog2() as usize,
+        }
+    }
+
+    pub(crate) fn validate_traversals(
+        in_order: &[T],
+        pre_order: &[T],
+    ) -> Result<(), ReconstructError> {
+        if in_order.len() != pre_order.len() {
+            return Err(ReconstructError::LengthMismatch);
+        }
+        if in_order.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(ReconstructError::UnsortedInOrder);
+        }
+
+        let mut sorted_pre_order: Vec<&T> = pre_order.iter().collect();
+        sorted_pre_order.sort();
+        if !sorted_pre_order.into_iter().eq(in_order.iter()) {
+            return Err(ReconstructError::ElementMismatch);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn iterative_half_nodes(root: &HeapNode<T>) -> usize {
+        let mut count = 0;
+        let mut stack = vec![root];
+
+        while let Some(node) = stack.pop() {
+            if let Some(current) = node {
+                if current.left.is_some() != current.right.is_some() {
+                    count += 1;
+                }
+                stack.push(&current.left);
+                stack.push(&current.right);
+            }
+        }
+
+        count
+    }
+
+    pub(crate) fn recursive_half_nodes(root: &HeapNode<T>) -> usize {
+        match root {
+            None => 0,
+            Some(node) => {
+                let is_half_node = node.left.is_some() != node.right.is_some();
+                usize::from(is_half_node)
+                    + Node::recursive_half_nodes(&node.left)
+                    + Node::recursive_half_nodes(&node.right)
+            }
+        }
+    }
+
+    fn is_leaf(&self) -> bool {
+        self.left.is_none() && self.right.is_none()
+    }
+
+    pub(crate) fn iterative_boundary_vec(root: &HeapNode<T>) -> Vec<&T> {
+        let mut elements = Vec::new();
+        let root = match root {
+            None => return elements,
+            Some(root) => root,
+        };
+
+        elements.push(&root.value);
+        if root.is_leaf() {
+            return elements;
+        }
+
+        let mut current = root.left.as_ref();
+        while let Some(node) = current {
+            if node.is_leaf() {
+                break;
+            }
+            elements.push(&node.value);
+            current = node.left.as_ref().or(node.right.as_ref());
+        }
+
+        let mut stack = vec![&root.right, &root.left];
+        while let Some(node) = stack.pop() {
+            if let Some(current) = node {
+                if current.is_leaf() {
+                    elements.push(&current.value);
+                }
+                stack.push(&current.right);
+                stack.push(&current.left);
+            }
+        }
+
+        let mut right_boundary = Vec::new();
+        let mut current = root.right.as_ref();
+        while let Some(node) = current {
+            if node.is_leaf() {
+                break;
+            }
+            right_boundary.push(&node.value);
+            current = node.right.as_ref().or(node.left.as_ref());
+        }
+        elements.extend(right_boundary.into_iter().rev());
+
+        elements
+    }
+
+    pub(crate) fn recursive_boundary_vec(root: &HeapNode<T>) -> Vec<&T> {
+        let mut elements = Vec::new();
+        if let Some(root) = root {
+            elements.push(&root.value);
+            if !root.is_leaf() {
+                Node::recursive_left_boundary(&root.left, &mut elements);
+                Node::recursive_leaves(&root.left, &mut elements);
+                Node::recursive_leaves(&root.right, &mut elements);
+                Node::recursive_right_boundary(&root.right, &mut elements);
+            }
+        }
+        elements
+    }
+
+    fn recursive_left_boundary<'a>(root: &'a HeapNode<T>, elements: &mut Vec<&'a T>) {
+        if let Some(node) = root {
+            if !node.is_leaf() {
+                elements.push(&node.value);
+                match node.left {
+                    Some(_) => Node::recursive_left_boundary(&node.left, elements),
+                    None => Node::recursive_left_boundary(&node.right, elements),
+                }
+            }
+        }
+    }
+
+    fn recursive_leaves<'a>(root: &'a HeapNode<T>, elements: &mut Vec<&'a T>) {
+        if let Some(node) = root {
+            if node.is_leaf() {
+                elements.push(&node.value);
+            }
+            Node::recursive_leaves(&node.left, elements);
+            Node::recursive_leaves(&node.right, elements);
+        }
+    }
+
+    fn recursive_right_boundary<'a>(root: &'a HeapNode<T>, elements: &mut Vec<&'a T>) {
+        if let Some(node) = root {
+            if !node.is_leaf() {
+                match node.right {
+                    Some(_) => Node::recursive_right_boundary(&node.right, elements),
+                    None => Node::recursive_right_boundary(&node.left, elements),
+                }
+                elements.push(&node.value);
+            }
+        }
+    }
+
+    pub(crate) fn iterative_vertical_order(root: &HeapNode<T>) -> Vec<Vec<&T>> {
+        let mut columns: BTreeMap<isize, Vec<&T>> = BTreeMap::new();
+        let mut deque = VecDeque::new();
+        deque.push_back((root, 0));
+
+        while let Some((node, column)) = deque.pop_front() {
+            if let Some(current) = node {
+                columns.entry(column).or_default().push(&current.value);
+                deque.push_back((&current.left, column - 1));
+                deque.push_back((&current.right, column + 1));
+            }
+        }
+
+        columns.into_values().collect()
+    }
+
+    pub(crate) fn recursive_vertical_order(root: &HeapNode<T>) -> Vec<Vec<&T>> {
+        let mut columns = BTreeMap::new();
+        Node::recursive_columns(root, 0, 0, &mut columns);
+        columns
+            .into_values()
+            .map(|mut column: Vec<(usize, &T)>| {
+                column.sort_by_key(|(row, _)| *row);
+                column.into_iter().map(|(_, value)| value).collect()
+            })
+            .collect()
+    }
+
+    fn recursive_columns<'a>(
+        root: &'a HeapNode<T>,
+        row: usize,
+        column: isize,
+        columns: &mut BTreeMap<isize, Vec<(usize, &'a T)>>,
+    ) {
+        if let Some(node) = root {
+            columns.entry(column).or_default().push((row, &node.value));
+            Node::recursive_columns(&node.left, row + 1, column - 1, columns);
+            Node::recursive_columns(&node.right, row + 1, column + 1, columns);
+        }
+    }
+
+    pub(crate) fn iterative_diagonal_vec(root: &HeapNode<T>) -> Vec<Vec<&T>> {
+        let mut diagonals = Vec::new();
+        let mut deque = VecDeque::new();
+        if let Some(root) = root {
+            deque.push_back(root);
+        }
+
+        while !deque.is_empty() {
+            let mut diagonal = Vec::new();
+            for _ in 0..deque.len() {
+                let mut current = deque.pop_front();
+                while let Some(node) = current {
+                    diagonal.push(&node.value);
+                    if let Some(left) = &node.left {
+                        deque.push_back(left);
+                    }
+                    current = node.right.as_ref();
+                }
+            }
+            diagonals.push(diagonal);
+        }
+
+        diagonals
+    }
+
+    pub(crate) fn recursive_diagonal_vec<'a>(
+        root: &'a HeapNode<T>,
+        diagonal: usize,
+        diagonals: &mut Vec<Vec<&'a T>>,
+    ) {
+        if let Some(node) = root {
+            if diagonals.len() == diagonal {
+                diagonals.push(Vec::new());
+            }
+            diagonals[diagonal].push(&node.value);
+            Node::recursive_diagonal_vec(&node.left, diagonal + 1, diagonals);
+            Node::recursive_diagonal_vec(&node.right, diagonal, diagonals);
+        }
+    }
+
+    pub(crate) fn iterative_max_path_sum_to_leaf(root: &HeapNode<T>) -> Option<(T, Vec<&T>)>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        let mut best: Option<(T, Vec<&T>)> = None;
+        let mut path = Vec::new();
+        let mut stack = Vec::new();
+        if let Some(root) = root {
+            stack.push((root, 0, root.value));
+        }
+
+        while let Some((current, depth, sum)) = stack.pop() {
+            path.truncate(depth);
+            path.push(&current.value);
+            if current.is_leaf() {
+                if best.as_ref().is_none_or(|(best_sum, _)| sum > *best_sum) {
+                    best = Some((sum, path.clone()));
+                }
+                continue;
+            }
+            if let Some(right) = &current.right {
+                stack.push((right, depth + 1, sum + right.value));
+            }
+            if let Some(left) = &current.left {
+                stack.push((left, depth + 1, sum + left.value));
+            }
+        }
+
+        best
+    }
+
+    pub(crate) fn recursive_max_path_sum_to_leaf(root: &HeapNode<T>) -> Option<(T, Vec<&T>)>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        let node = root.as_ref()?;
+        let left = Node::recursive_max_path_sum_to_leaf(&node.left);
+        let right = Node::recursive_max_path_sum_to_leaf(&node.right);
+        let best = match (left, right) {
+            (Some(left), Some(right)) if right.0 > left.0 => Some(right),
+            (Some(left), _) => Some(left),
+            (None, right) => right,
+        };
+
+        match best {
+            None => Some((node.value, vec![&node.value])),
+            Some((sum, mut path)) => {
+                path.insert(0, &node.value);
+                Some((node.value + sum, path))
+            }
+        }
+    }
+
+    pub(crate) fn center(root: &HeapNode<T>) -> Option<&T> {
+        let mut elements = Vec::new();
+        let mut neighbours: Vec<Vec<usize>> = Vec::new();
+        let mut stack = vec![(root.as_ref()?, None::<usize>)];
+
+        while let Some((current, parent)) = stack.pop() {
+            let index = elements.len();
+            elements.push(&current.value);
+            neighbours.push(Vec::new());
+            if let Some(parent) = parent {
+                neighbours[parent].push(index);
+                neighbours[index].push(parent);
+            }
+            if let Some(left) = &current.left {
+                stack.push((left, Some(index)));
+            }
+            if let Some(right) = &current.right {
+                stack.push((right, Some(index)));
+            }
+        }
+
+        let breadth_first_search = |start: usize| {
+            let mut parents = vec![None; elements.len()];
+            let mut visited = vec![false; elements.len()];
+            let mut deque = VecDeque::from([start]);
+            let mut farthest = start;
+            visited[start] = true;
+
+            while let Some(index) = deque.pop_front() {
+                farthest = index;
+                for &neighbour in &neighbours[index] {
+                    if !visited[neighbour] {
+                        visited[neighbour] = true;
+                        parents[neighbour] = Some(index);
+                        deque.push_back(neighbour);
+                    }
+                }
+            }
+
+            (farthest, parents)
+        };
+
+        // Both ends of a longest path are found with two searches, the center is in its middle.
+        let (start, _) = breadth_first_search(0);
+        let (end, parents) = breadth_first_search(start);
+        let mut path = vec![end];
+        while let Some(parent) = parents[*path.last().unwrap()] {
+            path.push(parent);
+        }
+
+        let middle = path.len() / 2;
+        match path.len() % 2 {
+            0 => Some(elements[path[middle - 1]].min(elements[path[middle]])),
+            _ => Some(elements[path[middle]]),
+        }
+    }
+
+    pub(crate) fn iterative_range_vec<'a>(root: &'a HeapNode<T>, low: &T, high: &T) -> Vec<&'a T> {
+        let mut elements = Vec::new();
+        let mut stack = Vec::new();
+        let mut current = root.as_ref();
+
+        loop {
+            while let Some(node) = current {
+                stack.push(node);
+                current = match node.value > *low {
+                    true => node.left.as_ref(),
+                    false => None,
+                };
+            }
+            match stack.pop() {
+                None => break,
+                Some(node) => {
+                    if node.value >= *low && node.value <= *high {
+                        elements.push(&node.value);
+                    }
+                    if node.value < *high {
+                        current = node.right.as_ref();
+                    }
+                }
+            }
+        }
+
+        elements
+    }
+
+    pub(crate) fn recursive_range_vec<'a>(
+        root: &'a HeapNode<T>,
+        low: &T,
+        high: &T,
+        elements: &mut Vec<&'a T>,
+    ) {
+        if let Some(node) = root {
+            if node.value > *low {
+                Node::recursive_range_vec(&node.left, low, high, elements);
+            }
+            if node.value >= *low && node.value <= *high {
+                elements.push(&node.value);
+            }
+            if node.value < *high {
+                Node::recursive_range_vec(&node.right, low, high, elements);
+            }
+        }
+    }
+
+    pub(crate) fn zip_compare<'a, I, J>(left: I, right: J) -> Vec<(Option<&'a T>, Option<&'a T>)>
+    where
+        I: Iterator<Item = &'a T>,
+        J: Iterator<Item = &'a T>,
+    {
+        let mut pairs = Vec::new();
+        let mut left = left.peekable();
+        let mut right = right.peekable();
+
+        loop {
+            let pair = match (left.peek(), right.peek()) {
+                (Some(left_value), Some(right_value)) => match left_value.cmp(right_value) {
+                    Ordering::Less => (left.next(), None),
+                    Ordering::Greater => (None, right.next()),
+                    Ordering::Equal => (left.next(), right.next()),
+                },
+                (Some(_), None) => (left.next(), None),
+                (None, Some(_)) => (None, right.next()),
+                (None, None) => break,
+            };
+            pairs.push(pair);
+        }
+
+        pairs
+    }
+
+    pub(crate) fn from_eytzinger(slots: &mut Vec<Option<T>>, index: usize) -> HeapNode<T> {
+        let value = slots.get_mut(index)?.take()?;
+        let left = Node::from_eytzinger(slots, 2 * index + 1);
+        let right = Node::from_eytzinger(slots, 2 * index + 2);
+
+        Some(Box::new(Node::with_children(value, left, right)))
+    }
+
+    pub(crate) fn iterative_count_greater(mut root: &HeapNode<T>, value: &T) -> usize {
+        let mut count = 0;
+
+        while let Some(current) = root {
+            match value.cmp(&current.value) {
+                Ordering::Less => {
+                    count += Node::size_of(&current.right) + 1;
+                    root = &current.left;
+                }
+                _ => root = &current.right,
+            }
+        }
+
+        count
+    }
+
+    pub(crate) fn recursive_count_greater(root: &HeapNode<T>, value: &T) -> usize {
+        match root {
+            None => 0,
+            Some(node) => match value.cmp(&node.value) {
+                Ordering::Less => {
+                    Node::size_of(&node.right)
+                        + 1
+                        + Node::recursive_count_greater(&node.left, value)
+                }
+                _ => Node::recursive_count_greater(&node.right, value),
+            },
+        }
+    }
+
+    pub(crate) fn iterative_splice(mut root: &mut HeapNode<T>, subtree: Box<Node<T>>) {
+        while let Some(ref mut node) = root {
+            node.size += subtree.size;
+            match subtree.value.cmp(&node.value) {
+                Ordering::Less => root = &mut node.left,
+                _ => root = &mut node.right,
+            }
+        }
+        *root = Some(subtree);
+    }
+
+    pub(crate) fn recursive_splice(root: &mut HeapNode<T>, subtree: Box<Node<T>>) {
+        match root {
+            None => *root = Some(subtree),
+            Some(node) => {
+                node.size += subtree.size;
+                match subtree.value.cmp(&node.value) {
+                    Ordering::Less => Node::recursive_splice(&mut node.left, subtree),
+                    _ => Node::recursive_splice(&mut node.right, subtree),
+                }
+            }
+        }
+    }
+
+    pub(crate) fn iterative_deepest_level_width(root: &HeapNode<T>) -> usize {
+        let mut level: Vec<&Node<T>> = root.iter().map(|node| node.as_ref()).collect();
+        let mut width = 0;
+
+        while !level.is_empty() {
+            width = level.len();
+            level = level
+                .into_iter()
+                .flat_map(|node| node.left.iter().chain(node.right.iter()))
+                .map(|node| node.as_ref())
+                .collect();
+        }
+
+        width
+    }
+
+    pub(crate) fn recursive_deepest_level_width(root: &HeapNode<T>) -> usize {
+        let mut elements = Vec::new();
+        let height = Node::recursive_height(root);
+        Node::recursive_current_level(root, &mut elements, height + 1);
+        elements.len()
+    }
+
+    pub(crate) fn iterative_count_deeper_than(root: &HeapNode<T>, max_depth: usize) -> usize {
+        let mut count = 0;
+        let mut stack = vec![(root, 0)];
+
+        while let Some((node, depth)) = stack.pop() {
+            if let Some(current) = node {
+                if depth > max_depth {
+                    count += 1;
+                }
+                stack.push((&current.left, depth + 1));
+                stack.push((&current.right, depth + 1));
+            }
+        }
+
+        count
+    }
+
+    pub(crate) fn recursive_count_deeper_than(
+        root: &HeapNode<T>,
+        depth: usize,
+        max_depth: usize,
+    ) -> usize {
+        match root {
+            None => 0,
+            Some(node) => {
+                usize::from(depth > max_depth)
+                    + Node::recursive_count_deeper_than(&node.left, depth + 1, max_depth)
+                    + Node::recursive_count_deeper_than(&node.right, depth + 1, max_depth)
+            }
+        }
+    }
+
+    pub(crate) fn iterative_longest_zigzag(root: &HeapNode<T>) -> usize {
+        let mut longest = 0;
+        let mut stack = Vec::new();
+        if let Some(root) = root {
+            stack.push((root, 0, 0));
+        }
+
+        // Every node tracks the length of the zigzag ending at it through a left or right edge
+        while let Some((current, left_run, right_run)) = stack.pop() {
+            longest = max(longest, max(left_run, right_run));
+            if let Some(left) = &current.left {
+                stack.push((left, right_run + 1, 0));
+            }
+            if let Some(right) = &current.right {
+                stack.push((right, 0, left_run + 1));
+            }
+        }
+
+        longest
+    }
+
+    pub(crate) fn recursive_longest_zigzag(
+        root: &HeapNode<T>,
+        left_run: usize,
+        right_run: usize,
+    ) -> usize {
+        match root {
+            None => 0,
+            Some(node) => max(
+                max(left_run, right_run),
+                max(
+                    Node::recursive_longest_zigzag(&node.left, right_run + 1, 0),
+                    Node::recursive_longest_zigzag(&node.right, 0, left_run + 1),
+                ),
+            ),
+        }
+    }
+
+    pub(crate) fn iterative_same_shape<U: Ord>(root: &HeapNode<T>, other: &HeapNode<U>) -> bool {
+        let mut stack = vec![(root, other)];
+
+        while let Some(nodes) = stack.pop() {
+            match nodes {
+                (None, None) => {}
+                (Some(node), Some(other_node)) => {
+                    stack.push((&node.left, &other_node.left));
+                    stack.push((&node.right, &other_node.right));
+                }
+                _ => return false,
+            }
+        }
+
+        true
+    }
+
+    pub(crate) fn recursive_same_shape<U: Ord>(root: &HeapNode<T>, other: &HeapNode<U>) -> bool {
+        match (root, other) {
+            (None, None) => true,
+            (Some(node), Some(other_node)) => {
+                Node::recursive_same_shape(&node.left, &other_node.left)
+                    && Node::recursive_same_shape(&node.right, &other_node.right)
+            }
+            _ => false,
+        }
+    }
+
+    pub(crate) fn prefix_sums<'a, I>(elements: I) -> Vec<T>
+    where
+        T: Add<Output = T> + Copy + 'a,
+        I: Iterator<Item = &'a T>,
+    {
+        let mut sums: Vec<T> = Vec::new();
+        for &value in elements {
+            sums.push(match sums.last() {
+                None => value,
+                Some(&sum) => sum + value,
+            });
+        }
+        sums
+    }
+
+    pub(crate) fn range_sum(prefix_sums: &[T], start: usize, end: usize) -> Option<T>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        if start >= end {
+            return None;
+        }
+
+        let sum = *prefix_sums.get(end - 1)?;
+        match start {
+            0 => Some(sum),
+            start => Some(sum - prefix_sums[start - 1]),
+        }
+    }
+
+    pub(crate) fn iterative_insertion_depth(mut root: &HeapNode<T>, value: &T) -> Option<usize> {
+        let mut depth = 0;
+        while let Some(node) = root {
+            match value.cmp(&node.value) {
+                Ordering::Equal => return None,
+                Ordering::Less => root = &node.left,
+                Ordering::Greater => root = &node.right,
+            }
+            depth += 1;
+        }
+        Some(depth)
+    }
+
+    pub(crate) fn recursive_insertion_depth(root: &HeapNode<T>, value: &T) -> Option<usize> {
+        match root {
+            None => Some(0),
+            Some(node) => match value.cmp(&node.value) {
+                Ordering::Equal => None,
+                Ordering::Less => {
+                    Node::recursive_insertion_depth(&node.left, value).map(|depth| depth + 1)
+                }
+                Ordering::Greater => {
+                    Node::recursive_insertion_depth(&node.right, value).map(|depth| depth + 1)
+                }
+            },
+        }
+    }
+
+    pub(crate) fn build_with_height<I: Iterator<Item = T>>(
+        values: &mut I,
+        mut size: usize,
+        mut height: usize,
+    ) -> HeapNode<T> {
+        let mut root = None;
+        let mut current = &mut root;
+
+        // The right spine carries the height whereas the left subtrees are filled up balanced
+        while size > 0 {
+            let capacity = 1_usize
+                .checked_shl(height as u32)
+                .map_or(usize::MAX, |capacity| capacity - 1);
+            let right_size = max(height, (size - 1).saturating_sub(capacity));
+            let left = Node::build_balanced(values, size - 1 - right_size);
+            let value = values.next().unwrap();
+
+            *current = Some(Box::new(Node {
+                value,
+                left,
+                right: None,
+                size,
+            }));
+            current = &mut current.as_mut().unwrap().right;
+            size = right_size;
+            height = height.saturating_sub(1);
+        }
+
+        root
+    }
+
+    pub(crate) fn iterative_iter_annotated<'a>(
+        root: &'a HeapNode<T>,
+    ) -> impl Iterator<Item = (&'a T, bool)>
+    where
+        T: 'a,
+    {
+        let mut stack = Vec::new();
+        let mut current = root.as_ref();
+
+        std::iter::from_fn(move || {
+            while let Some(node) = current {
+                stack.push(node);
+                current = node.left.as_ref();
+            }
+            let node = stack.pop()?;
+            current = node.right.as_ref();
+            Some((&node.value, node.is_leaf()))
+        })
+    }
+
+    pub(crate) fn recursive_iter_annotated<'a>(
+        root: &'a HeapNode<T>,
+        elements: &mut Vec<(&'a T, bool)>,
+    ) {
+        if let Some(node) = root {
+            Node::recursive_iter_annotated(&node.left, elements);
+            elements.push((&node.value, node.is_leaf()));
+            Node::recursive_iter_annotated(&node.right, elements);
+        }
+    }
+
+    pub(crate) fn rotate(root: &mut HeapNode<T>, rotation: Rotation) -> bool {
+        let can_rotate = match (root.as_ref(), rotation) {
+            (None, _) => false,
+            (Some(node), Rotation::Left) => node.right.is_some(),
+            (Some(node), Rotation::Right) => node.left.is_some(),
+        };
+
+        match (can_rotate, rotation) {
+            (false, _) => return false,
+            (true, Rotation::Left) => Node::rotate_left(root),
+            (true, Rotation::Right) => Node::rotate_right(root),
+        }
+
+        true
+    }
+
+    pub(crate) fn iterative_rotate(
+        mut root: &mut HeapNode<T>,
+        pivot: &T,
+        rotation: Rotation,
+    ) -> bool {
+        while let Some(ref mut current) = root {
+            match pivot.cmp(&current.value) {
+                Ordering::Less => root = &mut root.as_mut().unwrap().left,
+                Ordering::Greater => root = &mut root.as_mut().unwrap().right,
+                Ordering::Equal => return Node::rotate(root, rotation),
+            }
+        }
+
+        false
+    }
+
+    pub(crate) fn recursive_rotate(root: &mut HeapNode<T>, pivot: &T, rotation: Rotation) -> bool {
+        match root {
+            None => false,
+            Some(node) => match pivot.cmp(&node.value) {
+                Ordering::Less => Node::recursive_rotate(&mut node.left, pivot, rotation),
+                Ordering::Greater => Node::recursive_rotate(&mut node.right, pivot, rotation),
+                Ordering::Equal => Node::rotate(root, rotation),
+            },
+        }
+    }
+
+    pub(crate) fn recursive_splay(root: &mut HeapNode<T>, value: &T) {
+        let node = match root {
+            None => return,
+            Some(node) => node,
+        };
+
+        match value.cmp(&node.value) {
+            Ordering::Equal => {}
+            Ordering::Less => {
+                let left = match node.left.as_mut() {
+                    None => return,
+                    Some(left) => left,
+                };
+                match value.cmp(&left.value) {
+                    Ordering::Equal => {}
+                    // Zig-zig: the grandparent is rotated first, then the parent
+                    Ordering::Less => {
+                        Node::recursive_splay(&mut left.left, value);
+                        Node::rotate_right(root);
+                    }
+                    // Zig-zag: the element is rotated above its parent, then its grandparent
+                    Ordering::Greater => {
+                        Node::recursive_splay(&mut left.right, value);
+                        if left.right.is_some() {
+                            Node::rotate_left(&mut node.left);
+                        }
+                    }
+                }
+                if root.as_ref().unwrap().left.is_some() {
+                    Node::rotate_right(root);
+                }
+            }
+            Ordering::Greater => {
+                let right = match node.right.as_mut() {
+                    None => return,
+                    Some(right) => right,
+                };
+                match value.cmp(&right.value) {
+                    Ordering::Equal => {}
+                    Ordering::Greater => {
+                        Node::recursive_splay(&mut right.right, value);
+                        Node::rotate_left(root);
+                    }
+                    Ordering::Less => {
+                        Node::recursive_splay(&mut right.left, value);
+                        if right.left.is_some() {
+                            Node::rotate_right(&mut node.right);
+                        }
+                    }
+                }
+                if root.as_ref().unwrap().right.is_some() {
+                    Node::rotate_left(root);
+                }
+            }
+        }
+    }
+
+    /// Splays the node holding the given value, or the last node reached while searching for it,
+    /// to the root top-down and returns `true` if the value was found.
+    ///
+    /// Nodes smaller and larger than the value are split off the search path into a left and a
+    /// right tree, which become the subtrees of the new root once the search ends. Their sizes
+    /// are only fixed on reassembly, so no parent links or recursion are needed.
+    pub(crate) fn iterative_splay(root: &mut HeapNode<T>, value: &T) -> bool {
+        let mut current = match root.take() {
+            None => return false,
+            Some(node) => node,
+        };
+        // Every node is linked below the one pushed before it once the search ends
+        let mut left_nodes: Vec<Box<Node<T>>> = Vec::new();
+        let mut right_nodes: Vec<Box<Node<T>>> = Vec::new();
+
+        loop {
+            match value.cmp(&current.value) {
+                Ordering::Equal => break,
+                Ordering::Less => {
+                    let mut left = match current.left.take() {
+                        None => break,
+                        Some(left) => left,
+                    };
+                    // Zig-zig: the parent is rotated over its left child before it is split off
+                    if *value < left.value {
+                        current.left = left.right.take();
+                        current.update_size();
+                        left.right = Some(current);
+                        current = left;
+                        left = match current.left.take() {
+                            None => break,
+                            Some(left) => left,
+                        };
+                    }
+                    right_nodes.push(current);
+                    current = left;
+                }
+                Ordering::Greater => {
+                    let mut right = match current.right.take() {
+                        None => break,
+                        Some(right) => right,
+                    };
+                    if *value > right.value {
+                        current.right = right.left.take();
+                        current.update_size();
+                        right.left = Some(current);
+                        current = right;
+                        right = match current.right.take() {
+                            None => break,
+                            Some(right) => right,
+                        };
+                    }
+                    left_nodes.push(current);
+                    current = right;
+                }
+            }
+        }
+
+        let mut left_tree = current.left.take();
+        for mut node in left_nodes.into_iter().rev() {
+            node.right = left_tree;
+            node.update_size();
+            left_tree = Some(node);
+        }
+        let mut right_tree = current.right.take();
+        for mut node in right_nodes.into_iter().rev() {
+            node.left = right_tree;
+            node.update_size();
+            right_tree = Some(node);
+        }
+        current.left = left_tree;
+        current.right = right_tree;
+        current.update_size();
+
+        let found = current.value == *value;
+        *root = Some(current);
+        found
+    }
+
+    pub(crate) fn iterative_find_splay<'a>(root: &'a mut HeapNode<T>, value: &T) -> Option<&'a T> {
+        match Node::iterative_splay(root, value) {
+            true => root.as_ref().map(|node| &node.value),
+            false => None,
+        }
+    }
+
+    pub(crate) fn recursive_find_splay<'a>(root: &'a mut HeapNode<T>, value: &T) -> Option<&'a T> {
+        Node::recursive_splay(root, value);
+        match root {
+            Some(node) if node.value == *value => Some(&node.value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn splay_insert(root: &mut HeapNode<T>, value: T) -> Result<(), T> {
+        Node::iterative_splay(root, &value);
+
+        let mut node = Node::new(value);
+        match root.take() {
+            None => {}
+            Some(mut current) => match node.value.cmp(&current.value) {
+                Ordering::Equal => {
+                    *root = Some(current);
+                    return Err(node.value);
+                }
+                Ordering::Less => {
+                    node.left = current.left.take();
+                    current.update_size();
+                    node.right = Some(current);
+                }
+                Ordering::Greater => {
+                    node.right = current.right.take();
+                    current.update_size();
+                    node.left = Some(current);
+                }
+            },
+        }
+
+        node.update_size();
+        *root = Some(Box::new(node));
+        Ok(())
+    }
+
+    pub(crate) fn splay_remove(root: &mut HeapNode<T>, value: &T) -> Result<(), ()> {
+        if !Node::iterative_splay(root, value) {
+            return Err(());
+        }
+
+        let mut node = root.take().unwrap();
+        *root = match node.left.take() {
+            None => node.right.take(),
+            Some(left) => {
+                // Every element on the left is smaller, so splaying brings the maximum to the top
+                let mut left = Some(left);
+                Node::iterative_splay(&mut left, value);
+                let top = left.as_mut().unwrap();
+                top.right = node.right.take();
+                top.update_size();
+                left
+            }
+        };
+
+        Ok(())
+    }
+
+    pub(crate) fn splay_retrieve_as_mut<'a>(
+        root: &'a mut HeapNode<T>,
+        value: &T,
+    ) -> Option<&'a mut T> {
+        match Node::iterative_splay(root, value) {
+            true => root.as_mut().map(|node| &mut node.value),
+            false => None,
+        }
+    }
+
+    pub(crate) fn splay_get_or_insert(root: &mut HeapNode<T>, value: T) -> (&T, bool) {
+        let inserted = Node::splay_insert(root, value).is_ok();
+        (&root.as_ref().unwrap().value, inserted)
+    }
+
+    pub(crate) fn jaccard<'a, I, J>(left: I, right: J) -> f64
+    where
+        T: 'a,
+        I: Iterator<Item = &'a T>,
+        J: Iterator<Item = &'a T>,
+    {
+        match Node::diff_sizes(left, right) {
+            (0, 0, 0) => 1.0,
+            (only_left, both, only_right) => both as f64 / (only_left + both + only_right) as f64,
+        }
+    }
+
+    pub(crate) fn diff_sizes<'a, I, J>(left: I, right: J) -> (usize, usize, usize)
+    where
+        T: 'a,
+        I: Iterator<Item = &'a T>,
+        J: Iterator<Item = &'a T>,
+    {
+        let (mut only_left, mut both, mut only_right) = (0, 0, 0);
+        let mut left = left.peekable();
+        let mut right = right.peekable();
+
+        loop {
+            match (left.peek(), right.peek()) {
+                (Some(left_value), Some(right_value)) => match left_value.cmp(right_value) {
+                    Ordering::Less => {
+                        left.next();
+                        only_left += 1;
+                    }
+                    Ordering::Greater => {
+                        right.next();
+                        only_right += 1;
+                    }
+                    Ordering::Equal => {
+                        left.next();
+                        right.next();
+                        both += 1;
+                    }
+                },
+                (Some(_), None) => {
+                    left.next();
+                    only_left += 1;
+                }
+                (None, Some(_)) => {
+                    right.next();
+                    only_right += 1;
+                }
+                (None, None) => break,
+            }
+        }
+
+        (only_left, both, only_right)
+    }
+
+    pub(crate) fn iterative_iter_after<'a>(
+        root: &'a HeapNode<T>,
+        value: &T,
+    ) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        let mut stack = Vec::new();
+        let mut current = root.as_ref();
+        while let Some(node) = current {
+            if node.value > *value {
+                stack.push(node);
+                current = node.left.as_ref();
+            } else {
+                current = node.right.as_ref();
+            }
+        }
+
+        std::iter::from_fn(move || {
+            while let Some(node) = current {
+                stack.push(node);
+                current = node.left.as_ref();
+            }
+            let node = stack.pop()?;
+            current = node.right.as_ref();
+            Some(&node.value)
+        })
+    }
+
+    pub(crate) fn recursive_iter_after<'a>(
+        root: &'a HeapNode<T>,
+        value: &T,
+        elements: &mut Vec<&'a T>,
+    ) {
+        if let Some(node) = root {
+            if node.value > *value {
+                Node::recursive_iter_after(&node.left, value, elements);
+                elements.push(&node.value);
+            }
+            Node::recursive_iter_after(&node.right, value, elements);
+        }
+    }
+
+    pub(crate) fn fill_ratio(height: isize, size: usize) -> f64 {
+        size as f64 / (2f64.powi(height as i32 + 1) - 1.0)
+    }
+
+    pub(crate) fn iterative_subtree_balance_scores(root: &HeapNode<T>) -> Vec<(&T, f64)> {
+        let mut scores = Vec::new();
+        let mut subtrees: Vec<(isize, usize)> = Vec::new();
+        let mut stack = vec![(root, 0, 0)];
+
+        while let Some((node, state, index)) = stack.pop() {
+            match (node, state) {
+                (None, _) => subtrees.push((-1, 0)),
+                (Some(current), 0) => {
+                    stack.push((node, 1, 0));
+                    stack.push((&current.left, 0, 0));
+                }
+                (Some(current), 1) => {
+                    stack.push((node, 2, scores.len()));
+                    stack.push((&current.right, 0, 0));
+                    scores.push((&current.value, 0.0));
+                }
+                (Some(_), _) => {
+                    let (right_height, right_size) = subtrees.pop().unwrap();
+                    let (left_height, left_size) = subtrees.pop().unwrap();
+                    let height = 1 + max(left_height, right_height);
+                    let size = 1 + left_size + right_size;
+                    scores[index].1 = Node::<T>::fill_ratio(height, size);
+                    subtrees.push((height, size));
+                }
+            }
+        }
+
+        scores
+    }
+
+    pub(crate) fn recursive_subtree_balance_scores<'a>(
+        root: &'a HeapNode<T>,
+        scores: &mut Vec<(&'a T, f64)>,
+    ) -> (isize, usize) {
+        match root {
+            None => (-1, 0),
+            Some(node) => {
+                let (left_height, left_size) =
+                    Node::recursive_subtree_balance_scores(&node.left, scores);
+                let index = scores.len();
+                scores.push((&node.value, 0.0));
+                let (right_height, right_size) =
+                    Node::recursive_subtree_balance_scores(&node.right, scores);
+
+                let height = 1 + max(left_height, right_height);
+                let size = 1 + left_size + right_size;
+                scores[index].1 = Node::<T>::fill_ratio(height, size);
+                (height, size)
+            }
+        }
+    }
+
+    pub(crate) fn collect_strictly_increasing<I: IntoIterator<Item = T>>(
+        iter: I,
+    ) -> Result<Vec<T>, NotSorted> {
+        let mut elements: Vec<T> = Vec::new();
+        for (position, value) in iter.into_iter().enumerate() {
+            if elements.last().is_some_and(|last| *last >= value) {
+                return Err(NotSorted { position });
+            }
+            elements.push(value);
+        }
+
+        Ok(elements)
+    }
+
+    pub(crate) fn iterative_remap_shape<U: Ord, F: FnMut(&T) -> U>(
+        root: &HeapNode<T>,
+        mut f: F,
+    ) -> HeapNode<U> {
+        let mut remapped_root = None;
+        let mut stack = vec![(root, &mut remapped_root)];
+
+        while let Some((node, remapped)) = stack.pop() {
+            if let Some(current) = node {
+                let mut remapped_node = Node::new(f(&current.value));
+                remapped_node.size = current.size;
+                let Node { left, right, .. } = &mut **remapped.insert(Box::new(remapped_node));
+                stack.push((&current.right, right));
+                stack.push((&current.left, left));
+            }
+        }
+
+        remapped_root
+    }
+
+    pub(crate) fn recursive_remap_shape<U: Ord, F: FnMut(&T) -> U>(
+        root: &HeapNode<T>,
+        f: &mut F,
+    ) -> HeapNode<U> {
+        root.as_ref().map(|node| {
+            let value = f(&node.value);
+            Box::new(Node {
+                value,
+                left: Node::recursive_remap_shape(&node.left, f),
+                right: Node::recursive_remap_shape(&node.right, f),
+                size: node.size,
+            })
+        })
+    }
+
+    pub(crate) fn insert_comparisons(root: &mut HeapNode<T>, value: T) -> usize {
+        let mut comparisons = 0;
+        let mut current = &mut *root;
+        while let Some(ref mut node) = current {
+            comparisons += 1;
+            node.size += 1;
+            match value.cmp(&node.value) {
+                Ordering::Equal => {
+                    Node::adjust_path_sizes(root, &value, false);
+                    return comparisons;
+                }
+                Ordering::Less => current = &mut node.left,
+                Ordering::Greater => current = &mut node.right,
+            }
+        }
+        *current = Some(Box::new(Node::new(value)));
+
+        comparisons
+    }
+
+    pub(crate) fn iterative_consume_k_smallest(root: HeapNode<T>, k: usize) -> Vec<T> {
+        let mut elements = Vec::new();
+        let mut stack = vec![root];
+
+        while elements.len() < k {
+            match stack.pop() {
+                None => break,
+                Some(None) => {}
+                Some(Some(mut current)) => {
+                    if current.left.is_some() {
+                        let left_node = current.left.take();
+                        stack.push(Some(current));
+                        stack.push(left_node);
+                    } else {
+                        let right_node = current.right.take();
+                        elements.push(current.value);
+                        stack.push(right_node);
+                    }
+                }
+            }
+        }
+
+        for node in stack {
+            Node::drop_iteratively(node);
+        }
+
+        elements
+    }
+
+    pub(crate) fn recursive_consume_k_smallest(node: HeapNode<T>, k: usize, elements: &mut Vec<T>) {
+        if let Some(mut node) = node {
+            if elements.len() < k {
+                Node::recursive_consume_k_smallest(node.left.take(), k, elements);
+            }
+            if elements.len() < k {
+                elements.push(node.value);
+                Node::recursive_consume_k_smallest(node.right, k, elements);
+            } else {
+                Node::drop_iteratively(Some(node));
+            }
+        }
+    }
+
+    pub(crate) fn iterative_common_path<'a>(mut root: &'a HeapNode<T>, a: &T, b: &T) -> Vec<&'a T> {
+        let mut path = Vec::new();
+        while let Some(node) = root {
+            path.push(&node.value);
+            match (a.cmp(&node.value), b.cmp(&node.value)) {
+                (Ordering::Less, Ordering::Less) => root = &node.left,
+                (Ordering::Greater, Ordering::Greater) => root = &node.right,
+                _ => break,
+            }
+        }
+
+        path
+    }
+
+    pub(crate) fn recursive_common_path<'a>(
+        root: &'a HeapNode<T>,
+        a: &T,
+        b: &T,
+        path: &mut Vec<&'a T>,
+    ) {
+        if let Some(node) = root {
+            path.push(&node.value);
+            match (a.cmp(&node.value), b.cmp(&node.value)) {
+                (Ordering::Less, Ordering::Less) => {
+                    Node::recursive_common_path(&node.left, a, b, path)
+                }
+                (Ordering::Greater, Ordering::Greater) => {
+                    Node::recursive_common_path(&node.right, a, b, path)
+                }
+                _ => {}
+            }
+        }
+    }
+
+    pub(crate) fn iterative_depth_histogram(root: &HeapNode<T>) -> Vec<usize> {
+        let mut histogram = Vec::new();
+        let mut stack = vec![(root, 0)];
+
+        while let Some((node, depth)) = stack.pop() {
+            if let Some(current) = node {
+                if histogram.len() == depth {
+                    histogram.push(0);
+                }
+                histogram[depth] += 1;
+                stack.push((&current.right, depth + 1));
+                stack.push((&current.left, depth + 1));
+            }
+        }
+
+        histogram
+    }
+
+    pub(crate) fn recursive_depth_histogram(
+        root: &HeapNode<T>,
+        depth: usize,
+        histogram: &mut Vec<usize>,
+    ) {
+        if let Some(node) = root {
+            if histogram.len() == depth {
+                histogram.push(0);
+            }
+            histogram[depth] += 1;
+            Node::recursive_depth_histogram(&node.left, depth + 1, histogram);
+            Node::recursive_depth_histogram(&node.right, depth + 1, histogram);
+        }
+    }
+
+    pub(crate) fn full_levels(histogram: Vec<usize>) -> usize {
+        histogram
+            .into_iter()
+            .enumerate()
+            .take_while(|(depth, count)| 1usize.checked_shl(*depth as u32) == Some(*count))
+            .count()
+    }
+
+    pub(crate) fn iterative_best_single_rotation(root: &HeapNode<T>) -> Option<(&T, Rotation)> {
+        let mut best = None;
+        let mut subtrees: Vec<(usize, usize, usize)> = Vec::new();
+        let mut stack = vec![(root, false)];
+
+        while let Some((node, visited)) = stack.pop() {
+            match (node, visited) {
+                (None, _) => subtrees.push((0, 0, 0)),
+                (Some(current), false) => {
+                    stack.push((node, true));
+                    stack.push((&current.right, false));
+                    stack.push((&current.left, false));
+                }
+                (Some(current), true) => {
+                    let right = subtrees.pop().unwrap();
+                    let left = subtrees.pop().unwrap();
+                    Node::consider_rotations(&current.value, left, right, &mut best);
+                    subtrees.push((1 + left.0 + right.0, left.0, right.0));
+                }
+            }
+        }
+
+        best.map(|(value, rotation, _)| (value, rotation))
+    }
+
+    pub(crate) fn recursive_best_single_rotation<'a>(
+        root: &'a HeapNode<T>,
+        best: &mut Option<(&'a T, Rotation, isize)>,
+    ) -> (usize, usize, usize) {
+        match root {
+            None => (0, 0, 0),
+            Some(node) => {
+                let left = Node::recursive_best_single_rotation(&node.left, best);
+                let right = Node::recursive_best_single_rotation(&node.right, best);
+                Node::consider_rotations(&node.value, left, right, best);
+                (1 + left.0 + right.0, left.0, right.0)
+            }
+        }
+    }
+
+    /// Keeps track of the rotation that reduces the internal path length the most.
+    ///
+    /// Subtrees are given as `(size, left size, right size)`. Rotating right lifts the left
+    /// grandchild subtree by one level and lowers the right subtree by one level (and vice versa
+    /// for rotating left), so the change in path length follows from the subtree sizes alone.
+    fn consider_rotations<'a>(
+        pivot: &'a T,
+        left: (usize, usize, usize),
+        right: (usize, usize, usize),
+        best: &mut Option<(&'a T, Rotation, isize)>,
+    ) {
+        let mut candidates = Vec::new();
+        if left.0 > 0 {
+            candidates.push((Rotation::Right, right.0 as isize - left.1 as isize));
+        }
+        if right.0 > 0 {
+            candidates.push((Rotation::Left, left.0 as isize - right.2 as isize));
+        }
+
+        for (rotation, change) in candidates {
+            if change < best.map_or(0, |(_, _, best_change)| best_change) {
+                *best = Some((pivot, rotation, change));
+            }
+        }
+    }
+
+    fn render_slot(slot: usize, offset: usize) -> Option<usize> {
+        slot.checked_mul(2)?.checked_add(offset)
+    }
+
+    pub(crate) fn iterative_render_rows(root: &HeapNode<T>) -> Option<Vec<Vec<(usize, &T)>>> {
+        let mut rows: Vec<Vec<(usize, &T)>> = Vec::new();
+        let mut deque = VecDeque::new();
+        deque.push_back((root, 0, 0));
+
+        while let Some((node, level, slot)) = deque.pop_front() {
+            if let Some(current) = node {
+                if level == rows.len() {
+                    rows.push(Vec::new());
+                }
+                rows[level].push((slot, &current.value));
+                for (child, offset) in [(&current.left, 0), (&current.right, 1)] {
+                    if child.is_some() {
+                        deque.push_back((child, level + 1, Node::<T>::render_slot(slot, offset)?));
+                    }
+                }
+            }
+        }
+
+        Some(rows)
+    }
+
+    pub(crate) fn recursive_render_rows<'a>(
+        root: &'a HeapNode<T>,
+        level: usize,
+        slot: usize,
+        rows: &mut Vec<Vec<(usize, &'a T)>>,
+    ) -> Option<()> {
+        if let Some(node) = root {
+            if level == rows.len() {
+                rows.push(Vec::new());
+            }
+            rows[level].push((slot, &node.value));
+            for (child, offset) in [(&node.left, 0), (&node.right, 1)] {
+                if child.is_some() {
+                    let child_slot = Node::<T>::render_slot(slot, offset)?;
+                    Node::recursive_render_rows(child, level + 1, child_slot, rows)?;
+                }
+            }
+        }
+
+        Some(())
+    }
+
+    pub(crate) fn iterative_predecessor<'a>(mut root: &'a HeapNode<T>, value: &T) -> Option<&'a T> {
+        let mut predecessor = None;
+
+        while let Some(current) = root {
+            match value.cmp(&current.value) {
+                Ordering::Greater => {
+                    predecessor = Some(&current.value);
+                    root = &current.right;
+                }
+                _ => root = &current.left,
+            }
+        }
+
+        predecessor
+    }
+
+    pub(crate) fn recursive_predecessor<'a>(root: &'a HeapNode<T>, value: &T) -> Option<&'a T> {
+        match root {
+            None => None,
+            Some(node) => match value.cmp(&node.value) {
+                Ordering::Greater => {
+                    Node::recursive_predecessor(&node.right, value).or(Some(&node.value))
+                }
+                _ => Node::recursive_predecessor(&node.left, value),
+            },
+        }
+    }
+
+    pub(crate) fn iterative_successor<'a>(mut root: &'a HeapNode<T>, value: &T) -> Option<&'a T> {
+        let mut successor = None;
+
+        while let Some(current) = root {
+            match value.cmp(&current.value) {
+                Ordering::Less => {
+                    successor = Some(&current.value);
+                    root = &current.left;
+                }
+                _ => root = &current.right,
+            }
+        }
+
+        successor
+    }
+
+    pub(crate) fn recursive_successor<'a>(root: &'a HeapNode<T>, value: &T) -> Option<&'a T> {
+        match root {
+            None => None,
+            Some(node) => match value.cmp(&node.value) {
+                Ordering::Less => {
+                    Node::recursive_successor(&node.left, value).or(Some(&node.value))
+                }
+                _ => Node::recursive_successor(&node.right, value),
+            },
+        }
+    }
+
+    pub(crate) fn iterative_consume_greater_than(root: HeapNode<T>, threshold: &T) -> Vec<T> {
+        let mut elements = Vec::new();
+        let mut stack = vec![root];
+
+        while let Some(node) = stack.pop() {
+            if let Some(mut current) = node {
+                if current.value <= *threshold {
+                    Node::drop_iteratively(current.left.take());
+                    stack.push(current.right.take());
+                } else if current.left.is_some() {
+                    let left_node = current.left.take();
+                    stack.push(Some(current));
+                    stack.push(left_node);
+                } else {
+                    let right_node = current.right.take();
+                    elements.push(current.value);
+                    stack.push(right_node);
+                }
+            }
+        }
+
+        elements
+    }
+
+    pub(crate) fn recursive_consume_greater_than(
+        node: HeapNode<T>,
+        threshold: &T,
+        elements: &mut Vec<T>,
+    ) {
+        if let Some(mut node) = node {
+            if node.value > *threshold {
+                Node::recursive_consume_greater_than(node.left.take(), threshold, elements);
+                elements.push(node.value);
+            } else {
+                Node::drop_iteratively(node.left.take());
+            }
+            Node::recursive_consume_greater_than(node.right, threshold, elements);
+        }
+    }
+
+    pub(crate) fn drop_iteratively(root: HeapNode<T>) {
+        drop_tree_iteratively(root);
+    }
+
+    pub(crate) fn iterative_largest_child_subtree(root: &HeapNode<T>) -> usize {
+        root.as_ref().map_or(0, |node| {
+            max(
+                Node::iterative_count(&node.left),
+                Node::iterative_count(&node.right),
+            )
+        })
+    }
+
+    pub(crate) fn recursive_largest_child_subtree(root: &HeapNode<T>) -> usize {
+        match root {
+            None => 0,
+            Some(node) => max(
+                Node::recursive_count(&node.left),
+                Node::recursive_count(&node.right),
+            ),
+        }
+    }
+
+    pub(crate) fn pretty_print(root: &HeapNode<T>) -> String
+    where
+        T: Display,
+    {
+        let mut lines = Vec::new();
+        if let Some(node) = root {
+            lines.push(node.value.to_string());
+            Node::pretty_print_children(node, "", &mut lines);
+        }
+
+        lines.join("\n")
+    }
+
+    fn pretty_print_children(node: &Node<T>, prefix: &str, lines: &mut Vec<String>)
+    where
+        T: Display,
+    {
+        let children: Vec<&Box<Node<T>>> = node.left.iter().chain(node.right.iter()).collect();
+        for (index, child) in children.iter().enumerate() {
+            let (connector, indent) = match index == children.len() - 1 {
+                true => ("└── ", "    "),
+                false => ("├── ", "│   "),
+            };
+            lines.push(format!("{}{}{}", prefix, connector, child.value));
+            Node::pretty_print_children(child, &format!("{}{}", prefix, indent), lines);
+        }
+    }
+
+    pub(crate) fn iterative_ancestors<'a>(
+        mut root: &'a HeapNode<T>,
+        value: &T,
+    ) -> Option<Vec<&'a T>> {
+        let mut ancestors = Vec::new();
+
+        while let Some(current) = root {
+            match value.cmp(&current.value) {
+                Ordering::Equal => {
+                    ancestors.reverse();
+                    return Some(ancestors);
+                }
+                Ordering::Less => root = &current.left,
+                Ordering::Greater => root = &current.right,
+            }
+            ancestors.push(&current.value);
+        }
+
+        None
+    }
+
+    pub(crate) fn recursive_ancestors<'a>(root: &'a HeapNode<T>, value: &T) -> Option<Vec<&'a T>> {
+        let node = root.as_ref()?;
+        let mut ancestors = match value.cmp(&node.value) {
+            Ordering::Equal => return Some(Vec::new()),
+            Ordering::Less => Node::recursive_ancestors(&node.left, value)?,
+            Ordering::Greater => Node::recursive_ancestors(&node.right, value)?,
+        };
+
+        ancestors.push(&node.value);
+        Some(ancestors)
+    }
+
+    pub(crate) fn iterative_descendants<'a>(
+        mut root: &'a HeapNode<T>,
+        value: &T,
+    ) -> Option<Vec<&'a T>> {
+        while let Some(current) = root {
+            match value.cmp(&current.value) {
+                Ordering::Equal => {
+                    let mut descendants = Node::iterative_in_order_vec(&current.left);
+                    descendants.extend(Node::iterative_in_order_vec(&current.right));
+                    return Some(descendants);
+                }
+                Ordering::Less => root = &current.left,
+                Ordering::Greater => root = &current.right,
+            }
+        }
+
+        None
+    }
+
+    pub(crate) fn recursive_descendants<'a>(
+        root: &'a HeapNode<T>,
+        value: &T,
+    ) -> Option<Vec<&'a T>> {
+        let node = root.as_ref()?;
+        match value.cmp(&node.value) {
+            Ordering::Equal => {
+                let mut descendants = Vec::new();
+                Node::recursive_in_order_vec(&node.left, &mut descendants);
+                Node::recursive_in_order_vec(&node.right, &mut descendants);
+                Some(descendants)
+            }
+            Ordering::Less => Node::recursive_descendants(&node.left, value),
+            Ordering::Greater => Node::recursive_descendants(&node.right, value),
+        }
+    }
+
+    pub(crate) fn iterative_rebalance_subtree(mut root: &mut HeapNode<T>, value: &T) -> bool {
+        while let Some(ref mut current) = root {
+            match value.cmp(&current.value) {
+                Ordering::Less => root = &mut root.as_mut().unwrap().left,
+                Ordering::Greater => root = &mut root.as_mut().unwrap().right,
+                Ordering::Equal => {
+                    let size = current.size;
+                    Node::dsw_rebalance(root, size);
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    pub(crate) fn recursive_rebalance_subtree(root: &mut HeapNode<T>, value: &T) -> bool {
+        match root {
+            None => false,
+            Some(node) => match value.cmp(&node.value) {
+                Ordering::Less => Node::recursive_rebalance_subtree(&mut node.left, value),
+                Ordering::Greater => Node::recursive_rebalance_subtree(&mut node.right, value),
+                Ordering::Equal => {
+                    let size = node.size;
+                    Node::dsw_rebalance(root, size);
+                    true
+                }
+            },
+        }
+    }
+
+    pub(crate) fn bucket_counts<'a, I>(elements: I, boundaries: &[T]) -> Vec<usize>
+    where
+        T: 'a,
+        I: Iterator<Item = &'a T>,
+    {
+        debug_assert!(
+            boundaries.windows(2).all(|pair| pair[0] <= pair[1]),
+            "boundaries must be sorted"
+        );
+
+        let mut counts = vec![0; boundaries.len() + 1];
+        let mut bucket = 0;
+        for element in elements {
+            while bucket < boundaries.len() && *element >= boundaries[bucket] {
+                bucket += 1;
+            }
+            counts[bucket] += 1;
+        }
+
+        counts
+    }
+
+    pub(crate) fn iterative_get_or_insert(root: &mut HeapNode<T>, value: T) -> (&T, bool) {
+        let mut path_sizes = Vec::new();
+        let mut current = root;
+        while let Some(node) = current {
+            let Node {
+                value: existing,
+                left,
+                right,
+                size,
+            } = &mut **node;
+            match value.cmp(existing) {
+                Ordering::Equal => return (existing, false),
+                Ordering::Less => current = left,
+                Ordering::Greater => current = right,
+            }
+            path_sizes.push(size);
+        }
+
+        for size in path_sizes {
+            *size += 1;
+        }
+        (&current.insert(Box::new(Node::new(value))).value, true)
+    }
+
+    pub(crate) fn recursive_get_or_insert(root: &mut HeapNode<T>, value: T) -> (&T, bool) {
+        match root {
+            None => (&root.insert(Box::new(Node::new(value))).value, true),
+            Some(node) => {
+                let Node {
+                    value: existing,
+                    left,
+                    right,
+                    size,
+                } = &mut **node;
+                let (element, inserted) = match value.cmp(existing) {
+                    Ordering::Equal => return (existing, false),
+                    Ordering::Less => Node::recursive_get_or_insert(left, value),
+                    Ordering::Greater => Node::recursive_get_or_insert(right, value),
+                };
+                if inserted {
+                    *size += 1;
+                }
+                (element, inserted)
+            }
+        }
+    }
+
+    pub(crate) fn iterative_in_order_depths(mut root: &HeapNode<T>) -> Vec<usize> {
+        let mut depths = Vec::new();
+        let mut stack = Vec::new();
+        let mut depth = 0;
+
+        while !stack.is_empty() || root.is_some() {
+            if let Some(current) = root {
+                stack.push((current, depth));
+                root = &current.left;
+                depth += 1;
+            } else {
+                let (current, current_depth) = stack.pop().unwrap();
+                depths.push(current_depth);
+                root = &current.right;
+                depth = current_depth + 1;
+            }
+        }
+
+        depths
+    }
+
+    pub(crate) fn recursive_in_order_depths(
+        root: &HeapNode<T>,
+        depth: usize,
+        depths: &mut Vec<usize>,
+    ) {
+        if let Some(node) = root {
+            Node::recursive_in_order_depths(&node.left, depth + 1, depths);
+            depths.push(depth);
+            Node::recursive_in_order_depths(&node.right, depth + 1, depths);
+        }
+    }
+
+    pub(crate) fn zipf_expected_cost(depths: Vec<usize>, s: f64) -> Option<f64> {
+        if depths.is_empty() || s.is_nan() {
+            return None;
+        }
+
+        let (weighted_visits, total_weight) =
+            depths
+                .into_iter()
+                .enumerate()
+                .fold((0.0, 0.0), |(visits, total), (rank, depth)| {
+                    let weight = 1.0 / ((rank + 1) as f64).powf(s);
+                    (visits + weight * (depth + 1) as f64, total + weight)
+                });
+
+        Some(weighted_visits / total_weight)
+    }
+
+    pub(crate) fn level_width_bars(histogram: Vec<usize>) -> String {
+        histogram
+            .into_iter()
+            .map(|count| "#".repeat(count))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    pub(crate) fn iterative_height_and_diameter(root: &HeapNode<T>) -> (isize, usize) {
+        let mut subtrees = Vec::new();
+        let mut stack = vec![(root, false)];
+
+        while let Some((node, visited)) = stack.pop() {
+            match node {
+                None => subtrees.push((-1, 0)),
+                Some(_) if visited => {
+                    let (right_height, right_diameter) = subtrees.pop().unwrap();
+                    let (left_height, left_diameter) = subtrees.pop().unwrap();
+                    let through_node = (left_height + right_height + 2) as usize;
+                    subtrees.push((
+                        1 + max(left_height, right_height),
+                        max(through_node, max(left_diameter, right_diameter)),
+                    ));
+                }
+                Some(current) => {
+                    stack.push((node, true));
+                    stack.push((&current.right, false));
+                    stack.push((&current.left, false));
+                }
+            }
+        }
+
+        subtrees.pop().unwrap()
+    }
+
+    pub(crate) fn recursive_height_and_diameter(root: &HeapNode<T>) -> (isize, usize) {
+        match root {
+            None => (-1, 0),
+            Some(node) => {
+                let (left_height, left_diameter) = Node::recursive_height_and_diameter(&node.left);
+                let (right_height, right_diameter) =
+                    Node::recursive_height_and_diameter(&node.right);
+                let through_node = (left_height + right_height + 2) as usize;
+                (
+                    1 + max(left_height, right_height),
+                    max(through_node, max(left_diameter, right_diameter)),
+                )
+            }
+        }
+    }
+
+    pub(crate) fn iterative_lowest_common_ancestor<'a>(
+        mut root: &'a HeapNode<T>,
+        a: &T,
+        b: &T,
+    ) -> Option<&'a T> {
+        while let Some(node) = root {
+            match (a.cmp(&node.value), b.cmp(&node.value)) {
+                (Ordering::Less, Ordering::Less) => root = &node.left,
+                (Ordering::Greater, Ordering::Greater) => root = &node.right,
+                _ => return Some(&node.value),
+            }
+        }
+
+        None
+    }
+
+    pub(crate) fn recursive_lowest_common_ancestor<'a>(
+        root: &'a HeapNode<T>,
+        a: &T,
+        b: &T,
+    ) -> Option<&'a T> {
+        let node = root.as_ref()?;
+        match (a.cmp(&node.value), b.cmp(&node.value)) {
+            (Ordering::Less, Ordering::Less) => {
+                Node::recursive_lowest_common_ancestor(&node.left, a, b)
+            }
+            (Ordering::Greater, Ordering::Greater) => {
+                Node::recursive_lowest_common_ancestor(&node.right, a, b)
+            }
+            _ => Some(&node.value),
+        }
+    }
+
+    pub(crate) fn iterative_width(root: &HeapNode<T>) -> usize {
+        let mut width = 0;
+        let mut queue = VecDeque::new();
+        if let Some(node) = root {
+            queue.push_back(node);
+        }
+
+        while !queue.is_empty() {
+            let mut size = queue.len();
+            width = max(width, size);
+            while size > 0 {
+                let current = queue.pop_front().unwrap();
+                if let Some(left) = &current.left {
+                    queue.push_back(left);
+                }
+                if let Some(right) = &current.right {
+                    queue.push_back(right);
+                }
+                size -= 1;
+            }
+        }
+
+        width
+    }
+}
+
+/// Node of any of the trees within the crate that owns its children on the heap.
+pub(crate) trait OwnedChildren: Sized {
+    fn take_children(&mut self) -> (Option<Box<Self>>, Option<Box<Self>>);
+}
+
+impl<T: Ord> OwnedChildren for Node<T> {
+    fn take_children(&mut self) -> (HeapNode<T>, HeapNode<T>) {
+        (self.left.take(), self.right.take())
+    }
+}
+
+/// Drops every node of the given tree without recursing, as the compiler generated drop glue
+/// would otherwise overflow the stack on degenerate trees.
+pub(crate) fn drop_tree_iteratively<N: OwnedChildren>(root: Option<Box<N>>) {
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if let Some(mut current) = node {
+            let (left, right) = current.take_children();
+            stack.push(left);
+            stack.push(right);
+        }
+    }
+}
+
+/// Lazy iterator over references to the elements of a tree in **ascending order.**
+///
+/// Created by [in_order_iter()](crate::BinarySearchTree::in_order_iter()) and
+/// [asc_order_iter()](crate::BinarySearchTree::asc_order_iter()). Nodes are only visited once
+/// the iterator reaches them, from the front through one stack and from the back through a
+/// mirrored stack, so both ends can be consumed without collecting the elements first.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::{BinarySearchTree, IterativeBST};
+///
+/// let bst = IterativeBST::from(vec![3, 1, 2, 5, 4]);
+/// let mut in_order_iter = bst.in_order_iter();
+///
+/// assert_eq!(in_order_iter.next(), Some(&1));
+/// assert_eq!(in_order_iter.next_back(), Some(&5));
+/// assert_eq!(in_order_iter.len(), 3);
+/// assert_eq!(in_order_iter.collect::<Vec<&i32>>(), vec![&2, &3, &4]);
+/// ```
+#[derive(Debug)]
+pub struct InOrderIter<'a, T: Ord> {
+    forward: Vec<&'a Node<T>>,
+    backward: Vec<&'a Node<T>>,
+    remaining: usize,
+}
+
+impl<'a, T: Ord> InOrderIter<'a, T> {
+    pub(crate) fn new(root: &'a HeapNode<T>) -> InOrderIter<'a, T> {
+        let mut iter = InOrderIter {
+            forward: Vec::new(),
+            backward: Vec::new(),
+            remaining: Node::size_of(root),
+        };
+        iter.push_left_edge(root);
+        iter.push_right_edge(root);
+        iter
+    }
+
+    fn push_left_edge(&mut self, mut root: &'a HeapNode<T>) {
+        while let Some(node) = root {
+            self.forward.push(node);
+            root = &node.left;
+        }
+    }
+
+    fn push_right_edge(&mut self, mut root: &'a HeapNode<T>) {
+        while let Some(node) = root {
+            self.backward.push(node);
+            root = &node.right;
+        }
+    }
+}
+
+impl<'a, T: Ord> Iterator for InOrderIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = self.forward.pop()?;
+        self.push_left_edge(&node.right);
+        self.remaining -= 1;
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: Ord> DoubleEndedIterator for InOrderIter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = self.backward.pop()?;
+        self.push_right_edge(&node.left);
+        self.remaining -= 1;
+        Some(&node.value)
+    }
+}
+
+impl<T: Ord> ExactSizeIterator for InOrderIter<'_, T> {}
+
+impl<T: Ord> FusedIterator for InOrderIter<'_, T> {}
+
+/// Lazy iterator over mutable references to the elements of a tree in **ascending order.**
+///
+/// Created by [in_order_iter_mut()](crate::BinarySearchTree::in_order_iter_mut()).
+///
+/// # Important
+///
+/// The elements are modified in place without being moved within the tree, so it is the
+/// responsibility of the caller to keep them in the same relative order. Changing an element in
+/// a way that breaks the ordering leaves the tree in an unspecified state.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::{BinarySearchTree, IterativeBST};
+///
+/// let mut bst = IterativeBST::from(vec![2, 1, 3]);
+/// for value in bst.in_order_iter_mut() {
+///     *value *= 10;
+/// }
+///
+/// assert_eq!(bst.asc_order_vec(), vec![&10, &20, &30]);
+/// ```
+#[derive(Debug)]
+pub struct InOrderIterMut<'a, T: Ord> {
+    stack: Vec<(&'a mut T, &'a mut HeapNode<T>)>,
+    remaining: usize,
+}
+
+impl<'a, T: Ord> InOrderIterMut<'a, T> {
+    pub(crate) fn new(root: &'a mut HeapNode<T>) -> InOrderIterMut<'a, T> {
+        let mut iter = InOrderIterMut {
+            stack: Vec::new(),
+            remaining: Node::size_of(root),
+        };
+        iter.push_left_edge(root);
+        iter
+    }
+
+    fn push_left_edge(&mut self, mut root: &'a mut HeapNode<T>) {
+        while let Some(node) = root {
+            let Node {
+                value, left, right, ..
+            } = &mut **node;
+            self.stack.push((value, right));
+            root = left;
+        }
+    }
+}
+
+impl<'a, T: Ord> Iterator for InOrderIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        let (value, right) = self.stack.pop()?;
+        self.push_left_edge(right);
+        self.remaining -= 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: Ord> ExactSizeIterator for InOrderIterMut<'_, T> {}
+
+impl<T: Ord> FusedIterator for InOrderIterMut<'_, T> {}
@@ -1,9 +1,28 @@
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Add, Sub};
+use std::sync::Arc;
 use std::vec::IntoIter;
 
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::fingerprint::fnv1a;
 use crate::BinarySearchTree;
-use crate::Node;
+use crate::BuildReport;
+use crate::FrozenBST;
+use crate::FrozenIter;
 use crate::HeapNode;
+use crate::InOrderIter;
+use crate::InOrderIterMut;
+use crate::IterativeBST;
+use crate::Node;
+use crate::NotSorted;
+use crate::ReconstructError;
+use crate::Rotation;
+use crate::StableBytes;
+use crate::TooTall;
 /// Recursive Binary Search Tree implementation.
 ///
 /// # Important
@@ -17,6 +36,7 @@ use crate::HeapNode;
 pub struct RecursiveBST<T: Ord> {
     root: HeapNode<T>,
     size: usize,
+    rejected_duplicates: usize,
 }
 
 impl<T: Ord> RecursiveBST<T> {
@@ -37,10 +57,371 @@ impl<T: Ord> RecursiveBST<T> {
         RecursiveBST {
             root: None,
             size: 0,
+            rejected_duplicates: 0,
+        }
+    }
+
+    /// Creates a `RecursiveBST<T>` from keys and their **access frequencies** where frequently
+    /// accessed keys are placed closer to the root.
+    ///
+    /// This is a greedy approximation of an optimal binary search tree: within every subtree,
+    /// the key with the highest weight becomes the root. This is achieved by inserting the keys
+    /// in descending order of weight. If a key appears more than once, only its highest weight
+    /// is considered.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from_weighted(&[(1, 0.1), (2, 0.2), (3, 5.0), (4, 0.4)]);
+    ///
+    /// // The most frequently accessed key is the root
+    /// assert_eq!(bst.level_order_vec()[0], &3);
+    /// assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3, &4]);
+    /// ```
+    pub fn from_weighted(weighted_keys: &[(T, f64)]) -> RecursiveBST<T>
+    where
+        T: Clone,
+    {
+        let mut bst = RecursiveBST::new();
+        for (key, _) in Node::sort_by_weight(weighted_keys) {
+            bst.insert(key.clone());
+        }
+        bst
+    }
+
+    /// Reconstructs the unique `RecursiveBST<T>` described by the given **in-order** and
+    /// **pre-order** traversals.
+    ///
+    /// # Errors
+    ///
+    /// A [ReconstructError] is returned if the traversals are not permutations of the same
+    /// elements, if the in-order traversal is not strictly ascending or if the pre-order
+    /// traversal does not describe a binary search tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST, ReconstructError};
+    ///
+    /// let bst = RecursiveBST::from_inorder_preorder(&[1, 2, 3, 4], &[3, 1, 2, 4]).unwrap();
+    /// assert_eq!(bst.pre_order_vec(), vec![&3, &1, &2, &4]);
+    ///
+    /// let error = RecursiveBST::from_inorder_preorder(&[1, 2, 3], &[2, 1, 4]).unwrap_err();
+    /// assert_eq!(error, ReconstructError::ElementMismatch);
+    /// ```
+    pub fn from_inorder_preorder(
+        in_order: &[T],
+        pre_order: &[T],
+    ) -> Result<RecursiveBST<T>, ReconstructError>
+    where
+        T: Clone,
+    {
+        Node::validate_traversals(in_order, pre_order)?;
+
+        // Inserting the elements in pre-order reproduces the unique tree with that pre-order
+        let bst = RecursiveBST::from(pre_order);
+        if !bst.pre_order_iter().eq(pre_order.iter()) {
+            return Err(ReconstructError::InvalidPreOrder);
+        }
+
+        Ok(bst)
+    }
+
+    /// Creates a balanced `RecursiveBST<T>` from elements given in **strictly ascending order.**
+    ///
+    /// As the elements are already sorted, the tree is built in `O(n)` without comparing the
+    /// elements against each other beyond checking the order of every adjacent pair.
+    ///
+    /// # Errors
+    ///
+    /// A [NotSorted] error containing the position of the first element that is not greater than
+    /// the element before it is returned if the elements are not strictly ascending.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST, NotSorted};
+    ///
+    /// let bst = RecursiveBST::from_strictly_increasing(1..=7).unwrap();
+    /// assert_eq!(bst.pre_order_vec(), vec![&4, &2, &1, &3, &6, &5, &7]);
+    ///
+    /// let error = RecursiveBST::from_strictly_increasing(vec![1, 3, 3, 4]).unwrap_err();
+    /// assert_eq!(error, NotSorted { position: 2 });
+    /// ```
+    pub fn from_strictly_increasing<I: IntoIterator<Item = T>>(
+        iter: I,
+    ) -> Result<RecursiveBST<T>, NotSorted> {
+        let elements = Node::collect_strictly_increasing(iter)?;
+        let size = elements.len();
+        Ok(RecursiveBST {
+            root: Node::build_balanced(&mut elements.into_iter(), size),
+            size,
+            rejected_duplicates: 0,
+        })
+    }
+
+    /// Creates a balanced `RecursiveBST<T>` from a slice of elements in **strictly ascending
+    /// order.**
+    ///
+    /// The tree is built in `O(n)` by recursively picking the middle element of the slice as the
+    /// root of every subtree. Unlike
+    /// [from_strictly_increasing](RecursiveBST::from_strictly_increasing()), the order of the
+    /// elements is only checked in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the elements are not strictly ascending.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from_sorted_slice(&[1, 2, 3, 4, 5, 6, 7]);
+    ///
+    /// assert_eq!(bst.height(), Some(2));
+    /// assert_eq!(bst.pre_order_vec(), vec![&4, &2, &1, &3, &6, &5, &7]);
+    /// ```
+    pub fn from_sorted_slice(slice: &[T]) -> RecursiveBST<T>
+    where
+        T: Clone,
+    {
+        debug_assert!(
+            slice.windows(2).all(|pair| pair[0] < pair[1]),
+            "elements must be strictly ascending"
+        );
+
+        RecursiveBST {
+            root: Node::build_balanced(&mut slice.iter().cloned(), slice.len()),
+            size: slice.len(),
+            rejected_duplicates: 0,
+        }
+    }
+
+    pub(crate) fn into_parts(mut self) -> (HeapNode<T>, usize) {
+        (self.root.take(), self.size)
+    }
+
+    /// Creates a `RecursiveBST<T>` by repeatedly calling the given generator and inserting every
+    /// returned value until it returns `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut next = 0;
+    /// let bst = RecursiveBST::from_generator(|| {
+    ///     next += 2;
+    ///     (next <= 6).then_some(next)
+    /// });
+    ///
+    /// assert_eq!(bst.asc_order_vec(), vec![&2, &4, &6]);
+    /// ```
+    pub fn from_generator<F: FnMut() -> Option<T>>(mut generator: F) -> RecursiveBST<T> {
+        let mut bst = RecursiveBST::new();
+        while let Some(value) = generator() {
+            bst.insert(value);
+        }
+        bst
+    }
+
+    /// Creates a `RecursiveBST<T>` from the given elements alongside a [BuildReport] describing
+    /// the input.
+    ///
+    /// The elements are inserted in the given order, exactly like
+    /// [from_iter()](RecursiveBST::from_iter()), while counting the duplicates that were dropped
+    /// and the elements that were out of ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let (bst, report) = RecursiveBST::from_iter_validated(vec![3, 1, 3, 2]);
+    ///
+    /// assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3]);
+    /// assert_eq!(report.total, 4);
+    /// assert_eq!(report.duplicates, 1);
+    /// assert_eq!(report.out_of_order, 2);
+    /// ```
+    pub fn from_iter_validated<I: IntoIterator<Item = T>>(
+        iter: I,
+    ) -> (RecursiveBST<T>, BuildReport) {
+        let elements: Vec<T> = iter.into_iter().collect();
+        let total = elements.len();
+        let out_of_order = elements.windows(2).filter(|pair| pair[1] < pair[0]).count();
+
+        let bst = RecursiveBST::from_iter(elements);
+        let report = BuildReport {
+            total,
+            duplicates: bst.rejected_duplicates,
+            out_of_order,
+        };
+        (bst, report)
+    }
+    /// Returns `true` if both trees have an **identical structure**, regardless of the values
+    /// stored within them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![2, 1, 3]);
+    /// let char_bst = RecursiveBST::from(vec!['b', 'a', 'c']);
+    /// let sorted_bst = RecursiveBST::from(vec![1, 2, 3]);
+    ///
+    /// assert!(bst.same_shape(&char_bst));
+    /// assert!(!bst.same_shape(&sorted_bst));
+    /// ```
+    pub fn same_shape<U: Ord>(&self, other: &RecursiveBST<U>) -> bool {
+        Node::recursive_same_shape(&self.root, &other.root)
+    }
+}
+
+impl<T: Ord + Copy + Add<Output = T> + From<u8>> RecursiveBST<T> {
+    /// Returns a balanced `RecursiveBST<T>` containing every integer within `[low, high]`
+    /// that does **not** exist within the tree.
+    ///
+    /// An empty tree is returned if `low > high`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![2, 4]);
+    /// let complement = bst.complement_in(1, 5);
+    ///
+    /// assert_eq!(complement.asc_order_vec(), vec![&1, &3, &5]);
+    /// assert!(bst.complement_in(5, 1).is_empty());
+    /// ```
+    pub fn complement_in(&self, low: T, high: T) -> RecursiveBST<T> {
+        let missing = Node::missing_in_range(self.asc_order_vec(), low, high);
+        let size = missing.len();
+        RecursiveBST {
+            root: Node::build_balanced(&mut missing.into_iter(), size),
+            size,
+            rejected_duplicates: 0,
+        }
+    }
+
+    /// Returns the elements of the tree collapsed into **inclusive ranges** of consecutive integers
+    /// in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![1, 2, 3, 5, 6, 9]);
+    ///
+    /// assert_eq!(bst.to_ranges(), vec![(1, 3), (5, 6), (9, 9)]);
+    /// ```
+    pub fn to_ranges(&self) -> Vec<(T, T)> {
+        Node::to_ranges(self.asc_order_vec())
+    }
+
+    /// Creates a balanced `RecursiveBST<T>` containing every integer within the given
+    /// **inclusive ranges.**
+    ///
+    /// Overlapping ranges are de-duplicated and inverted ranges (where `low > high`) are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from_ranges(&[(1, 3), (2, 4), (9, 9), (7, 6)]);
+    ///
+    /// assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3, &4, &9]);
+    /// assert_eq!(bst.to_ranges(), vec![(1, 4), (9, 9)]);
+    /// ```
+    pub fn from_ranges(ranges: &[(T, T)]) -> RecursiveBST<T> {
+        let values = Node::expand_ranges(ranges);
+        let size = values.len();
+        RecursiveBST {
+            root: Node::build_balanced(&mut values.into_iter(), size),
+            size,
+            rejected_duplicates: 0,
+        }
+    }
+
+    /// Returns the number of nodes on the longest downward path where every element is
+    /// **exactly one greater** than its parent.
+    ///
+    /// An empty tree returns `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         5
+    ///  //       /  \
+    ///  //      1    8
+    ///  //       \
+    ///  //        2
+    ///  //         \
+    ///  //          3
+    /// let bst = RecursiveBST::from(vec![5, 1, 8, 2, 3]);
+    ///
+    /// // The longest consecutive path is 1 -> 2 -> 3
+    /// assert_eq!(bst.longest_consecutive_path(), 3);
+    /// ```
+    pub fn longest_consecutive_path(&self) -> usize {
+        Node::recursive_longest_consecutive_path(&self.root, None, 0)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T: Ord + Send> RecursiveBST<T> {
+    /// Creates a balanced `RecursiveBST<T>` from a **parallel** iterator.
+    ///
+    /// The elements are collected and sorted in parallel and any duplicates are removed before
+    /// the tree is built bottom-up, without inserting the elements one at a time or rebalancing
+    /// the tree afterwards. The removed duplicates count towards
+    /// [rejected_duplicates()](RecursiveBST::rejected_duplicates()).
+    ///
+    /// Only available with the `rayon` feature enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::par_from_iter(vec![5, 3, 7, 1, 3, 9]);
+    ///
+    /// assert_eq!(bst.asc_order_vec(), vec![&1, &3, &5, &7, &9]);
+    /// assert_eq!(bst.height(), Some(2));
+    /// assert_eq!(bst.rejected_duplicates(), 1);
+    /// ```
+    pub fn par_from_iter<I: IntoParallelIterator<Item = T>>(iter: I) -> RecursiveBST<T> {
+        let mut elements: Vec<T> = iter.into_par_iter().collect();
+        let total = elements.len();
+        elements.par_sort_unstable();
+        elements.dedup();
+
+        let size = elements.len();
+        RecursiveBST {
+            root: Node::build_balanced(&mut elements.into_iter(), size),
+            size,
+            rejected_duplicates: total - size,
         }
     }
 }
 
+#[cfg(feature = "rayon")]
+impl<T: Ord + Send> FromParallelIterator<T> for RecursiveBST<T> {
+    fn from_par_iter<I: IntoParallelIterator<Item = T>>(iter: I) -> Self {
+        RecursiveBST::par_from_iter(iter)
+    }
+}
+
 impl<T: Ord> Default for RecursiveBST<T> {
     /// Creates an empty `RecursiveBST<T>`
     fn default() -> RecursiveBST<T> {
@@ -90,6 +471,47 @@ impl<T: Ord + Clone> From<&[T]> for RecursiveBST<T> {
     }
 }
 
+impl<T: Ord> From<IterativeBST<T>> for RecursiveBST<T> {
+    /// Moves the nodes of an [IterativeBST] into a `RecursiveBST<T>`, keeping the exact same
+    /// structure without inserting any element again.
+    fn from(bst: IterativeBST<T>) -> Self {
+        let (root, size) = bst.into_parts();
+        RecursiveBST {
+            root,
+            size,
+            rejected_duplicates: 0,
+        }
+    }
+}
+
+impl<T: Ord> Drop for RecursiveBST<T> {
+    fn drop(&mut self) {
+        Node::drop_iteratively(self.root.take());
+    }
+}
+
+impl<T: Ord> IntoIterator for RecursiveBST<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Consumes the tree and returns an iterator over its elements in **ascending order**, the
+    /// same as [into_in_order_iter](BinarySearchTree::into_in_order_iter()).
+    fn into_iter(self) -> IntoIter<T> {
+        self.into_in_order_iter()
+    }
+}
+
+impl<'a, T: Ord> IntoIterator for &'a RecursiveBST<T> {
+    type Item = &'a T;
+    type IntoIter = InOrderIter<'a, T>;
+
+    /// Returns an iterator over references to the elements in **ascending order**, the same as
+    /// [in_order_iter](BinarySearchTree::in_order_iter()).
+    fn into_iter(self) -> InOrderIter<'a, T> {
+        self.in_order_iter()
+    }
+}
+
 impl<T: Ord + Clone> Clone for RecursiveBST<T> {
     fn clone(&self) -> Self {
         let mut bst = RecursiveBST::new();
@@ -188,6 +610,8 @@ impl<T: Ord> BinarySearchTree<T> for RecursiveBST<T> {
             Some(ref mut node) => {
                 if node.recursive_insert(value).is_ok() {
                     self.size += 1;
+                } else {
+                    self.rejected_duplicates += 1;
                 }
             }
         }
@@ -619,10 +1043,8 @@ impl<T: Ord> BinarySearchTree<T> for RecursiveBST<T> {
     /// assert_eq!(asc_order_iter.next(), Some(&5));
     /// assert_eq!(asc_order_iter.next(), None);
     /// ```
-    fn asc_order_iter(&self) -> IntoIter<&T> {
-        let mut elements = Vec::new();
-        Node::recursive_in_order_vec(&self.root, &mut elements);
-        elements.into_iter()
+    fn asc_order_iter(&self) -> InOrderIter<'_, T> {
+        self.in_order_iter()
     }
 
     /// Returns an iterator over [RecursiveBST::pre_order_vec()].
@@ -682,10 +1104,8 @@ impl<T: Ord> BinarySearchTree<T> for RecursiveBST<T> {
     /// assert_eq!(in_order_iter.next(), Some(&5));
     /// assert_eq!(in_order_iter.next(), None);
     /// ```
-    fn in_order_iter(&self) -> IntoIter<&T> {
-        let mut elements: Vec<&T> = Vec::new();
-        Node::recursive_in_order_vec(&self.root, &mut elements);
-        elements.into_iter()
+    fn in_order_iter(&self) -> InOrderIter<'_, T> {
+        InOrderIter::new(&self.root)
     }
 
     /// Returns an iterator over [RecursiveBST::post_order_vec()].
@@ -805,9 +1225,9 @@ impl<T: Ord> BinarySearchTree<T> for RecursiveBST<T> {
     ///
     /// // bst.insert(10); -> COMPILE ERROR
     /// ```
-    fn into_pre_order_iter(self) -> IntoIter<T> {
+    fn into_pre_order_iter(mut self) -> IntoIter<T> {
         let mut elements = Vec::new();
-        Node::recursive_consume_pre_order_vec(self.root, &mut elements);
+        Node::recursive_consume_pre_order_vec(self.root.take(), &mut elements);
         elements.into_iter()
     }
 
@@ -841,9 +1261,9 @@ impl<T: Ord> BinarySearchTree<T> for RecursiveBST<T> {
     ///
     /// // bst.insert(10); -> COMPILE ERROR
     /// ```
-    fn into_in_order_iter(self) -> IntoIter<T> {
+    fn into_in_order_iter(mut self) -> IntoIter<T> {
         let mut elements = Vec::new();
-        Node::recursive_consume_in_order_vec(self.root, &mut elements);
+        Node::recursive_consume_in_order_vec(self.root.take(), &mut elements);
         elements.into_iter()
     }
 
@@ -872,9 +1292,9 @@ impl<T: Ord> BinarySearchTree<T> for RecursiveBST<T> {
     ///
     /// // bst.insert(10); -> COMPILE ERROR
     /// ```
-    fn into_post_order_iter(self) -> IntoIter<T> {
+    fn into_post_order_iter(mut self) -> IntoIter<T> {
         let mut elements = Vec::new();
-        Node::recursive_consume_post_order_vec(self.root, &mut elements);
+        Node::recursive_consume_post_order_vec(self.root.take(), &mut elements);
         elements.into_iter()
     }
 
@@ -903,753 +1323,5072 @@ impl<T: Ord> BinarySearchTree<T> for RecursiveBST<T> {
     ///
     /// // bst.insert(10); -> COMPILE ERROR
     /// ```
-    fn into_level_order_iter(self) -> IntoIter<T> {
+    fn into_level_order_iter(mut self) -> IntoIter<T> {
         let mut elements = Vec::new();
-        Node::recursive_consume_level_order_vec(self.root, &mut elements);
+        Node::recursive_consume_level_order_vec(self.root.take(), &mut elements);
         elements.into_iter()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::vec::IntoIter;
-
-    use crate::{BinarySearchTree, RecursiveBST};
-
-    #[test]
-    fn successfully_insert_elements_into_bst() {
-        let mut expected_bst = RecursiveBST::new();
-        expected_bst.insert(0);
-        expected_bst.insert(1);
-        expected_bst.insert(2);
-        expected_bst.insert(-20);
-
-        let mut actual_bst = RecursiveBST::new();
-        actual_bst.insert(0);
-        actual_bst.insert(1);
-        actual_bst.insert(1);
-        actual_bst.insert(2);
-        actual_bst.insert(-20);
-
-        assert_eq!(actual_bst, expected_bst);
-        assert_eq!(actual_bst.size(), 4);
+    /// Returns the **canonical key** of the tree, which is the sorted sequence of its elements.
+    ///
+    /// Two trees are considered equal if and only if their canonical keys are equal, regardless
+    /// of the order in which elements were inserted or the resulting shape of the tree.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [RecursiveBST::asc_order_vec()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![3, 1, 2]);
+    /// let another_bst = RecursiveBST::from(vec![1, 2, 3]);
+    ///
+    /// assert_eq!(bst.canonical_key(), vec![&1, &2, &3]);
+    /// assert_eq!(bst.canonical_key(), another_bst.canonical_key());
+    /// ```
+    fn canonical_key(&self) -> Vec<&T> {
+        self.asc_order_vec()
     }
 
-    #[test]
-    fn check_if_bst_is_empty() {
-        let mut bst = RecursiveBST::new();
-        assert!(bst.is_empty());
+    /// Inserts given value as a node and returns the **depth** at which the new node was placed
+    /// or `None` if the value already exists.
+    ///
+    /// The depth is the number of edges between the root and the newly inserted node, so a large
+    /// depth indicates that the tree is becoming skewed.
+    ///
+    /// **Duplicate values are _not allowed_**.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    ///
+    /// assert_eq!(bst.insert_with_depth(10), Some(0));
+    /// assert_eq!(bst.insert_with_depth(5), Some(1));
+    /// assert_eq!(bst.insert_with_depth(2), Some(2));
+    /// assert_eq!(bst.insert_with_depth(5), None); // Element is not inserted
+    /// assert_eq!(bst.size(), 3);
+    /// ```
+    fn insert_with_depth(&mut self, value: T) -> Option<usize> {
+        let depth = match self.root {
+            None => {
+                self.root = Some(Box::from(Node::new(value)));
+                Some(0)
+            }
+            Some(ref mut node) => node.recursive_insert(value).ok(),
+        };
 
-        bst.insert(1);
-        assert!(!bst.is_empty());
+        if depth.is_some() {
+            self.size += 1;
+        } else {
+            self.rejected_duplicates += 1;
+        }
+        depth
     }
 
-    #[test]
-    fn check_if_bst_is_not_empty() {
-        let mut bst = RecursiveBST::new();
-        assert!(!bst.is_not_empty());
+    /// Returns the **average depth** of all nodes or `None` if tree is empty.
+    ///
+    /// This is the internal path length (the sum of the depths of all nodes) divided by the
+    /// [size](RecursiveBST::size()) of the tree, which predicts the average cost of a successful
+    /// search.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = RecursiveBST::new();
+    /// assert_eq!(bst.average_search_depth(), None);
+    ///
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The average search depth is 10 / 7.
+    /// assert_eq!(bst.average_search_depth(), Some(10.0 / 7.0));
+    /// ```
+    fn average_search_depth(&self) -> Option<f64> {
+        match self.size {
+            0 => None,
+            size => Some(Node::recursive_internal_path_length(&self.root, 0) as f64 / size as f64),
+        }
+    }
+
+    /// Returns `true` if **every** element of the tree satisfies the given predicate.
+    ///
+    /// The traversal stops as soon as an element that does not satisfy the predicate is found.
+    /// An empty tree always returns `true`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert!(bst.all(|value| *value > 0));
+    /// assert!(!bst.all(|value| *value > 2));
+    /// ```
+    fn all<F: Fn(&T) -> bool>(&self, predicate: F) -> bool {
+        Node::recursive_all(&self.root, &predicate)
+    }
+
+    /// Returns `true` if **any** element of the tree satisfies the given predicate.
+    ///
+    /// The traversal stops as soon as an element that satisfies the predicate is found.
+    /// An empty tree always returns `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert!(bst.any(|value| *value == 7));
+    /// assert!(!bst.any(|value| *value > 100));
+    /// ```
+    fn any<F: Fn(&T) -> bool>(&self, predicate: F) -> bool {
+        Node::recursive_any(&self.root, &predicate)
+    }
+
+    /// Returns the running `(minimum, maximum)` for every prefix of the elements in
+    /// **ascending order.**
+    ///
+    /// The element at position `i` is the minimum and maximum over the first `i + 1` elements.
+    /// As the elements are sorted, this doubles as a sanity check on the ordering of the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// bst.insert(4);
+    /// bst.insert(2);
+    /// bst.insert(6);
+    ///
+    /// assert_eq!(bst.prefix_extremes(), vec![(&2, &2), (&2, &4), (&2, &6)]);
+    /// ```
+    fn prefix_extremes(&self) -> Vec<(&T, &T)> {
+        Node::prefix_extremes(self.in_order_vec())
+    }
+
+    /// Rebalances the tree **in-place** so that it has the minimum possible height.
+    ///
+    /// This uses the [Day-Stout-Warren](https://en.wikipedia.org/wiki/Day%E2%80%93Stout%E2%80%93Warren_algorithm)
+    /// algorithm which first flattens the tree into a sorted "vine" and then rotates the vine
+    /// back into a balanced tree. No nodes are allocated or de-allocated during this process.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// bst.insert(1);
+    /// bst.insert(2);
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(6);
+    /// bst.insert(7);
+    /// assert_eq!(bst.height(), Some(6));
+    ///
+    /// bst.rebalance();
+    ///
+    /// assert_eq!(bst.height(), Some(2));
+    /// assert_eq!(bst.level_order_vec(), vec![&4, &2, &6, &1, &3, &5, &7]);
+    /// ```
+    fn rebalance(&mut self) {
+        Node::dsw_rebalance(&mut self.root, self.size);
+    }
+
+    /// Returns the number of **rotations** that [RecursiveBST::rebalance()] would perform
+    /// on the tree **without** modifying it.
+    ///
+    /// The count consists of the right rotations needed to flatten the tree into a vine (every
+    /// node that is not already on the rightmost path from the root) plus the left rotations
+    /// needed to turn the vine back into a balanced tree (which only depends on the size).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// bst.insert(1);
+    /// bst.insert(2);
+    /// bst.insert(3);
+    ///
+    /// // The tree is already a vine so only one left rotation is required
+    /// assert_eq!(bst.rebalance_cost(), 1);
+    /// ```
+    fn rebalance_cost(&self) -> usize {
+        Node::dsw_rotation_count(&self.root, self.size)
+    }
+
+    /// Returns an **indented outline** of the tree with one element per line in the order of a
+    /// **pre-order traversal.**
+    ///
+    /// Every element is indented by two spaces per level of depth. An empty tree returns an
+    /// empty string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = RecursiveBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// assert_eq!(bst.to_outline(), "4\n  2\n    1\n    3\n  6\n    5\n    7");
+    /// ```
+    fn to_outline(&self) -> String
+    where
+        T: Display,
+    {
+        let mut lines = Vec::new();
+        Node::recursive_outline(&self.root, 0, &mut lines);
+        lines.join("\n")
+    }
+
+    /// Returns the number of elements `(less than, greater than)` the given value or `None` if
+    /// the value does not exist within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// bst.insert(3);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(4);
+    ///
+    /// assert_eq!(bst.side_counts(&3), Some((2, 2)));
+    /// assert_eq!(bst.side_counts(&5), Some((4, 0)));
+    /// assert_eq!(bst.side_counts(&10), None);
+    /// ```
+    fn side_counts(&self, value: &T) -> Option<(usize, usize)> {
+        if !self.contains(value) {
+            return None;
+        }
+
+        let rank = Node::recursive_rank(&self.root, value);
+        Some((rank, self.size - rank - 1))
+    }
+
+    /// Returns an iterator over the elements of **both** trees in **ascending order.**
+    ///
+    /// The trees are merged lazily and elements that exist within both trees are only
+    /// yielded once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![1, 3, 5]);
+    /// let another_bst = RecursiveBST::from(vec![2, 3, 4]);
+    ///
+    /// let merged: Vec<&i32> = bst.merge_iter(&another_bst).collect();
+    ///
+    /// assert_eq!(merged, vec![&1, &2, &3, &4, &5]);
+    /// ```
+    fn merge_iter<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        Node::merge_iter(self.in_order_iter(), other.in_order_iter())
+    }
+
+    /// Returns a histogram mapping every **balance factor** within the tree to the number of
+    /// nodes with that balance factor.
+    ///
+    /// The balance factor of a node is the height of its left subtree minus the height of its
+    /// right subtree (where an empty subtree has a height of `-1`). A well balanced tree only
+    /// contains balance factors of `-1`, `0` and `1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     /
+    ///  //    1
+    /// let mut bst = RecursiveBST::new();
+    /// bst.insert(4);
+    /// bst.insert(2);
+    /// bst.insert(6);
+    /// bst.insert(1);
+    ///
+    /// assert_eq!(bst.balance_factor_histogram(), HashMap::from([(0, 2), (1, 2)]));
+    /// ```
+    fn balance_factor_histogram(&self) -> HashMap<isize, usize> {
+        let mut histogram = HashMap::new();
+        Node::recursive_balance_factor_histogram(&self.root, &mut histogram);
+        histogram
+    }
+
+    /// Returns the tree as a reference to [Any] so that it can be downcast back into a
+    /// `RecursiveBST<T>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst: Box<dyn BinarySearchTree<i32>> = Box::new(RecursiveBST::from(vec![1, 2, 3]));
+    ///
+    /// let recursive_bst = bst.as_any().downcast_ref::<RecursiveBST<i32>>().unwrap();
+    /// assert_eq!(recursive_bst, &RecursiveBST::from(vec![1, 2, 3]));
+    /// ```
+    fn as_any(&self) -> &dyn Any
+    where
+        T: 'static,
+    {
+        self
+    }
+
+    /// Returns the ratio between the actual **height** and the optimal height of the tree or
+    /// `None` if tree is empty.
+    ///
+    /// The optimal height of a tree with `n` nodes is `floor(log2(n))`, therefore a score of
+    /// `1.0` means the tree is perfectly efficient and larger scores mean the tree is
+    /// degenerating into a linked list. A tree with a single node always has a score of `1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// assert_eq!(bst.skew_score(), None);
+    ///
+    /// bst.insert(1);
+    /// bst.insert(2);
+    /// bst.insert(3);
+    ///
+    /// // The height is 2 whereas the optimal height is 1
+    /// assert_eq!(bst.skew_score(), Some(2.0));
+    /// ```
+    fn skew_score(&self) -> Option<f64> {
+        self.height()
+            .map(|height| match Node::<T>::optimal_height(self.size) {
+                0 => 1.0,
+                optimal_height => height as f64 / optimal_height as f64,
+            })
+    }
+
+    /// Returns the number of nodes with **exactly one** child.
+    ///
+    /// These are neither leaves nor full internal nodes; a tree with many of them is on its way
+    /// to degenerating into a linked list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(7);
+    /// bst.insert(8);
+    ///
+    /// // Only 7 has a single child
+    /// assert_eq!(bst.half_nodes(), 1);
+    /// ```
+    fn half_nodes(&self) -> usize {
+        Node::recursive_half_nodes(&self.root)
+    }
+
+    /// Returns references to the elements on the **boundary** of the tree in anti-clockwise order.
+    ///
+    /// The boundary starts at the root, followed by the left boundary top-down (excluding leaves),
+    /// then all leaves from left to right and finally the right boundary bottom-up (excluding
+    /// leaves). Every element appears exactly once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// bst.insert(4);
+    /// bst.insert(2);
+    /// bst.insert(6);
+    /// bst.insert(1);
+    /// bst.insert(3);
+    /// bst.insert(5);
+    /// bst.insert(7);
+    ///
+    /// assert_eq!(bst.boundary_vec(), vec![&4, &2, &1, &3, &5, &7, &6]);
+    /// ```
+    fn boundary_vec(&self) -> Vec<&T> {
+        Node::recursive_boundary_vec(&self.root)
+    }
+
+    /// Returns references to the elements grouped by **vertical column** from left to right.
+    ///
+    /// The root is in column `0`, a left child is one column to the left of its parent and a
+    /// right child is one column to the right. Each column lists its elements top-to-bottom, with
+    /// elements on the same row ordered from left to right.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// bst.insert(4);
+    /// bst.insert(2);
+    /// bst.insert(6);
+    /// bst.insert(1);
+    /// bst.insert(3);
+    /// bst.insert(5);
+    /// bst.insert(7);
+    ///
+    /// assert_eq!(
+    ///     bst.vertical_order(),
+    ///     vec![vec![&1], vec![&2], vec![&4, &3, &5], vec![&6], vec![&7]]
+    /// );
+    /// ```
+    fn vertical_order(&self) -> Vec<Vec<&T>> {
+        Node::recursive_vertical_order(&self.root)
+    }
+
+    /// Returns references to the elements grouped by **diagonal**.
+    ///
+    /// Moving to a right child stays on the same diagonal whereas moving to a left child starts
+    /// the next diagonal. The first diagonal therefore contains the root and its chain of right
+    /// children.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// bst.insert(4);
+    /// bst.insert(2);
+    /// bst.insert(6);
+    /// bst.insert(1);
+    /// bst.insert(3);
+    /// bst.insert(5);
+    /// bst.insert(7);
+    ///
+    /// assert_eq!(bst.diagonal_vec(), vec![vec![&4, &6, &7], vec![&2, &3, &5], vec![&1]]);
+    /// ```
+    fn diagonal_vec(&self) -> Vec<Vec<&T>> {
+        let mut diagonals = Vec::new();
+        Node::recursive_diagonal_vec(&self.root, 0, &mut diagonals);
+        diagonals
+    }
+
+    /// Returns the **maximum sum** over all root-to-leaf paths together with the elements on that
+    /// path or `None` if tree is empty.
+    ///
+    /// If several paths share the maximum sum, the leftmost one is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// assert_eq!(bst.max_path_sum_to_leaf(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(8);
+    /// bst.insert(7);
+    ///
+    /// assert_eq!(bst.max_path_sum_to_leaf(), Some((20, vec![&5, &8, &7])));
+    /// ```
+    fn max_path_sum_to_leaf(&self) -> Option<(T, Vec<&T>)>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        Node::recursive_max_path_sum_to_leaf(&self.root)
+    }
+
+    /// Returns a reference to the element at the **center** of the tree or `None` if tree is empty.
+    ///
+    /// The center is the element with the smallest eccentricity, i.e. the smallest greatest
+    /// distance to any other element when edges are treated as undirected. A tree has at most two
+    /// such elements, in which case the smaller one is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// assert_eq!(bst.center(), None);
+    ///
+    /// bst.insert(1);
+    /// bst.insert(2);
+    /// bst.insert(3);
+    ///
+    /// assert_eq!(bst.center(), Some(&2));
+    /// ```
+    fn center(&self) -> Option<&T> {
+        Node::center(&self.root)
+    }
+
+    /// Returns a new **balanced** tree containing clones of the elements within the inclusive range
+    /// `[low, high]`.
+    ///
+    /// An empty tree is returned if `low` is greater than `high`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![1, 2, 3, 4, 5]);
+    /// let window = bst.clone_range(&2, &4);
+    ///
+    /// assert_eq!(window.asc_order_vec(), vec![&2, &3, &4]);
+    /// assert_eq!(window.height(), Some(1));
+    /// ```
+    fn clone_range(&self, low: &T, high: &T) -> RecursiveBST<T>
+    where
+        T: Clone,
+    {
+        let mut elements = Vec::new();
+        Node::recursive_range_vec(&self.root, low, high, &mut elements);
+        let size = elements.len();
+        RecursiveBST {
+            root: Node::build_balanced(&mut elements.into_iter().cloned(), size),
+            size,
+            rejected_duplicates: 0,
+        }
+    }
+
+    /// Returns the elements of **both** trees aligned by value in **ascending order.**
+    ///
+    /// Elements that exist within both trees are paired as `(Some, Some)` whereas elements that
+    /// only exist within one tree are paired with `None` on the side of the other tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![1, 2, 4]);
+    /// let another_bst = RecursiveBST::from(vec![2, 3, 4]);
+    ///
+    /// assert_eq!(
+    ///     bst.zip_compare(&another_bst),
+    ///     vec![
+    ///         (Some(&1), None),
+    ///         (Some(&2), Some(&2)),
+    ///         (None, Some(&3)),
+    ///         (Some(&4), Some(&4))
+    ///     ]
+    /// );
+    /// ```
+    fn zip_compare<'a>(&'a self, other: &'a Self) -> Vec<(Option<&'a T>, Option<&'a T>)> {
+        Node::zip_compare(self.in_order_iter(), other.in_order_iter())
+    }
+
+    /// Consumes the tree and returns a read-only [FrozenBST] storing all elements within a
+    /// single contiguous array in **Eytzinger** layout.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// bst.insert(1);
+    /// bst.insert(2);
+    /// bst.insert(3);
+    ///
+    /// let frozen_bst = bst.freeze();
+    ///
+    /// assert!(frozen_bst.contains(&2));
+    /// assert_eq!(frozen_bst.level_order_iter().collect::<Vec<&i32>>(), vec![&2, &1, &3]);
+    /// ```
+    fn freeze(self) -> FrozenBST<T> {
+        FrozenBST::from_sorted(self.into_asc_order_iter().collect())
+    }
+
+    /// Returns the number of elements whose rank would **increase by one** if the given value was
+    /// inserted, i.e. the number of elements strictly greater than the value.
+    ///
+    /// The tree is not modified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(bst.rank_shift_on_insert(&0), 5);
+    /// assert_eq!(bst.rank_shift_on_insert(&3), 2);
+    /// assert_eq!(bst.rank_shift_on_insert(&6), 0);
+    /// ```
+    fn rank_shift_on_insert(&self, value: &T) -> usize {
+        Node::recursive_count_greater(&self.root, value)
+    }
+
+    /// Returns a **deterministic fingerprint** of the elements within the tree.
+    ///
+    /// The fingerprint is computed with FNV-1a over the [StableBytes] of every element in
+    /// ascending order, therefore it only depends on the contents (not the shape) of the tree and
+    /// is stable across process runs and machines.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![1, 2, 3]);
+    /// let another_bst = RecursiveBST::from(vec![3, 2, 1]);
+    ///
+    /// assert_eq!(bst.fingerprint(), another_bst.fingerprint());
+    /// ```
+    fn fingerprint(&self) -> u64
+    where
+        T: StableBytes,
+    {
+        fnv1a(self.in_order_iter())
+    }
+
+    /// Inserts the elements of a **sorted** slice by linking them into the tree as a single
+    /// balanced subtree.
+    ///
+    /// The subtree can only be linked in if the slice is in strictly ascending order and no
+    /// existing element lies within the range of the slice. Neither is required though: if the
+    /// slice is not strictly ascending or overlaps with the tree, the elements are inserted one at
+    /// a time instead, in both debug and release builds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::from_iter(1..=3);
+    /// bst.splice_sorted(&[6, 7, 8]);
+    ///
+    /// assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3, &6, &7, &8]);
+    /// assert_eq!(bst.pre_order_vec(), vec![&1, &2, &3, &7, &6, &8]);
+    /// ```
+    fn splice_sorted(&mut self, sorted: &[T])
+    where
+        T: Clone,
+    {
+        let is_sorted = sorted.windows(2).all(|pair| pair[0] < pair[1]);
+
+        let (first, last) = match (sorted.first(), sorted.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return,
+        };
+        let mut overlapping = Vec::new();
+        Node::recursive_range_vec(&self.root, first, last, &mut overlapping);
+        if !is_sorted || !overlapping.is_empty() {
+            for value in sorted {
+                self.insert(value.clone());
+            }
+            return;
+        }
+
+        if let Some(subtree) = Node::build_balanced(&mut sorted.iter().cloned(), sorted.len()) {
+            Node::recursive_splice(&mut self.root, subtree);
+            self.size += sorted.len();
+        }
+    }
+
+    /// Returns the number of nodes at the **deepest level** of the tree.
+    ///
+    /// This indicates how filled out the bottom level of the tree is, an empty tree returns `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// bst.insert(4);
+    /// bst.insert(2);
+    /// bst.insert(6);
+    /// bst.insert(1);
+    /// bst.insert(3);
+    ///
+    /// assert_eq!(bst.deepest_level_width(), 2);
+    /// ```
+    fn deepest_level_width(&self) -> usize {
+        Node::recursive_deepest_level_width(&self.root)
+    }
+
+    /// Returns `true` if the cached [size](Self::size()) matches the actual number of nodes
+    /// within the tree.
+    ///
+    /// The nodes are counted through a full traversal, therefore this is intended as a defensive
+    /// check within tests and debug builds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// bst.insert(1);
+    /// bst.insert(2);
+    /// bst.remove(&1);
+    ///
+    /// assert!(bst.verify_size());
+    /// ```
+    fn verify_size(&self) -> bool {
+        Node::recursive_count(&self.root) == self.size
+    }
+
+    /// Counts the actual number of nodes within the tree and repairs the cached
+    /// [size](Self::size()) if it has drifted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::from_iter(1..=5);
+    /// bst.recount();
+    ///
+    /// assert_eq!(bst.size(), 5);
+    /// ```
+    fn recount(&mut self) {
+        self.size = Node::recursive_count(&self.root);
+    }
+
+    /// Returns references to the elements grouped into buckets by the result of the given `key`
+    /// function.
+    ///
+    /// The elements within every bucket are in **ascending order.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![4, 2, 1, 3]);
+    /// let groups = bst.group_by(|value| *value > 2);
+    ///
+    /// assert_eq!(groups[&false], vec![&1, &2]);
+    /// assert_eq!(groups[&true], vec![&3, &4]);
+    /// ```
+    fn group_by<K: Ord, F: Fn(&T) -> K>(&self, key: F) -> BTreeMap<K, Vec<&T>> {
+        self.in_order_iter()
+            .fold(BTreeMap::new(), |mut groups, value| {
+                groups.entry(key(value)).or_default().push(value);
+                groups
+            })
+    }
+
+    /// Returns the number of element removals needed to bring the **height** of the tree down
+    /// to its optimal height, given the current shape of the tree.
+    ///
+    /// This is the number of nodes beyond the optimal-height frontier, i.e. nodes with a depth
+    /// greater than `floor(log2(n))`. Removing exactly these nodes (deepest first) leaves a tree
+    /// whose height is the optimal height of the current tree, whereas a balanced tree requires
+    /// no removals.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from_iter(1..=5);
+    ///
+    /// // The optimal height is 2, therefore 4 and 5 are beyond the frontier
+    /// assert_eq!(bst.removals_to_balance(), 2);
+    /// ```
+    fn removals_to_balance(&self) -> usize {
+        let optimal_height = Node::<T>::optimal_height(self.size);
+        Node::recursive_count_deeper_than(&self.root, 0, optimal_height)
+    }
+
+    /// Returns the number of edges on the **longest zigzag path** within the tree.
+    ///
+    /// A zigzag path travels downwards and alternates between left and right children at
+    /// every step. An empty tree or a tree with a single node returns `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// bst.insert(10);
+    /// bst.insert(5);
+    /// bst.insert(8);
+    /// bst.insert(6);
+    ///
+    /// // 10 -> 5 -> 8 -> 6 alternates left, right, left
+    /// assert_eq!(bst.longest_zigzag(), 3);
+    /// ```
+    fn longest_zigzag(&self) -> usize {
+        Node::recursive_longest_zigzag(&self.root, 0, 0)
+    }
+
+    /// Returns the **cumulative sums** of the elements in ascending order.
+    ///
+    /// The element at index `i` is the sum of the `i + 1` smallest elements within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![3, 1, 4, 2]);
+    ///
+    /// assert_eq!(bst.prefix_sums(), vec![1, 3, 6, 10]);
+    /// ```
+    fn prefix_sums(&self) -> Vec<T>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        Node::prefix_sums(self.in_order_iter())
+    }
+
+    /// Returns the **sum** of all elements within the inclusive range `[low, high]`, given the
+    /// [prefix_sums](Self::prefix_sums()) of the tree.
+    ///
+    /// The bounds of the range are located through rank lookups and the sum is taken as the
+    /// difference between two of the prefix sums, so every query only takes `O(h)` once the prefix
+    /// sums have been computed. `None` is returned if the range is empty or if `prefix_sums` is
+    /// too short to cover it, e.g. because it was computed before elements were inserted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![3, 1, 4, 2]);
+    ///
+    /// let prefix_sums = bst.prefix_sums();
+    ///
+    /// assert_eq!(bst.range_sum(&prefix_sums, &2, &3), Some(5));
+    /// assert_eq!(bst.range_sum(&prefix_sums, &0, &10), Some(10));
+    /// assert_eq!(bst.range_sum(&prefix_sums, &5, &10), None);
+    /// ```
+    fn range_sum(&self, prefix_sums: &[T], low: &T, high: &T) -> Option<T>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        let start = Node::recursive_rank(&self.root, low);
+        let end = self.size - Node::recursive_count_greater(&self.root, high);
+        Node::range_sum(prefix_sums, start, end)
+    }
+
+    /// Returns `true` if inserting the given value would **increase the height** of the tree.
+    ///
+    /// The depth at which the value would be inserted is found through a dry-run descent and
+    /// compared against the current height, without modifying the tree. Inserting into an empty
+    /// tree counts as an increase whereas a value that already exists never increases the height.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// bst.insert(2);
+    /// bst.insert(1);
+    /// bst.insert(3);
+    /// bst.insert(4);
+    ///
+    /// assert!(bst.would_grow_height(&5));
+    /// assert!(!bst.would_grow_height(&0));
+    /// ```
+    fn would_grow_height(&self, value: &T) -> bool {
+        match Node::recursive_insertion_depth(&self.root, value) {
+            None => false,
+            Some(depth) => self.height().is_none_or(|height| depth as isize > height),
+        }
+    }
+
+    /// Returns a **read-only snapshot** of the elements in ascending order.
+    ///
+    /// The elements are cloned once into an `Arc<[T]>` which can be shared (and cheaply cloned)
+    /// across threads, independently of any later changes to the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::from(vec![3, 1, 2]);
+    /// let snapshot = bst.snapshot();
+    ///
+    /// bst.insert(4);
+    ///
+    /// assert_eq!(*snapshot, [1, 2, 3]);
+    /// ```
+    fn snapshot(&self) -> Arc<[T]>
+    where
+        T: Clone,
+    {
+        self.in_order_iter().cloned().collect()
+    }
+
+    /// Returns the number of **distinct** elements within the inclusive range `[low, high]`.
+    ///
+    /// As duplicates are not stored, this is the number of elements within the range, found in
+    /// `O(h)` as the difference between two rank lookups. See
+    /// [CountingBST::distinct_count_in_range()](crate::CountingBST::distinct_count_in_range())
+    /// for a tree that keeps track of repeated elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![5, 1, 3, 9, 7]);
+    ///
+    /// assert_eq!(bst.distinct_count_in_range(&2, &7), 3);
+    /// assert_eq!(bst.distinct_count_in_range(&7, &2), 0);
+    /// ```
+    fn distinct_count_in_range(&self, low: &T, high: &T) -> usize {
+        // Both lookups count the elements outside of the range, which overlap once low > high
+        (self.size - Node::recursive_count_greater(&self.root, high))
+            .saturating_sub(Node::recursive_rank(&self.root, low))
+    }
+
+    /// Rebuilds the tree so that it has **exactly** the given height.
+    ///
+    /// A tree with `n` elements can only have a height between `floor(log2(n))` and `n - 1`,
+    /// [TooTall] is returned (and the tree is left untouched) for any other target.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST, TooTall};
+    ///
+    /// let mut bst = RecursiveBST::from_iter(1..=7);
+    ///
+    /// assert_eq!(bst.rebalance_to_height(4), Ok(()));
+    /// assert_eq!(bst.height(), Some(4));
+    ///
+    /// assert_eq!(bst.rebalance_to_height(1), Err(TooTall));
+    /// assert_eq!(bst.height(), Some(4));
+    /// ```
+    fn rebalance_to_height(&mut self, target: usize) -> Result<(), TooTall> {
+        if self.size == 0 || target < Node::<T>::optimal_height(self.size) || target > self.size - 1
+        {
+            return Err(TooTall);
+        }
+
+        let mut elements = Vec::new();
+        Node::recursive_consume_in_order_vec(self.root.take(), &mut elements);
+        self.root = Node::build_with_height(&mut elements.into_iter(), self.size, target);
+        Ok(())
+    }
+
+    /// Returns an iterator over the elements in **in-order** paired with `true` if the element
+    /// is a leaf (a node without any children).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// bst.insert(2);
+    /// bst.insert(1);
+    /// bst.insert(3);
+    ///
+    /// let annotated: Vec<(&i32, bool)> = bst.iter_annotated().collect();
+    ///
+    /// assert_eq!(annotated, vec![(&1, true), (&2, false), (&3, true)]);
+    /// ```
+    fn iter_annotated<'a>(&'a self) -> impl Iterator<Item = (&'a T, bool)>
+    where
+        T: 'a,
+    {
+        let mut elements = Vec::new();
+        Node::recursive_iter_annotated(&self.root, &mut elements);
+        elements.into_iter()
+    }
+
+    /// Rotates the subtree rooted at the given **pivot** element in the given direction.
+    ///
+    /// Returns `true` if the rotation was applied or `false` if the pivot does not exist or it
+    /// lacks the child that would take its place.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST, Rotation};
+    ///
+    /// let mut bst = RecursiveBST::from_iter(1..=3);
+    ///
+    /// assert!(bst.rotate(&1, Rotation::Left));
+    /// assert_eq!(bst.pre_order_vec(), vec![&2, &1, &3]);
+    ///
+    /// assert!(!bst.rotate(&1, Rotation::Left));
+    /// assert!(!bst.rotate(&5, Rotation::Right));
+    /// ```
+    fn rotate(&mut self, pivot: &T, rotation: Rotation) -> bool {
+        Node::recursive_rotate(&mut self.root, pivot, rotation)
+    }
+
+    /// Returns the ordered list of `(pivot, direction)` rotations that
+    /// [rebalance](Self::rebalance()) would apply, without modifying the tree.
+    ///
+    /// Applying these rotations in order through [rotate](Self::rotate()) balances the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST, Rotation};
+    ///
+    /// let bst = RecursiveBST::from_iter(1..=3);
+    ///
+    /// assert_eq!(bst.rotations_to_balance(), vec![(1, Rotation::Left)]);
+    /// ```
+    fn rotations_to_balance(&self) -> Vec<(T, Rotation)>
+    where
+        T: Clone,
+    {
+        let mut bst = RecursiveBST::new();
+        for value in self.pre_order_iter() {
+            bst.insert(value.clone());
+        }
+
+        let mut rotations = Vec::new();
+        Node::dsw_rebalance_with(&mut bst.root, bst.size, &mut |pivot: &T, rotation| {
+            rotations.push((pivot.clone(), rotation))
+        });
+        rotations
+    }
+
+    /// Returns a reference to the element or `None` if element does not exist.
+    ///
+    /// The element is **splayed** to the root through rotations, so frequently accessed elements
+    /// become shallow over time. If the element does not exist, the last node reached while
+    /// searching for it is splayed instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::from_iter(1..=5);
+    ///
+    /// assert_eq!(bst.find_splay(&5), Some(&5));
+    /// assert_eq!(bst.pre_order_vec()[0], &5);
+    ///
+    /// assert_eq!(bst.find_splay(&10), None);
+    /// ```
+    fn find_splay(&mut self, value: &T) -> Option<&T> {
+        Node::recursive_find_splay(&mut self.root, value)
+    }
+
+    /// Returns the **Jaccard similarity** between the elements of both trees.
+    ///
+    /// This is the size of the intersection divided by the size of the union, a value within
+    /// `[0.0, 1.0]`. Both sizes are counted within a single pass over the ascending elements.
+    /// Two empty trees are considered identical and therefore have a similarity of `1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![1, 2, 3]);
+    /// let another_bst = RecursiveBST::from(vec![2, 3, 4]);
+    ///
+    /// assert_eq!(bst.jaccard(&another_bst), 0.5);
+    /// assert_eq!(RecursiveBST::<i32>::new().jaccard(&RecursiveBST::new()), 1.0);
+    /// ```
+    fn jaccard(&self, other: &Self) -> f64 {
+        Node::jaccard(self.in_order_iter(), other.in_order_iter())
+    }
+
+    /// Returns an **owning iterator** over a copy of the elements in ascending order.
+    ///
+    /// The elements are cloned once into a buffer owned by the [FrozenIter], which therefore
+    /// keeps yielding the original elements even if the tree is later modified or dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::from(vec![3, 1, 2]);
+    /// let frozen_iter = bst.frozen_iter();
+    ///
+    /// bst.insert(4);
+    /// bst.remove(&1);
+    ///
+    /// assert_eq!(frozen_iter.collect::<Vec<i32>>(), vec![1, 2, 3]);
+    /// ```
+    fn frozen_iter(&self) -> FrozenIter<T>
+    where
+        T: Clone,
+    {
+        FrozenIter::new(self.in_order_iter().cloned().collect())
+    }
+
+    /// Returns a reference to the element at the given **quantile** or `None` if tree is empty.
+    ///
+    /// The quantile `q` is converted into the rank `round(q * (size - 1))`, so `0.0` returns the
+    /// minimum, `0.5` the median and `1.0` the maximum. Quantiles outside of `[0.0, 1.0]` are
+    /// clamped into the range whereas `NaN` returns `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![30, 10, 50, 20, 40]);
+    ///
+    /// assert_eq!(bst.quantile(0.0), Some(&10));
+    /// assert_eq!(bst.quantile(0.5), Some(&30));
+    /// assert_eq!(bst.quantile(1.0), Some(&50));
+    /// assert_eq!(bst.quantile(2.0), Some(&50)); // Clamped to 1.0
+    /// ```
+    fn quantile(&self, q: f64) -> Option<&T> {
+        if self.is_empty() || q.is_nan() {
+            return None;
+        }
+
+        let rank = (q.clamp(0.0, 1.0) * (self.size - 1) as f64).round() as usize;
+        self.select(rank)
+    }
+
+    /// Returns an iterator over the elements **strictly greater** than the given value in
+    /// **ascending order.**
+    ///
+    /// The iterator starts at the successor of the value, which does not need to exist within
+    /// the tree, making it suitable for paging through the elements after the last one seen.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![3, 1, 4, 2, 5]);
+    ///
+    /// assert_eq!(bst.iter_after(&3).collect::<Vec<&i32>>(), vec![&4, &5]);
+    /// assert_eq!(bst.iter_after(&0).count(), 5);
+    /// assert_eq!(bst.iter_after(&5).next(), None);
+    /// ```
+    fn iter_after<'a>(&'a self, value: &T) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        let mut elements = Vec::new();
+        Node::recursive_iter_after(&self.root, value, &mut elements);
+        elements.into_iter()
+    }
+
+    /// Returns every element paired with the **fill ratio** of its subtree in ascending order.
+    ///
+    /// The fill ratio is the number of elements within the subtree divided by the number of
+    /// elements a perfect tree of the same height could hold, so `1.0` marks a perfectly dense
+    /// subtree (such as a leaf) and lower scores mark increasingly sparse or skewed subtrees.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from_iter(1..=3);
+    ///
+    /// assert_eq!(bst.subtree_balance_scores(), vec![(&1, 3.0 / 7.0), (&2, 2.0 / 3.0), (&3, 1.0)]);
+    /// ```
+    fn subtree_balance_scores(&self) -> Vec<(&T, f64)> {
+        let mut scores = Vec::new();
+        Node::recursive_subtree_balance_scores(&self.root, &mut scores);
+        scores
+    }
+
+    /// Returns the **expected number of nodes visited** by a successful search or `None` if tree
+    /// is empty.
+    ///
+    /// Every element is assumed to be searched for with the same probability. As finding a node
+    /// visits the node itself and all of its ancestors, this is the
+    /// [average search depth](RecursiveBST::average_search_depth()) plus one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// assert_eq!(bst.expected_search_visits(), None);
+    ///
+    /// bst.insert(1);
+    /// assert_eq!(bst.expected_search_visits(), Some(1.0));
+    /// ```
+    fn expected_search_visits(&self) -> Option<f64> {
+        match self.size {
+            0 => None,
+            size => Some(
+                (Node::recursive_internal_path_length(&self.root, 0) + size) as f64 / size as f64,
+            ),
+        }
+    }
+
+    /// Returns every `step`-th element by rank (ranks `0`, `step`, `2 * step`, ...) in
+    /// ascending order, giving an evenly spaced sample of the tree.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from_iter(1..=8);
+    ///
+    /// assert_eq!(bst.sample_by_step(3), vec![&1, &4, &7]);
+    /// assert_eq!(bst.sample_by_step(10), vec![&1]);
+    /// ```
+    fn sample_by_step(&self, step: usize) -> Vec<&T> {
+        assert!(step > 0, "step must be greater than zero");
+        self.in_order_iter().step_by(step).collect()
+    }
+
+    /// Returns `true` if `other` can be obtained from this tree through a sequence of
+    /// **rotations** only.
+    ///
+    /// Rotations preserve the in-order sequence of a binary search tree, so this holds exactly
+    /// when both trees are valid binary search trees containing the same elements, regardless
+    /// of their shapes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![1, 2, 3]);
+    /// let another_bst = RecursiveBST::from(vec![2, 1, 3]);
+    ///
+    /// assert!(bst.is_rotation_of(&another_bst));
+    /// assert!(!bst.is_rotation_of(&RecursiveBST::from(vec![1, 2, 4])));
+    /// ```
+    fn is_rotation_of(&self, other: &Self) -> bool {
+        // A strictly ascending in-order sequence is equivalent to a valid binary search tree
+        self.in_order_iter().is_sorted_by(|a, b| a < b)
+            && self.in_order_iter().eq(other.in_order_iter())
+    }
+
+    /// Inserts every value of the given iterator, [rebalancing](RecursiveBST::rebalance()) the tree
+    /// after every `rebalance_every` insertions.
+    ///
+    /// This prevents the tree from degenerating into a vine while loading long (for example
+    /// sorted) streams of elements. A `rebalance_every` of `0` never rebalances.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// bst.insert_stream_autobalance(1..=7, 7);
+    ///
+    /// assert_eq!(bst.size(), 7);
+    /// assert_eq!(bst.height(), Some(2));
+    /// ```
+    fn insert_stream_autobalance<I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+        rebalance_every: usize,
+    ) {
+        for (count, value) in iter.into_iter().enumerate() {
+            self.insert(value);
+            if rebalance_every > 0 && (count + 1) % rebalance_every == 0 {
+                self.rebalance();
+            }
+        }
+    }
+
+    /// Returns a reference to the **positional median** of the tree or `None` if tree is empty.
+    ///
+    /// For an odd number of elements this is the middle element by rank, whereas for an even
+    /// number of elements the **lower** of both middle elements is returned, i.e. the element
+    /// at rank `(size - 1) / 2`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::from(vec![10, 40, 20]);
+    /// assert_eq!(bst.element_nearest_median(), Some(&20));
+    ///
+    /// bst.insert(30);
+    /// assert_eq!(bst.element_nearest_median(), Some(&20));
+    /// ```
+    fn element_nearest_median(&self) -> Option<&T> {
+        match self.size {
+            0 => None,
+            size => self.select((size - 1) / 2),
+        }
+    }
+
+    /// Returns the number of elements `(only_in_self, in_both, only_in_other)` when comparing
+    /// the elements of both trees.
+    ///
+    /// All three counts are computed within a single pass over the ascending elements of both
+    /// trees, without building any intermediate trees.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![1, 2, 3]);
+    /// let another_bst = RecursiveBST::from(vec![2, 3, 4]);
+    ///
+    /// assert_eq!(bst.diff_sizes(&another_bst), (1, 2, 1));
+    /// ```
+    fn diff_sizes(&self, other: &Self) -> (usize, usize, usize) {
+        Node::diff_sizes(self.in_order_iter(), other.in_order_iter())
+    }
+
+    /// Returns an [IterativeBST] with the **exact same structure** as this tree, where every
+    /// element has been replaced by the result of calling the given function on it.
+    ///
+    /// **The function is _not_ required to preserve the ordering of the elements, therefore the
+    /// returned tree may violate the ordering of a binary search tree.** Such a tree is only
+    /// suitable for inspecting its structure (for example through traversals), as searching
+    /// within it or modifying it gives unspecified results.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![2, 1, 3]);
+    /// let remapped_bst = bst.remap_shape(|value| value * -10);
+    ///
+    /// // The ordering is reversed but the structure is kept
+    /// assert_eq!(remapped_bst.pre_order_vec(), vec![&-20, &-10, &-30]);
+    /// ```
+    fn remap_shape<U: Ord, F: FnMut(&T) -> U>(&self, mut f: F) -> IterativeBST<U> {
+        IterativeBST::from_parts(Node::recursive_remap_shape(&self.root, &mut f), self.size)
+    }
+
+    /// Consumes the tree and returns the `k` **smallest** elements in ascending order.
+    ///
+    /// The consuming in-order traversal stops as soon as `k` elements have been collected
+    /// instead of draining the whole tree. All elements are returned if `k` is greater than the
+    /// size of the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![5, 2, 8, 1, 9]);
+    ///
+    /// assert_eq!(bst.into_k_smallest(3), vec![1, 2, 5]);
+    /// ```
+    fn into_k_smallest(mut self, k: usize) -> Vec<T> {
+        let mut elements = Vec::new();
+        Node::recursive_consume_k_smallest(self.root.take(), k, &mut elements);
+        elements
+    }
+
+    /// Returns the **shared prefix** of the paths from the root to both given values, which ends
+    /// at their lowest common ancestor.
+    ///
+    /// An empty vector is returned if either value does not exist within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![4, 2, 6, 1, 3, 5, 7]);
+    ///
+    /// assert_eq!(bst.common_path(&1, &3), vec![&4, &2]);
+    /// assert_eq!(bst.common_path(&1, &7), vec![&4]);
+    /// assert!(bst.common_path(&1, &8).is_empty());
+    /// ```
+    fn common_path(&self, a: &T, b: &T) -> Vec<&T> {
+        let mut path = Vec::new();
+        if self.contains(a) && self.contains(b) {
+            Node::recursive_common_path(&self.root, a, b, &mut path);
+        }
+        path
+    }
+
+    /// Returns the number of **completely filled** levels, counting downwards from the root.
+    ///
+    /// The level at depth `d` is full when it contains exactly `2^d` nodes and the count stops
+    /// at the first level that is not full. An empty tree has no full levels.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// bst.insert(2);
+    /// bst.insert(1);
+    /// bst.insert(3);
+    /// bst.insert(4);
+    ///
+    /// assert_eq!(bst.full_levels(), 2);
+    /// ```
+    fn full_levels(&self) -> usize {
+        let mut histogram = Vec::new();
+        Node::recursive_depth_histogram(&self.root, 0, &mut histogram);
+        Node::<T>::full_levels(histogram)
+    }
+
+    /// Returns up to `radius` elements on **each side** of the given value by rank, alongside
+    /// the value itself, in ascending order.
+    ///
+    /// Fewer elements are returned on one side when the value is close to the minimum or the
+    /// maximum of the tree. An empty vector is returned if the value does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from_iter(1..=7);
+    ///
+    /// assert_eq!(bst.take_window_around(&4, 2), vec![&2, &3, &4, &5, &6]);
+    /// assert_eq!(bst.take_window_around(&1, 2), vec![&1, &2, &3]);
+    /// ```
+    fn take_window_around(&self, center: &T, radius: usize) -> Vec<&T> {
+        if !self.contains(center) {
+            return Vec::new();
+        }
+
+        let rank = Node::recursive_rank(&self.root, center);
+        let start = rank.saturating_sub(radius);
+        self.in_order_iter()
+            .skip(start)
+            .take((rank - start + 1).saturating_add(radius))
+            .collect()
+    }
+
+    /// Returns the number of **duplicate insertions** that have been rejected since the tree
+    /// was created.
+    ///
+    /// Every call to [insert()](RecursiveBST::insert()),
+    /// [insert_with_depth()](RecursiveBST::insert_with_depth()) or
+    /// [try_insert()](RecursiveBST::try_insert()) with a value that already exists increments this
+    /// counter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// bst.insert(1);
+    /// bst.insert(1); // Element is not inserted
+    ///
+    /// assert_eq!(bst.rejected_duplicates(), 1);
+    /// ```
+    fn rejected_duplicates(&self) -> usize {
+        self.rejected_duplicates
+    }
+
+    /// Returns the single `(pivot, direction)` rotation that would reduce the **internal path
+    /// length** of the tree the most, or `None` if no rotation would reduce it.
+    ///
+    /// This allows a tree to be improved incrementally, one [rotate](RecursiveBST::rotate()) at a
+    /// time, instead of through a full [rebalance](RecursiveBST::rebalance()).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::from_iter(1..=3);
+    /// let (pivot, rotation) = bst.best_single_rotation().unwrap();
+    /// bst.rotate(&pivot, rotation);
+    ///
+    /// assert_eq!(bst.height(), Some(1));
+    /// assert_eq!(bst.best_single_rotation(), None);
+    /// ```
+    fn best_single_rotation(&self) -> Option<(T, Rotation)>
+    where
+        T: Clone,
+    {
+        let mut best = None;
+        Node::recursive_best_single_rotation(&self.root, &mut best);
+        best.map(|(pivot, rotation, _)| (pivot.clone(), rotation))
+    }
+
+    /// Returns the elements grouped into one row per level, each paired with its **positional
+    /// slot** within that level.
+    ///
+    /// The level `l` has `2^l` slots, so that the children of the node at slot `i` are always
+    /// found at slots `2i` and `2i + 1` of the next row. Only the slots holding a node are
+    /// returned, in ascending order, therefore a gap between two consecutive slots marks missing
+    /// nodes and every row is only as long as the number of nodes on its level.
+    ///
+    /// `None` is returned if a slot does not fit into a `usize`, which can only happen for nodes
+    /// at a depth greater than `usize::BITS`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::from_iter(1..=3);
+    /// bst.rebalance();
+    /// bst.insert(4);
+    ///
+    /// assert_eq!(
+    ///     bst.render_rows(),
+    ///     Some(vec![
+    ///         vec![(0, &2)],
+    ///         vec![(0, &1), (1, &3)],
+    ///         vec![(3, &4)]
+    ///     ])
+    /// );
+    /// ```
+    fn render_rows(&self) -> Option<Vec<Vec<(usize, &T)>>> {
+        let mut rows = Vec::new();
+        Node::recursive_render_rows(&self.root, 0, 0, &mut rows)?;
+        Some(rows)
+    }
+
+    /// Returns a reference to the **in-order predecessor** of the given value, the largest
+    /// element strictly smaller than it, or `None` if there is no such element.
+    ///
+    /// The value itself does not need to exist within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![8, 3, 10, 1, 6]);
+    ///
+    /// assert_eq!(bst.predecessor(&8), Some(&6));
+    /// assert_eq!(bst.predecessor(&9), Some(&8));
+    /// assert_eq!(bst.predecessor(&1), None);
+    /// ```
+    fn predecessor(&self, value: &T) -> Option<&T> {
+        Node::recursive_predecessor(&self.root, value)
+    }
+
+    /// Returns a reference to the **in-order successor** of the given value, the smallest
+    /// element strictly greater than it, or `None` if there is no such element.
+    ///
+    /// The value itself does not need to exist within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![8, 3, 10, 1, 6]);
+    ///
+    /// assert_eq!(bst.successor(&6), Some(&8));
+    /// assert_eq!(bst.successor(&4), Some(&6));
+    /// assert_eq!(bst.successor(&10), None);
+    /// ```
+    fn successor(&self, value: &T) -> Option<&T> {
+        Node::recursive_successor(&self.root, value)
+    }
+
+    /// Consumes the tree and returns the elements **strictly greater** than the threshold in
+    /// ascending order.
+    ///
+    /// Subtrees that only hold elements smaller than or equal to the threshold are dropped
+    /// during the consuming in-order traversal without being collected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![5, 2, 8, 1, 9]);
+    ///
+    /// assert_eq!(bst.into_greater_than(&2), vec![5, 8, 9]);
+    /// ```
+    fn into_greater_than(mut self, threshold: &T) -> Vec<T> {
+        let mut elements = Vec::new();
+        Node::recursive_consume_greater_than(self.root.take(), threshold, &mut elements);
+        elements
+    }
+
+    /// Returns the size of the **largest subtree** directly below the root as a fraction of the
+    /// size of the tree.
+    ///
+    /// A value close to `0.5` means removing the root splits the tree evenly, whereas a value
+    /// close to `1.0` means the tree hangs off to one side. Empty and single element trees
+    /// return `0.0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::from_iter(1..=4);
+    /// assert_eq!(bst.max_subtree_fraction(), 0.75);
+    ///
+    /// bst.rebalance();
+    /// assert_eq!(bst.max_subtree_fraction(), 0.5);
+    /// ```
+    fn max_subtree_fraction(&self) -> f64 {
+        match self.size {
+            0 => 0.0,
+            size => Node::recursive_largest_child_subtree(&self.root) as f64 / size as f64,
+        }
+    }
+
+    /// Returns a **multi-line diagram** of the tree, connecting every element to its children
+    /// through `├──` and `└──` branches.
+    ///
+    /// Children are listed left before right, a node with a single child connects it through
+    /// `└──`. An empty tree returns an empty string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::from_iter(1..=3);
+    /// bst.rebalance();
+    /// bst.insert(4);
+    ///
+    /// assert_eq!(bst.pretty_print(), "2\n├── 1\n└── 3\n    └── 4");
+    /// ```
+    fn pretty_print(&self) -> String
+    where
+        T: Display,
+    {
+        Node::pretty_print(&self.root)
+    }
+
+    /// Returns the **ancestors** of the given value, ordered from its parent up to the root, or
+    /// `None` if the value does not exist within the tree.
+    ///
+    /// The root has no ancestors, so an empty vector is returned for it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::from_iter(1..=7);
+    /// bst.rebalance();
+    ///
+    /// assert_eq!(bst.ancestors(&5), Some(vec![&6, &4]));
+    /// assert_eq!(bst.ancestors(&4), Some(vec![]));
+    /// assert_eq!(bst.ancestors(&8), None);
+    /// ```
+    fn ancestors(&self, value: &T) -> Option<Vec<&T>> {
+        Node::recursive_ancestors(&self.root, value)
+    }
+
+    /// Returns the **descendants** of the given value in ascending order, every element within
+    /// the subtree rooted at it apart from the value itself, or `None` if the value does not
+    /// exist within the tree.
+    ///
+    /// A leaf has no descendants, so an empty vector is returned for it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::from_iter(1..=7);
+    /// bst.rebalance();
+    ///
+    /// assert_eq!(bst.descendants(&6), Some(vec![&5, &7]));
+    /// assert_eq!(bst.descendants(&7), Some(vec![]));
+    /// assert_eq!(bst.descendants(&8), None);
+    /// ```
+    fn descendants(&self, value: &T) -> Option<Vec<&T>> {
+        Node::recursive_descendants(&self.root, value)
+    }
+
+    /// Returns the **rank** of the given value, the number of elements strictly smaller than it.
+    ///
+    /// The value itself does not need to exist within the tree. As every node keeps track of the
+    /// size of its subtree, this only walks a single path down from the root.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![10, 20, 30]);
+    ///
+    /// assert_eq!(bst.rank(&10), 0);
+    /// assert_eq!(bst.rank(&25), 2);
+    /// assert_eq!(bst.rank(&99), 3);
+    /// ```
+    fn rank(&self, value: &T) -> usize {
+        Node::recursive_rank(&self.root, value)
+    }
+
+    /// Returns a reference to the `k`-th **smallest** element (starting from `0`) or `None` if
+    /// `k` is not smaller than the size of the tree.
+    ///
+    /// As every node keeps track of the size of its subtree, this only walks a single path down
+    /// from the root.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![10, 30, 20]);
+    ///
+    /// assert_eq!(bst.select(0), Some(&10));
+    /// assert_eq!(bst.select(2), Some(&30));
+    /// assert_eq!(bst.select(3), None);
+    /// ```
+    fn select(&self, k: usize) -> Option<&T> {
+        Node::recursive_select(&self.root, k)
+    }
+
+    /// Rebalances only the **subtree** rooted at the given value, leaving the rest of the tree
+    /// untouched. Returns `true` if the value exists within the tree.
+    ///
+    /// This is cheaper than [rebalance](RecursiveBST::rebalance()) when the imbalance is known to be
+    /// local to a single subtree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::from_iter(1..=3);
+    /// bst.rebalance();
+    /// bst.extend(4..=6);
+    /// assert_eq!(bst.height(), Some(4));
+    ///
+    /// assert!(bst.rebalance_subtree(&3));
+    /// assert_eq!(bst.height(), Some(3));
+    /// assert!(!bst.rebalance_subtree(&7));
+    /// ```
+    fn rebalance_subtree(&mut self, value: &T) -> bool {
+        Node::recursive_rebalance_subtree(&mut self.root, value)
+    }
+
+    /// Returns an iterator over the elements within the inclusive range `[low, high]` in
+    /// **ascending order.**
+    ///
+    /// Subtrees lying entirely outside the range are never visited. Neither bound needs to exist
+    /// within the tree and an empty iterator is returned if `low` is greater than `high`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from_iter(1..=10);
+    ///
+    /// assert_eq!(bst.range(&3, &7).collect::<Vec<&i32>>(), vec![&3, &4, &5, &6, &7]);
+    /// assert_eq!(bst.range(&0, &2).collect::<Vec<&i32>>(), vec![&1, &2]);
+    /// assert_eq!(bst.range(&7, &3).next(), None);
+    /// ```
+    fn range(&self, low: &T, high: &T) -> IntoIter<&T> {
+        let mut elements = Vec::new();
+        Node::recursive_range_vec(&self.root, low, high, &mut elements);
+        elements.into_iter()
+    }
+
+    /// Returns the number of elements falling into each of the **buckets** separated by the
+    /// given sorted boundaries.
+    ///
+    /// For `k` boundaries `k + 1` counts are returned, one for each of the half-open buckets
+    /// `(-inf, b0), [b0, b1), ..., [b(k-1), +inf)`. The counts are gathered within a single
+    /// in-order sweep of the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from_iter(1..=10);
+    ///
+    /// assert_eq!(bst.bucket_counts(&[3, 8]), vec![2, 5, 3]);
+    /// assert_eq!(bst.bucket_counts(&[]), vec![10]);
+    /// ```
+    fn bucket_counts(&self, boundaries: &[T]) -> Vec<usize> {
+        Node::bucket_counts(self.in_order_iter(), boundaries)
+    }
+
+    /// Removes and returns every element satisfying the given predicate in **ascending order**,
+    /// similar to `Vec::extract_if`.
+    ///
+    /// The tree is drained, split by the predicate and rebuilt as a **balanced** tree from the
+    /// remaining elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::from_iter(1..=7);
+    ///
+    /// assert_eq!(bst.extract_if(|value| value % 3 == 0), vec![3, 6]);
+    /// assert_eq!(bst.asc_order_vec(), vec![&1, &2, &4, &5, &7]);
+    /// assert_eq!(bst.size(), 5);
+    /// ```
+    fn extract_if<F: FnMut(&T) -> bool>(&mut self, predicate: F) -> Vec<T> {
+        let mut elements = Vec::new();
+        Node::recursive_consume_in_order_vec(self.root.take(), &mut elements);
+        let (extracted, remaining): (Vec<T>, Vec<T>) = elements.into_iter().partition(predicate);
+        self.size = remaining.len();
+        self.root = Node::build_balanced(&mut remaining.into_iter(), self.size);
+        extracted
+    }
+
+    /// Returns a reference to the element equal to the given value, inserting the value first
+    /// if it does not exist within the tree yet.
+    ///
+    /// The element is located or created within a **single** walk down from the root. Unlike
+    /// [insert()](RecursiveBST::insert()), finding an existing element does not count towards
+    /// [rejected_duplicates()](RecursiveBST::rejected_duplicates()).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::from(vec![(2, 'a')]);
+    ///
+    /// assert_eq!(bst.get_or_insert((2, 'a')), &(2, 'a'));
+    /// assert_eq!(bst.get_or_insert((1, 'b')), &(1, 'b'));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn get_or_insert(&mut self, value: T) -> &T {
+        let (element, inserted) = Node::recursive_get_or_insert(&mut self.root, value);
+        if inserted {
+            self.size += 1;
+        }
+        element
+    }
+
+    /// Returns the **expected number of nodes visited** by a successful search when the elements
+    /// are accessed according to a Zipf distribution with exponent `s`, or `None` if the tree is
+    /// empty or `s` is `NaN`.
+    ///
+    /// The element of rank `r` (starting from `1` for the minimum) is accessed with a weight of
+    /// `1 / r^s`, so smaller elements are accessed more often. A lower cost means frequently
+    /// accessed elements sit closer to the root, whereas an exponent of `0` weights every element
+    /// equally and matches [expected_search_visits()](RecursiveBST::expected_search_visits()).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// assert_eq!(bst.zipf_expected_cost(1.0), None);
+    ///
+    /// bst.insert(1);
+    /// bst.insert(2);
+    /// assert_eq!(bst.zipf_expected_cost(1.0), Some(4.0 / 3.0));
+    /// ```
+    fn zipf_expected_cost(&self, s: f64) -> Option<f64> {
+        let mut depths = Vec::new();
+        Node::recursive_in_order_depths(&self.root, 0, &mut depths);
+        Node::<T>::zipf_expected_cost(depths, s)
+    }
+
+    /// Returns a new **balanced** tree containing clones of the elements that exist within
+    /// **either** tree.
+    ///
+    /// As both trees are traversed in ascending order, the elements are merged in a single pass
+    /// without any comparisons against the new tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![1, 2, 3]);
+    /// let other_bst = RecursiveBST::from(vec![3, 4, 5]);
+    ///
+    /// let union = bst.union(&other_bst);
+    /// assert_eq!(union.asc_order_vec(), vec![&1, &2, &3, &4, &5]);
+    /// assert_eq!(union.height(), Some(2));
+    /// ```
+    fn union(&self, other: &Self) -> RecursiveBST<T>
+    where
+        T: Clone,
+    {
+        let elements: Vec<&T> =
+            Node::merge_iter(self.in_order_iter(), other.in_order_iter()).collect();
+        let size = elements.len();
+        RecursiveBST {
+            root: Node::build_balanced(&mut elements.into_iter().cloned(), size),
+            size,
+            rejected_duplicates: 0,
+        }
+    }
+
+    /// Returns a new **balanced** tree containing clones of the elements that exist within
+    /// **both** trees.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![1, 2, 3]);
+    /// let other_bst = RecursiveBST::from(vec![3, 4, 5]);
+    ///
+    /// assert_eq!(bst.intersection(&other_bst).asc_order_vec(), vec![&3]);
+    /// ```
+    fn intersection(&self, other: &Self) -> RecursiveBST<T>
+    where
+        T: Clone,
+    {
+        let elements: Vec<&T> = Node::zip_compare(self.in_order_iter(), other.in_order_iter())
+            .into_iter()
+            .filter_map(|pair| match pair {
+                (Some(value), Some(_)) => Some(value),
+                _ => None,
+            })
+            .collect();
+        let size = elements.len();
+        RecursiveBST {
+            root: Node::build_balanced(&mut elements.into_iter().cloned(), size),
+            size,
+            rejected_duplicates: 0,
+        }
+    }
+
+    /// Returns a new **balanced** tree containing clones of the elements that exist within this
+    /// tree but **not** within `other`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![1, 2, 3]);
+    /// let other_bst = RecursiveBST::from(vec![3, 4, 5]);
+    ///
+    /// assert_eq!(bst.difference(&other_bst).asc_order_vec(), vec![&1, &2]);
+    /// assert_eq!(other_bst.difference(&bst).asc_order_vec(), vec![&4, &5]);
+    /// ```
+    fn difference(&self, other: &Self) -> RecursiveBST<T>
+    where
+        T: Clone,
+    {
+        let elements: Vec<&T> = Node::zip_compare(self.in_order_iter(), other.in_order_iter())
+            .into_iter()
+            .filter_map(|pair| match pair {
+                (Some(value), None) => Some(value),
+                _ => None,
+            })
+            .collect();
+        let size = elements.len();
+        RecursiveBST {
+            root: Node::build_balanced(&mut elements.into_iter().cloned(), size),
+            size,
+            rejected_duplicates: 0,
+        }
+    }
+
+    /// Returns a compact **histogram** of the number of nodes on every level of the tree.
+    ///
+    /// Every level is rendered on its own line, starting from the root, as a bar containing one
+    /// `#` per node on that level. An empty string is returned for an empty tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![2, 1, 3, 4]);
+    ///
+    /// assert_eq!(bst.level_width_bars(), "#\n##\n#");
+    /// ```
+    fn level_width_bars(&self) -> String {
+        let mut histogram = Vec::new();
+        Node::recursive_depth_histogram(&self.root, 0, &mut histogram);
+        Node::<T>::level_width_bars(histogram)
+    }
+
+    /// Returns `true` if the tree is **height-balanced**, i.e. the heights of the left and right
+    /// subtrees of every node differ by at most `1`.
+    ///
+    /// An empty tree is always balanced.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::from(vec![2, 1, 3]);
+    /// assert!(bst.is_balanced());
+    ///
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// assert!(!bst.is_balanced());
+    /// ```
+    fn is_balanced(&self) -> bool {
+        self.balance_factor_histogram()
+            .keys()
+            .all(|balance_factor| balance_factor.abs() <= 1)
+    }
+
+    /// Rebuilds the tree into a **height-balanced** shape, keeping the same elements.
+    ///
+    /// Unlike [rebalance](RecursiveBST::rebalance()), the nodes are drained into a sorted list and
+    /// the tree is rebuilt by recursively picking the median of every sublist as the root of its
+    /// subtree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::from_iter(1..=7);
+    /// assert_eq!(bst.height(), Some(6));
+    ///
+    /// bst.balance();
+    /// assert_eq!(bst.height(), Some(2));
+    /// assert!(bst.is_balanced());
+    /// ```
+    fn balance(&mut self) {
+        let mut elements = Vec::new();
+        Node::recursive_consume_in_order_vec(self.root.take(), &mut elements);
+        self.root = Node::build_balanced(&mut elements.into_iter(), self.size);
+    }
+
+    /// Returns the **diameter** of the tree, the number of edges on the longest path between any
+    /// two nodes.
+    ///
+    /// The longest path does not necessarily pass through the root. An empty tree or a tree with
+    /// a single element has a diameter of `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// assert_eq!(bst.diameter(), 0);
+    ///
+    /// bst.insert(1);
+    /// assert_eq!(bst.diameter(), 0);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(3);
+    /// assert_eq!(bst.diameter(), 2);
+    /// ```
+    fn diameter(&self) -> usize {
+        Node::recursive_height_and_diameter(&self.root).1
+    }
+
+    /// Returns a reference to the **lowest common ancestor** of both given values or `None` if
+    /// either value does not exist within the tree.
+    ///
+    /// The lowest common ancestor is the deepest element that has both values within its
+    /// subtree, where an element counts as being within its own subtree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::from_iter(1..=7);
+    /// bst.rebalance();
+    ///
+    /// assert_eq!(bst.lowest_common_ancestor(&1, &3), Some(&2));
+    /// assert_eq!(bst.lowest_common_ancestor(&3, &5), Some(&4));
+    /// assert_eq!(bst.lowest_common_ancestor(&6, &7), Some(&6));
+    /// assert_eq!(bst.lowest_common_ancestor(&1, &8), None);
+    /// ```
+    fn lowest_common_ancestor(&self, a: &T, b: &T) -> Option<&T> {
+        if !self.contains(a) || !self.contains(b) {
+            return None;
+        }
+
+        Node::recursive_lowest_common_ancestor(&self.root, a, b)
+    }
+
+    /// Removes every given value from the tree and returns the number of elements that were
+    /// actually removed.
+    ///
+    /// Values that do not exist within the tree are ignored and a value given more than once is
+    /// only removed (and counted) once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::from_iter(1..=5);
+    ///
+    /// assert_eq!(bst.remove_all(&[1, 3, 3, 6]), 2);
+    /// assert_eq!(bst.asc_order_vec(), vec![&2, &4, &5]);
+    /// ```
+    fn remove_all(&mut self, values: &[T]) -> usize {
+        let removed = values
+            .iter()
+            .filter(|value| Node::recursive_remove(&mut self.root, value).is_ok())
+            .count();
+        self.size -= removed;
+        removed
+    }
+
+    /// Inserts the given value into the tree, returning `Ok(())` if it was newly inserted.
+    ///
+    /// Unlike [insert()](RecursiveBST::insert()), an `Err` handing the value back is returned if an
+    /// equal element already exists, so the value can be reused. Rejected values count towards
+    /// [rejected_duplicates()](RecursiveBST::rejected_duplicates()).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    ///
+    /// assert_eq!(bst.try_insert(1), Ok(()));
+    /// assert_eq!(bst.try_insert(1), Err(1));
+    /// assert_eq!(bst.size(), 1);
+    /// ```
+    fn try_insert(&mut self, value: T) -> Result<(), T> {
+        match self.root {
+            None => self.root = Some(Box::from(Node::new(value))),
+            Some(ref mut node) => {
+                if let Err(value) = node.recursive_insert(value) {
+                    self.rejected_duplicates += 1;
+                    return Err(value);
+                }
+            }
+        }
+
+        self.size += 1;
+        Ok(())
+    }
+
+    /// Returns the **width** of the tree, the largest number of nodes on any single level.
+    ///
+    /// An empty tree has a width of `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// assert_eq!(bst.width(), 0);
+    ///
+    /// bst.insert(1);
+    /// assert_eq!(bst.width(), 1);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(3);
+    /// assert_eq!(bst.width(), 1);
+    /// ```
+    fn width(&self) -> usize {
+        let mut histogram = Vec::new();
+        Node::recursive_depth_histogram(&self.root, 0, &mut histogram);
+        histogram.into_iter().max().unwrap_or(0)
+    }
+
+    /// Returns a lazy iterator over **mutable** references to the elements in ascending order.
+    ///
+    /// # Important
+    ///
+    /// The elements are modified in place without being moved within the tree, so it is the
+    /// responsibility of the caller to keep them in the same relative order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::from(vec![(1, 'a'), (2, 'b')]);
+    /// for (_, letter) in bst.in_order_iter_mut() {
+    ///     *letter = letter.to_ascii_uppercase();
+    /// }
+    ///
+    /// assert_eq!(bst.asc_order_vec(), vec![&(1, 'A'), &(2, 'B')]);
+    /// ```
+    fn in_order_iter_mut(&mut self) -> InOrderIterMut<'_, T> {
+        InOrderIterMut::new(&mut self.root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+    use std::collections::{BTreeMap, HashMap};
+    use std::sync::Arc;
+    use std::vec::IntoIter;
+
+    use crate::{
+        BinarySearchTree, BuildReport, IterativeBST, Node, NotSorted, ReconstructError,
+        RecursiveBST, Rotation, TooTall,
+    };
+
+    #[test]
+    fn successfully_insert_elements_into_bst() {
+        let mut expected_bst = RecursiveBST::new();
+        expected_bst.insert(0);
+        expected_bst.insert(1);
+        expected_bst.insert(2);
+        expected_bst.insert(-20);
+
+        let mut actual_bst = RecursiveBST::new();
+        actual_bst.insert(0);
+        actual_bst.insert(1);
+        actual_bst.insert(1);
+        actual_bst.insert(2);
+        actual_bst.insert(-20);
+
+        assert_eq!(actual_bst, expected_bst);
+        assert_eq!(actual_bst.size(), 4);
+    }
+
+    #[test]
+    fn check_if_bst_is_empty() {
+        let mut bst = RecursiveBST::new();
+        assert!(bst.is_empty());
+
+        bst.insert(1);
+        assert!(!bst.is_empty());
+    }
+
+    #[test]
+    fn check_if_bst_is_not_empty() {
+        let mut bst = RecursiveBST::new();
+        assert!(!bst.is_not_empty());
+
+        bst.insert(1);
+        assert!(bst.is_not_empty());
+    }
+
+    #[test]
+    fn check_if_bst_contains_elements() {
+        let mut bst = RecursiveBST::new();
+        assert!(!bst.contains(&10));
+
+        bst.insert(1);
+        bst.insert(5);
+
+        assert!(!bst.contains(&10));
+        assert!(bst.contains(&1));
+        assert!(bst.contains(&5));
+    }
+
+    #[test]
+    fn successfully_remove_root_node_from_bst() {
+        let mut bst = RecursiveBST::new();
+        bst.insert(0);
+
+        bst.remove(&0);
+
+        assert!(bst.is_empty());
+        assert_eq!(bst.size(), 0)
+    }
+
+    #[test]
+    fn successfully_remove_leaf_node() {
+        let mut expected_bst = RecursiveBST::new();
+        expected_bst.insert(5);
+        expected_bst.insert(4);
+        expected_bst.insert(6);
+        let mut actual_bst = RecursiveBST::new();
+        actual_bst.insert(5);
+        actual_bst.insert(4);
+        actual_bst.insert(6);
+        actual_bst.insert(7);
+
+        actual_bst.remove(&7);
+
+        assert_eq!(actual_bst.size(), 3);
+        assert_eq!(actual_bst, expected_bst);
+    }
+
+    #[test]
+    fn successfully_remove_single_right_node_with_children() {
+        let mut expected_bst = RecursiveBST::new();
+        expected_bst.insert(5);
+        expected_bst.insert(4);
+        expected_bst.insert(7);
+        expected_bst.insert(8);
+        let mut actual_bst = RecursiveBST::new();
+        actual_bst.insert(5);
+        actual_bst.insert(4);
+        actual_bst.insert(6);
+        actual_bst.insert(7);
+        actual_bst.insert(8);
+
+        actual_bst.remove(&6);
+
+        println!("{}", actual_bst);
+        assert_eq!(actual_bst.size(), 4);
+        assert_eq!(actual_bst, expected_bst);
+    }
+
+    #[test]
+    fn successfully_remove_single_left_node_with_children() {
+        let mut expected_bst = RecursiveBST::new();
+        expected_bst.insert(5);
+        expected_bst.insert(3);
+        expected_bst.insert(2);
+        expected_bst.insert(6);
+        let mut actual_bst = RecursiveBST::new();
+        actual_bst.insert(5);
+        actual_bst.insert(4);
+        actual_bst.insert(6);
+        actual_bst.insert(3);
+        actual_bst.insert(2);
+
+        actual_bst.remove(&4);
+
+        assert_eq!(actual_bst.size(), 4);
+        assert_eq!(actual_bst, expected_bst);
+    }
+
+    #[test]
+    fn successfully_remove_node_with_two_children() {
+        let mut expected_bst = RecursiveBST::new();
+        expected_bst.insert(10);
+        expected_bst.insert(3);
+        expected_bst.insert(8);
+        expected_bst.insert(15);
+        let mut actual_bst = RecursiveBST::new();
+        actual_bst.insert(10);
+        actual_bst.insert(5);
+        actual_bst.insert(8);
+        actual_bst.insert(3);
+        actual_bst.insert(15);
+
+        actual_bst.remove(&5);
+
+        assert_eq!(actual_bst, expected_bst);
+    }
+
+    #[test]
+    fn successfully_does_not_fail_when_removing_non_existing_element() {
+        let mut expected_bst = RecursiveBST::new();
+        expected_bst.insert(10);
+        expected_bst.insert(5);
+        expected_bst.insert(8);
+        expected_bst.insert(3);
+        expected_bst.insert(15);
+
+        let mut actual_bst = RecursiveBST::new();
+        actual_bst.insert(10);
+        actual_bst.insert(5);
+        actual_bst.insert(8);
+        actual_bst.insert(3);
+        actual_bst.insert(15);
+
+        actual_bst.remove(&20);
+
+        assert_eq!(actual_bst.size(), 5);
+        assert_eq!(actual_bst, expected_bst);
+    }
+
+    #[test]
+    fn successfully_retrieve_element() {
+        let mut bst = RecursiveBST::new();
+        bst.insert(5);
+        bst.insert(10);
+
+        let retrieved_value = bst.retrieve(&5);
+        let invalid_value = bst.retrieve(&15);
+
+        assert_eq!(retrieved_value, Some(&5));
+        assert_eq!(invalid_value, None);
+    }
+
+    #[test]
+    fn successfully_retrieve_element_as_mut_and_modify_bst() {
+        let mut expected_bst = RecursiveBST::new();
+        expected_bst.insert(10);
+        expected_bst.insert(2);
+
+        let mut actual_bst = RecursiveBST::new();
+        actual_bst.insert(10);
+        actual_bst.insert(5);
+
+        let _retrieved_value_as_mut: &mut i32 = actual_bst.retrieve_as_mut(&5).unwrap();
+        *_retrieved_value_as_mut = 2;
+
+        assert_eq!(actual_bst, expected_bst);
+    }
+
+    #[test]
+    fn successfully_get_height_of_bst() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.height(), None);
+
+        bst.insert(4);
+        assert_eq!(bst.height(), Some(0));
+
+        bst.insert(2);
+        bst.insert(6);
+        bst.insert(1);
+        bst.insert(3);
+        bst.insert(4);
+        bst.insert(7);
+        assert_eq!(bst.height(), Some(2));
+
+        bst.insert(8);
+        assert_eq!(bst.height(), Some(3));
+    }
+
+    #[test]
+    fn successfully_get_min_from_bst() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.min(), None);
+
+        bst.insert(5);
+        bst.insert(3);
+        bst.insert(1);
+        bst.insert(15);
+
+        assert_eq!(bst.min(), Some(&1));
+    }
+
+    #[test]
+    fn successfully_get_max_from_bst() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.max(), None);
+
+        bst.insert(5);
+        bst.insert(12);
+        bst.insert(1);
+        bst.insert(15);
+
+        assert_eq!(bst.max(), Some(&15));
+    }
+
+    #[test]
+    fn successfully_remove_min_from_bst() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.remove_min(), None);
+
+        bst.insert(5);
+        assert_eq!(bst.remove_min(), Some(5));
+        assert_eq!(bst.size(), 0);
+
+        bst.insert(3);
+        bst.insert(1);
+        bst.insert(2);
+        bst.insert(15);
+
+        assert_eq!(bst.remove_min(), Some(1));
+        assert!(bst.contains(&2));
+        assert_eq!(bst.size(), 3);
+    }
+
+    #[test]
+    fn successfully_remove_max_from_bst() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.remove_max(), None);
+
+        bst.insert(5);
+        assert_eq!(bst.remove_max(), Some(5));
+        assert_eq!(bst.size(), 0);
+
+        bst.insert(3);
+        bst.insert(1);
+        bst.insert(15);
+        bst.insert(10);
+
+        assert_eq!(bst.remove_max(), Some(15));
+        assert!(bst.contains(&10));
+        assert_eq!(bst.size(), 3);
+    }
+
+    #[test]
+    fn pre_order_iter() {
+        let mut bst = RecursiveBST::new();
+        bst.insert(3);
+        bst.insert(4);
+        bst.insert(5);
+        bst.insert(1);
+        bst.insert(2);
+
+        let mut pre_order_iter = bst.pre_order_iter();
+
+        assert_eq!(pre_order_iter.next(), Some(&3));
+        assert_eq!(pre_order_iter.next(), Some(&1));
+        assert_eq!(pre_order_iter.next(), Some(&2));
+        assert_eq!(pre_order_iter.next(), Some(&4));
+        assert_eq!(pre_order_iter.next(), Some(&5));
+        assert_eq!(pre_order_iter.next(), None);
+
+        bst.insert(10);
+
+        let mut another_pre_order_iter = bst.pre_order_iter();
+
+        assert_eq!(another_pre_order_iter.next(), Some(&3));
+        assert_eq!(another_pre_order_iter.next(), Some(&1));
+        assert_eq!(another_pre_order_iter.next(), Some(&2));
+        assert_eq!(another_pre_order_iter.next(), Some(&4));
+        assert_eq!(another_pre_order_iter.next(), Some(&5));
+        assert_eq!(another_pre_order_iter.next(), Some(&10));
+        assert_eq!(another_pre_order_iter.next(), None);
+    }
+
+    #[test]
+    fn in_order_iter() {
+        let mut bst = RecursiveBST::new();
+        bst.insert(3);
+        bst.insert(4);
+        bst.insert(5);
+        bst.insert(1);
+        bst.insert(2);
+
+        let mut in_order_iter = bst.in_order_iter();
+
+        assert_eq!(in_order_iter.next(), Some(&1));
+        assert_eq!(in_order_iter.next(), Some(&2));
+        assert_eq!(in_order_iter.next(), Some(&3));
+        assert_eq!(in_order_iter.next(), Some(&4));
+        assert_eq!(in_order_iter.next(), Some(&5));
+        assert_eq!(in_order_iter.next(), None);
+
+        bst.insert(6);
+
+        let mut another_in_order_iter = bst.in_order_iter();
+
+        assert_eq!(another_in_order_iter.next(), Some(&1));
+        assert_eq!(another_in_order_iter.next(), Some(&2));
+        assert_eq!(another_in_order_iter.next(), Some(&3));
+        assert_eq!(another_in_order_iter.next(), Some(&4));
+        assert_eq!(another_in_order_iter.next(), Some(&5));
+        assert_eq!(another_in_order_iter.next(), Some(&6));
+        assert_eq!(another_in_order_iter.next(), None);
+    }
+
+    #[test]
+    fn post_order_iter() {
+        let mut bst = RecursiveBST::new();
+        bst.insert(3);
+        bst.insert(4);
+        bst.insert(5);
+        bst.insert(1);
+        bst.insert(2);
+
+        let mut post_order_iter = bst.post_order_iter();
+
+        assert_eq!(post_order_iter.next(), Some(&2));
+        assert_eq!(post_order_iter.next(), Some(&1));
+        assert_eq!(post_order_iter.next(), Some(&5));
+        assert_eq!(post_order_iter.next(), Some(&4));
+        assert_eq!(post_order_iter.next(), Some(&3));
+        assert_eq!(post_order_iter.next(), None);
+
+        bst.insert(10);
+
+        let mut another_post_order_iter = bst.post_order_iter();
+
+        assert_eq!(another_post_order_iter.next(), Some(&2));
+        assert_eq!(another_post_order_iter.next(), Some(&1));
+        assert_eq!(another_post_order_iter.next(), Some(&10));
+        assert_eq!(another_post_order_iter.next(), Some(&5));
+        assert_eq!(another_post_order_iter.next(), Some(&4));
+        assert_eq!(another_post_order_iter.next(), Some(&3));
+        assert_eq!(another_post_order_iter.next(), None);
+    }
+
+    #[test]
+    fn level_order_iter() {
+        let mut bst = RecursiveBST::new();
+        bst.insert(15);
+        bst.insert(20);
+        bst.insert(10);
+        bst.insert(8);
+        bst.insert(12);
+        bst.insert(16);
+        bst.insert(25);
+
+        let mut level_order_iter = bst.level_order_iter();
+
+        assert_eq!(level_order_iter.next(), Some(&15));
+        assert_eq!(level_order_iter.next(), Some(&10));
+        assert_eq!(level_order_iter.next(), Some(&20));
+        assert_eq!(level_order_iter.next(), Some(&8));
+        assert_eq!(level_order_iter.next(), Some(&12));
+        assert_eq!(level_order_iter.next(), Some(&16));
+        assert_eq!(level_order_iter.next(), Some(&25));
+        assert_eq!(level_order_iter.next(), None);
+
+        bst.insert(4);
+
+        let mut another_level_order_iter = bst.level_order_iter();
+
+        assert_eq!(another_level_order_iter.next(), Some(&15));
+        assert_eq!(another_level_order_iter.next(), Some(&10));
+        assert_eq!(another_level_order_iter.next(), Some(&20));
+        assert_eq!(another_level_order_iter.next(), Some(&8));
+        assert_eq!(another_level_order_iter.next(), Some(&12));
+        assert_eq!(another_level_order_iter.next(), Some(&16));
+        assert_eq!(another_level_order_iter.next(), Some(&25));
+        assert_eq!(another_level_order_iter.next(), Some(&4));
+        assert_eq!(another_level_order_iter.next(), None);
+    }
+
+    #[test]
+    fn into_pre_order_iter_with_no_elements() {
+        let bst: RecursiveBST<i32> = RecursiveBST::new();
+
+        let mut pre_order_traversal = bst.into_pre_order_iter();
+
+        assert_eq!(pre_order_traversal.next(), None);
+    }
+
+    #[test]
+    fn into_pre_order_iter_with_one_element() {
+        let mut bst = RecursiveBST::new();
+        bst.insert(3);
+
+        let mut pre_order_traversal = bst.into_pre_order_iter();
+
+        assert_eq!(pre_order_traversal.next(), Some(3));
+        assert_eq!(pre_order_traversal.next(), None);
+    }
+
+    #[test]
+    fn into_pre_order_iter() {
+        let mut iter: IntoIter<i32> = RecursiveBST::new().into_pre_order_iter();
+        assert_eq!(iter.next(), None);
+
+        let mut bst = RecursiveBST::new();
+        bst.insert(3);
+        bst.insert(4);
+        bst.insert(5);
+        bst.insert(1);
+        bst.insert(2);
+
+        let mut pre_order_iter = bst.into_pre_order_iter();
+
+        assert_eq!(pre_order_iter.next(), Some(3));
+        assert_eq!(pre_order_iter.next(), Some(1));
+        assert_eq!(pre_order_iter.next(), Some(2));
+        assert_eq!(pre_order_iter.next(), Some(4));
+        assert_eq!(pre_order_iter.next(), Some(5));
+        assert_eq!(pre_order_iter.next(), None);
+    }
+
+    #[test]
+    fn into_in_order_iter_with_no_elements() {
+        let bst: RecursiveBST<i32> = RecursiveBST::new();
+
+        let mut in_order_traversal = bst.into_in_order_iter();
+
+        assert_eq!(in_order_traversal.next(), None);
+    }
+
+    #[test]
+    fn into_in_order_iter_with_one_element() {
+        let mut bst = RecursiveBST::new();
+        bst.insert(3);
+
+        let mut in_order_traversal = bst.into_in_order_iter();
+
+        assert_eq!(in_order_traversal.next(), Some(3));
+        assert_eq!(in_order_traversal.next(), None);
+    }
+
+    #[test]
+    fn into_in_order_iter() {
+        let another_bst: RecursiveBST<i32> = RecursiveBST::new();
+        let mut iter = another_bst.into_in_order_iter();
+        assert_eq!(iter.next(), None);
+
+        let mut bst = RecursiveBST::new();
+        bst.insert(3);
+        bst.insert(4);
+        bst.insert(5);
+        bst.insert(1);
+        bst.insert(2);
+
+        let mut in_order_iter = bst.into_in_order_iter();
+
+        assert_eq!(in_order_iter.next(), Some(1));
+        assert_eq!(in_order_iter.next(), Some(2));
+        assert_eq!(in_order_iter.next(), Some(3));
+        assert_eq!(in_order_iter.next(), Some(4));
+        assert_eq!(in_order_iter.next(), Some(5));
+        assert_eq!(in_order_iter.next(), None);
+    }
+
+    #[test]
+    fn into_post_order_iter_with_no_elements() {
+        let bst: RecursiveBST<i32> = RecursiveBST::new();
+
+        let mut post_order_traversal = bst.into_post_order_iter();
+
+        assert_eq!(post_order_traversal.next(), None);
+    }
+
+    #[test]
+    fn into_post_order_iter_with_one_element() {
+        let mut bst = RecursiveBST::new();
+        bst.insert(3);
+
+        let mut post_order_traversal = bst.into_post_order_iter();
+
+        assert_eq!(post_order_traversal.next(), Some(3));
+        assert_eq!(post_order_traversal.next(), None);
+    }
+
+    #[test]
+    fn into_post_order_iter_with_many_elements() {
+        let mut bst = RecursiveBST::new();
+        bst.insert(3);
+        bst.insert(4);
+        bst.insert(5);
+        bst.insert(1);
+        bst.insert(2);
+
+        let mut post_order_traversal = bst.into_post_order_iter();
+
+        assert_eq!(post_order_traversal.next(), Some(2));
+        assert_eq!(post_order_traversal.next(), Some(1));
+        assert_eq!(post_order_traversal.next(), Some(5));
+        assert_eq!(post_order_traversal.next(), Some(4));
+        assert_eq!(post_order_traversal.next(), Some(3));
+        assert_eq!(post_order_traversal.next(), None);
+    }
+
+    #[test]
+    fn into_level_order_iter_with_no_elements() {
+        let bst: RecursiveBST<i32> = RecursiveBST::new();
+
+        let mut level_order_traversal = bst.into_level_order_iter();
+
+        assert_eq!(level_order_traversal.next(), None);
+    }
+
+    #[test]
+    fn into_level_order_iter_with_one_element() {
+        let mut bst = RecursiveBST::new();
+        bst.insert(3);
+
+        let mut level_order_traversal = bst.into_level_order_iter();
+
+        assert_eq!(level_order_traversal.next(), Some(3));
+        assert_eq!(level_order_traversal.next(), None);
+    }
+
+    #[test]
+    fn into_level_order_iter_with_many_elements() {
+        let mut bst = RecursiveBST::new();
+        bst.insert(3);
+        bst.insert(5);
+        bst.insert(4);
+        bst.insert(1);
+        bst.insert(2);
+
+        let mut level_order_traversal = bst.into_level_order_iter();
+
+        assert_eq!(level_order_traversal.next(), Some(3));
+        assert_eq!(level_order_traversal.next(), Some(1));
+        assert_eq!(level_order_traversal.next(), Some(5));
+        assert_eq!(level_order_traversal.next(), Some(2));
+        assert_eq!(level_order_traversal.next(), Some(4));
+        assert_eq!(level_order_traversal.next(), None);
+    }
+
+    #[test]
+    fn successfully_get_pre_order_vec() {
+        let mut bst = RecursiveBST::new();
+        assert!(bst.pre_order_vec().is_empty());
+
+        bst.insert(3);
+        bst.insert(4);
+        bst.insert(5);
+        bst.insert(1);
+        bst.insert(2);
+
+        assert_eq!(bst.pre_order_vec(), vec![&3, &1, &2, &4, &5]);
+    }
+
+    #[test]
+    fn successfully_get_in_order_vec() {
+        let mut bst = RecursiveBST::new();
+        assert!(bst.in_order_vec().is_empty());
+
+        bst.insert(3);
+        bst.insert(4);
+        bst.insert(5);
+        bst.insert(1);
+        bst.insert(2);
+
+        assert_eq!(bst.in_order_vec(), vec![&1, &2, &3, &4, &5]);
+    }
+
+    #[test]
+    fn successfully_get_post_order_vec() {
+        let mut bst = RecursiveBST::new();
+        assert!(bst.post_order_vec().is_empty());
+
+        bst.insert(3);
+        bst.insert(4);
+        bst.insert(5);
+        bst.insert(1);
+        bst.insert(2);
+
+        assert_eq!(bst.post_order_vec(), vec![&2, &1, &5, &4, &3]);
+    }
+
+    #[test]
+    fn successfully_get_level_order_vec() {
+        let mut bst = RecursiveBST::new();
+        assert!(bst.level_order_vec().is_empty());
+
+        bst.insert(15);
+        bst.insert(20);
+        bst.insert(10);
+        bst.insert(8);
+        bst.insert(12);
+        bst.insert(16);
+        bst.insert(25);
+
+        assert_eq!(
+            bst.level_order_vec(),
+            vec![&15, &10, &20, &8, &12, &16, &25]
+        );
+    }
+
+    #[test]
+    fn successfully_create_bst_from_vec() {
+        let mut expected_bst = RecursiveBST::new();
+        expected_bst.insert(10);
+        expected_bst.insert(20);
+        expected_bst.insert(5);
+        expected_bst.insert(30);
+
+        let actual_bst = RecursiveBST::from(vec![10, 20, 5, 30]);
+
+        assert_eq!(actual_bst, expected_bst);
+    }
+
+    #[test]
+    fn successfully_create_bst_from_slice() {
+        let mut expected_bst = RecursiveBST::new();
+        expected_bst.insert(10);
+        expected_bst.insert(20);
+        expected_bst.insert(5);
+        expected_bst.insert(30);
+
+        let actual_bst = RecursiveBST::from(vec![10, 20, 5, 30].as_slice());
+
+        assert_eq!(actual_bst, expected_bst);
+    }
+
+    #[test]
+    fn successfully_create_bst_from_into_vec() {
+        let mut expected_bst = RecursiveBST::new();
+        expected_bst.insert(10);
+        expected_bst.insert(20);
+        expected_bst.insert(5);
+        expected_bst.insert(30);
+
+        let actual_bst: RecursiveBST<i32> = vec![10, 20, 5, 30].into();
+
+        assert_eq!(actual_bst, expected_bst);
+    }
+
+    #[test]
+    fn successfully_extend_bst_from_iter() {
+        let vec = vec![8, 1, 10];
+        let mut expected_bst = RecursiveBST::new();
+        expected_bst.insert(3);
+        expected_bst.insert(2);
+        expected_bst.insert(5);
+        expected_bst.insert(8);
+        expected_bst.insert(1);
+        expected_bst.insert(10);
+        let mut actual_bst = RecursiveBST::new();
+        actual_bst.insert(3);
+        actual_bst.insert(2);
+        actual_bst.insert(5);
+
+        actual_bst.extend(vec);
+
+        assert_eq!(actual_bst.size(), 6);
+        assert_eq!(actual_bst, expected_bst);
+    }
+
+    #[test]
+    fn successfully_create_bst_from_iter() {
+        let mut expected_bst = RecursiveBST::new();
+        expected_bst.insert(3);
+        expected_bst.insert(2);
+        expected_bst.insert(5);
+        expected_bst.insert(8);
+        expected_bst.insert(1);
+        expected_bst.insert(10);
+
+        let actual_bst = RecursiveBST::from_iter(vec![3, 2, 5, 8, 1, 10]);
+
+        assert_eq!(actual_bst, expected_bst);
+    }
+
+    #[test]
+    fn successfully_clone_bst() {
+        let mut expected_bst = RecursiveBST::new();
+        expected_bst.insert(3);
+        expected_bst.insert(2);
+        expected_bst.insert(5);
+        expected_bst.insert(8);
+        expected_bst.insert(1);
+        expected_bst.insert(10);
+
+        let cloned_bst = expected_bst.clone();
+
+        assert_eq!(cloned_bst, expected_bst);
+    }
+
+    #[test]
+    fn successfully_clone_into_another_bst() {
+        let mut actual_bst = RecursiveBST::new();
+        actual_bst.insert(3);
+        actual_bst.insert(2);
+        let mut expected_bst = RecursiveBST::new();
+        expected_bst.insert(3);
+        expected_bst.insert(2);
+        expected_bst.insert(5);
+        expected_bst.insert(8);
+        expected_bst.insert(1);
+        expected_bst.insert(10);
+        assert_ne!(actual_bst, expected_bst);
+
+        actual_bst.clone_from(&expected_bst);
+
+        assert_eq!(actual_bst, expected_bst);
+    }
+
+    #[test]
+    fn successfully_get_same_canonical_key_for_differently_built_trees() {
+        let balanced_bst = RecursiveBST::from(vec![4, 2, 6, 1, 3, 5, 7]);
+        let skewed_bst = RecursiveBST::from(vec![1, 2, 3, 4, 5, 6, 7]);
+        assert_ne!(balanced_bst.pre_order_vec(), skewed_bst.pre_order_vec());
+
+        assert_eq!(balanced_bst.canonical_key(), skewed_bst.canonical_key());
+        assert_eq!(
+            balanced_bst.canonical_key(),
+            vec![&1, &2, &3, &4, &5, &6, &7]
+        );
+    }
+
+    #[test]
+    fn successfully_insert_elements_with_depth() {
+        let mut bst = RecursiveBST::new();
+
+        assert_eq!(bst.insert_with_depth(4), Some(0));
+        assert_eq!(bst.insert_with_depth(2), Some(1));
+        assert_eq!(bst.insert_with_depth(6), Some(1));
+        assert_eq!(bst.insert_with_depth(3), Some(2));
+        assert_eq!(bst.insert_with_depth(5), Some(2));
+        assert_eq!(bst.insert_with_depth(7), Some(2));
+        assert_eq!(bst.insert_with_depth(8), Some(3));
+        assert_eq!(bst.insert_with_depth(9), Some(4));
+        assert_eq!(bst.size(), 8);
+
+        assert_eq!(bst.insert_with_depth(4), None);
+        assert_eq!(bst.insert_with_depth(9), None);
+        assert_eq!(bst.size(), 8);
+    }
+
+    #[test]
+    fn successfully_get_average_search_depth() {
+        let empty_bst: RecursiveBST<i32> = RecursiveBST::new();
+        assert_eq!(empty_bst.average_search_depth(), None);
+
+        let perfect_bst = RecursiveBST::from(vec![4, 2, 6, 1, 3, 5, 7]);
+        assert_eq!(perfect_bst.average_search_depth(), Some(10.0 / 7.0));
+
+        let chained_bst = RecursiveBST::from(vec![1, 2, 3, 4]);
+        assert_eq!(chained_bst.average_search_depth(), Some(6.0 / 4.0));
+    }
+
+    #[test]
+    fn check_if_all_elements_satisfy_predicate() {
+        let mut bst = RecursiveBST::new();
+        assert!(bst.all(|value| *value > 0));
+
+        bst.insert(4);
+        bst.insert(2);
+        bst.insert(6);
+        bst.insert(1);
+
+        assert!(bst.all(|value| *value > 0));
+        assert!(!bst.all(|value| *value % 2 == 0));
+    }
+
+    #[test]
+    fn check_if_any_element_satisfies_predicate() {
+        let mut bst = RecursiveBST::new();
+        assert!(!bst.any(|value| *value > 0));
+
+        bst.insert(4);
+        bst.insert(2);
+        bst.insert(6);
+        bst.insert(1);
+
+        assert!(!bst.any(|value| *value > 100));
+        assert!(bst.any(|value| *value == 1));
+    }
+
+    #[test]
+    fn successfully_get_complement_in_universe() {
+        let bst = RecursiveBST::from(vec![2, 4]);
+
+        let complement = bst.complement_in(1, 5);
+
+        assert_eq!(complement.size(), 3);
+        assert_eq!(complement.height(), Some(1));
+        assert_eq!(complement, RecursiveBST::from(vec![1, 3, 5]));
+    }
+
+    #[test]
+    fn successfully_get_empty_complement_in_universe() {
+        let bst = RecursiveBST::from(vec![1, 2, 3]);
+
+        assert!(bst.complement_in(1, 3).is_empty());
+        assert!(bst.complement_in(3, 1).is_empty());
+        assert_eq!(
+            RecursiveBST::new().complement_in(u8::MAX - 1, u8::MAX),
+            RecursiveBST::from(vec![u8::MAX - 1, u8::MAX])
+        );
+    }
+
+    #[test]
+    fn successfully_get_prefix_extremes() {
+        let bst = RecursiveBST::from(vec![3, 1, 4, 2]);
+
+        let prefix_extremes = bst.prefix_extremes();
+
+        assert_eq!(
+            prefix_extremes,
+            vec![(&1, &1), (&1, &2), (&1, &3), (&1, &4)]
+        );
+        assert!(prefix_extremes
+            .iter()
+            .all(|(min, _)| *min == bst.min().unwrap()));
+        assert!(prefix_extremes.windows(2).all(|pair| pair[0].1 < pair[1].1));
+        assert!(RecursiveBST::<i32>::new().prefix_extremes().is_empty());
+    }
+
+    #[test]
+    fn successfully_get_ranges() {
+        let bst = RecursiveBST::from(vec![5, 1, 9, 3, 2, 6]);
+        assert_eq!(bst.to_ranges(), vec![(1, 3), (5, 6), (9, 9)]);
+
+        let single_bst = RecursiveBST::from(vec![4]);
+        assert_eq!(single_bst.to_ranges(), vec![(4, 4)]);
+
+        let empty_bst: RecursiveBST<i32> = RecursiveBST::new();
+        assert_eq!(empty_bst.to_ranges(), vec![]);
+    }
+
+    #[test]
+    fn successfully_create_bst_from_ranges() {
+        let bst = RecursiveBST::from(vec![9, 5, 1, 6, 2, 3, 12]);
+
+        let actual_bst = RecursiveBST::from_ranges(&bst.to_ranges());
+
+        assert_eq!(actual_bst, bst);
+        assert_eq!(actual_bst.size(), 7);
+        assert_eq!(actual_bst.height(), Some(2));
+    }
+
+    #[test]
+    fn successfully_create_bst_from_overlapping_and_inverted_ranges() {
+        let bst = RecursiveBST::from_ranges(&[(3, 5), (1, 4), (10, 8)]);
+
+        assert_eq!(bst.size(), 5);
+        assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3, &4, &5]);
+    }
+
+    #[test]
+    fn successfully_rebalance_bst() {
+        let mut bst = RecursiveBST::new();
+        bst.rebalance();
+        assert!(bst.is_empty());
+
+        bst.extend(1..=15);
+        assert_eq!(bst.height(), Some(14));
+
+        bst.rebalance();
+
+        assert_eq!(bst.size(), 15);
+        assert_eq!(bst.height(), Some(3));
+        assert_eq!(
+            bst.asc_order_vec(),
+            (1..=15).collect::<Vec<_>>().iter().collect::<Vec<_>>()
+        );
+
+        bst.extend((16..=20).rev());
+        bst.rebalance();
+
+        assert_eq!(bst.height(), Some(4));
+        assert_eq!(
+            bst.in_order_vec(),
+            (1..=20).collect::<Vec<_>>().iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn successfully_predict_rebalance_cost() {
+        let shapes = vec![
+            vec![],
+            vec![1],
+            vec![1, 2, 3, 4, 5, 6, 7],
+            vec![7, 6, 5, 4, 3, 2, 1],
+            vec![4, 2, 6, 1, 3, 5, 7],
+            vec![10, 5, 15, 3, 8, 20, 1, 4, 9, 12, 30, 25],
+        ];
+
+        for shape in shapes {
+            let mut bst = RecursiveBST::from(shape);
+            let expected_rotations = bst.rebalance_cost();
+
+            let actual_rotations = Node::dsw_rebalance(&mut bst.root, bst.size);
+
+            assert_eq!(actual_rotations, expected_rotations);
+        }
+    }
+
+    #[test]
+    fn successfully_get_outline() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.to_outline(), "");
+
+        bst.insert(4);
+        assert_eq!(bst.to_outline(), "4");
+
+        bst.insert(2);
+        bst.insert(6);
+        bst.insert(1);
+        bst.insert(3);
+        bst.insert(5);
+        bst.insert(7);
+
+        assert_eq!(bst.to_outline(), "4\n  2\n    1\n    3\n  6\n    5\n    7");
+    }
+
+    #[test]
+    fn successfully_get_side_counts() {
+        let bst = RecursiveBST::from(vec![1, 2, 3, 4, 5]);
+
+        assert_eq!(bst.side_counts(&3), Some((2, 2)));
+        assert_eq!(bst.side_counts(&1), Some((0, 4)));
+        assert_eq!(bst.side_counts(&5), Some((4, 0)));
+        assert_eq!(bst.side_counts(&6), None);
+        assert_eq!(RecursiveBST::new().side_counts(&1), None);
+    }
+
+    #[test]
+    fn successfully_get_longest_consecutive_path() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.longest_consecutive_path(), 0);
+
+        bst.insert(10);
+        assert_eq!(bst.longest_consecutive_path(), 1);
+
+        bst.extend(vec![5, 1, 2, 3, 12, 13]);
+        assert_eq!(bst.longest_consecutive_path(), 3);
+
+        bst.insert(4);
+        assert_eq!(bst.longest_consecutive_path(), 4);
+
+        let boundary_bst = RecursiveBST::from(vec![u8::MAX, 0]);
+        assert_eq!(boundary_bst.longest_consecutive_path(), 1);
+    }
+
+    #[test]
+    fn successfully_create_bst_from_weighted_keys() {
+        let weighted_keys = vec![(1, 0.1), (2, 0.2), (3, 0.05), (4, 10.0), (5, 0.3), (6, 2.5)];
+
+        let bst = RecursiveBST::from_weighted(&weighted_keys);
+        let level_order = bst.level_order_vec();
+        let position = |key: &i32| level_order.iter().position(|value| *value == key).unwrap();
+
+        assert_eq!(bst.size(), 6);
+        assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3, &4, &5, &6]);
+        assert_eq!(position(&4), 0);
+        assert!(position(&6) < position(&5));
+        assert!(position(&4) < position(&3));
+    }
+
+    #[test]
+    fn successfully_create_bst_from_weighted_keys_with_duplicates() {
+        let bst = RecursiveBST::from_weighted(&[(1, 0.1), (2, 0.2), (1, 0.9)]);
+
+        assert_eq!(bst.size(), 2);
+        assert_eq!(bst.level_order_vec(), vec![&1, &2]);
+    }
+
+    #[test]
+    fn merge_iter() {
+        let bst = RecursiveBST::from(vec![1, 3, 5]);
+        let another_bst = RecursiveBST::from(vec![2, 3, 4]);
+
+        let mut merge_iter = bst.merge_iter(&another_bst);
+
+        assert_eq!(merge_iter.next(), Some(&1));
+        assert_eq!(merge_iter.next(), Some(&2));
+        assert_eq!(merge_iter.next(), Some(&3));
+        assert_eq!(merge_iter.next(), Some(&4));
+        assert_eq!(merge_iter.next(), Some(&5));
+        assert_eq!(merge_iter.next(), None);
+    }
+
+    #[test]
+    fn merge_iter_with_empty_bst() {
+        let bst = RecursiveBST::from(vec![2, 1]);
+        let empty_bst = RecursiveBST::new();
+
+        assert_eq!(bst.merge_iter(&empty_bst).collect::<Vec<_>>(), vec![&1, &2]);
+        assert_eq!(empty_bst.merge_iter(&bst).collect::<Vec<_>>(), vec![&1, &2]);
+        assert_eq!(empty_bst.merge_iter(&empty_bst).next(), None);
+    }
+
+    #[test]
+    fn successfully_get_balance_factor_histogram_of_balanced_bst() {
+        let bst = RecursiveBST::from(vec![8, 4, 12, 2, 6, 10, 14, 1, 3, 5]);
+
+        let histogram = bst.balance_factor_histogram();
+
+        assert_eq!(histogram.values().sum::<usize>(), bst.size());
+        assert!(histogram.keys().all(|factor| (-1..=1).contains(factor)));
+        assert!(RecursiveBST::<i32>::new()
+            .balance_factor_histogram()
+            .is_empty());
+    }
+
+    #[test]
+    fn successfully_get_balance_factor_histogram_of_chained_bst() {
+        let bst = RecursiveBST::from(vec![1, 2, 3, 4, 5]);
+
+        let histogram = bst.balance_factor_histogram();
+
+        assert_eq!(
+            histogram,
+            HashMap::from([(0, 1), (-1, 1), (-2, 1), (-3, 1), (-4, 1)])
+        );
+    }
+
+    #[test]
+    fn successfully_get_skew_score() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.skew_score(), None);
+
+        bst.insert(1);
+        assert_eq!(bst.skew_score(), Some(1.0));
+
+        let mut chained_bst = RecursiveBST::from_iter(1..=15);
+        assert_eq!(chained_bst.skew_score(), Some(14.0 / 3.0));
+
+        chained_bst.rebalance();
+        assert_eq!(chained_bst.skew_score(), Some(1.0));
+    }
+
+    #[test]
+    fn successfully_reconstruct_bst_from_traversals() {
+        let bst = RecursiveBST::from(vec![8, 3, 10, 1, 6, 14, 4, 7, 13]);
+        let in_order: Vec<i32> = bst.in_order_iter().cloned().collect();
+        let pre_order: Vec<i32> = bst.pre_order_iter().cloned().collect();
+
+        let reconstructed_bst = RecursiveBST::from_inorder_preorder(&in_order, &pre_order).unwrap();
+
+        assert_eq!(reconstructed_bst, bst);
+        assert_eq!(reconstructed_bst.size(), bst.size());
+        assert_eq!(reconstructed_bst.pre_order_vec(), bst.pre_order_vec());
+        assert_eq!(reconstructed_bst.level_order_vec(), bst.level_order_vec());
+    }
+
+    #[test]
+    fn fail_to_reconstruct_bst_from_mismatched_traversals() {
+        assert_eq!(
+            RecursiveBST::from_inorder_preorder(&[1, 2, 3], &[2, 1]),
+            Err(ReconstructError::LengthMismatch)
+        );
+        assert_eq!(
+            RecursiveBST::from_inorder_preorder(&[2, 1, 3], &[2, 1, 3]),
+            Err(ReconstructError::UnsortedInOrder)
+        );
+        assert_eq!(
+            RecursiveBST::from_inorder_preorder(&[1, 2, 3], &[2, 1, 5]),
+            Err(ReconstructError::ElementMismatch)
+        );
+        assert_eq!(
+            RecursiveBST::from_inorder_preorder(&[1, 2, 3], &[2, 3, 1]),
+            Err(ReconstructError::InvalidPreOrder)
+        );
+    }
+
+    #[test]
+    fn successfully_get_half_nodes() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.half_nodes(), 0);
+
+        bst.insert(1);
+        assert_eq!(bst.half_nodes(), 0);
+
+        let mut chained_bst = RecursiveBST::from_iter(1..=7);
+        assert_eq!(chained_bst.half_nodes(), 6);
+
+        chained_bst.rebalance();
+        assert_eq!(chained_bst.half_nodes(), 0);
+    }
+
+    #[test]
+    fn successfully_get_boundary_vec() {
+        let mut bst = RecursiveBST::new();
+        assert!(bst.boundary_vec().is_empty());
+
+        bst.insert(4);
+        assert_eq!(bst.boundary_vec(), vec![&4]);
+
+        bst.insert(2);
+        bst.insert(6);
+        bst.insert(1);
+        bst.insert(3);
+        bst.insert(5);
+        bst.insert(7);
+        assert_eq!(bst.boundary_vec(), vec![&4, &2, &1, &3, &5, &7, &6]);
+
+        let chained_bst = RecursiveBST::from_iter(1..=5);
+        assert_eq!(chained_bst.boundary_vec(), vec![&1, &5, &4, &3, &2]);
+
+        let left_only_bst = RecursiveBST::from_iter([8, 4, 6, 5]);
+        assert_eq!(left_only_bst.boundary_vec(), vec![&8, &4, &6, &5]);
+    }
+
+    #[test]
+    fn successfully_get_vertical_order() {
+        let mut bst = RecursiveBST::new();
+        assert!(bst.vertical_order().is_empty());
+
+        bst.insert(8);
+        bst.insert(4);
+        bst.insert(12);
+        bst.insert(2);
+        bst.insert(6);
+        bst.insert(10);
+        bst.insert(14);
+        bst.insert(7);
+        bst.insert(9);
+        assert_eq!(
+            bst.vertical_order(),
+            vec![
+                vec![&2],
+                vec![&4, &9],
+                vec![&8, &6, &10],
+                vec![&12, &7],
+                vec![&14]
+            ]
+        );
+    }
+
+    #[test]
+    fn successfully_get_diagonal_vec() {
+        let mut bst = RecursiveBST::new();
+        assert!(bst.diagonal_vec().is_empty());
+
+        bst.insert(8);
+        bst.insert(3);
+        bst.insert(10);
+        bst.insert(1);
+        bst.insert(6);
+        bst.insert(14);
+        bst.insert(4);
+        bst.insert(7);
+        bst.insert(13);
+        assert_eq!(
+            bst.diagonal_vec(),
+            vec![vec![&8, &10, &14], vec![&3, &6, &7, &13], vec![&1, &4]]
+        );
+    }
+
+    #[test]
+    fn successfully_get_max_path_sum_to_leaf() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.max_path_sum_to_leaf(), None);
+
+        bst.insert(10);
+        assert_eq!(bst.max_path_sum_to_leaf(), Some((10, vec![&10])));
+
+        bst.insert(-5);
+        bst.insert(20);
+        bst.insert(-8);
+        bst.insert(15);
+        bst.insert(30);
+        bst.insert(12);
+        assert_eq!(bst.max_path_sum_to_leaf(), Some((60, vec![&10, &20, &30])));
+
+        bst.insert(35);
+        assert_eq!(
+            bst.max_path_sum_to_leaf(),
+            Some((95, vec![&10, &20, &30, &35]))
+        );
+    }
+
+    #[test]
+    fn successfully_get_center() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.center(), None);
+
+        bst.insert(4);
+        assert_eq!(bst.center(), Some(&4));
+
+        bst.insert(2);
+        bst.insert(6);
+        bst.insert(1);
+        bst.insert(3);
+        bst.insert(5);
+        bst.insert(7);
+        assert_eq!(bst.center(), Some(&4));
+
+        let chained_bst = RecursiveBST::from_iter(1..=5);
+        assert_eq!(chained_bst.center(), Some(&3));
+
+        let even_chained_bst = RecursiveBST::from_iter((1..=4).rev());
+        assert_eq!(even_chained_bst.center(), Some(&2));
+
+        let lopsided_bst = RecursiveBST::from_iter([1, 5, 4, 3, 6, 7, 8]);
+        assert_eq!(lopsided_bst.center(), Some(&5));
+    }
+
+    #[test]
+    fn successfully_clone_range() {
+        let bst = RecursiveBST::from_iter(1..=5);
+
+        let window = bst.clone_range(&2, &4);
+        assert_eq!(window, RecursiveBST::from_iter([3, 2, 4]));
+        assert_eq!(window.size(), 3);
+
+        assert_eq!(bst.clone_range(&0, &10), bst.clone());
+        assert!(bst.clone_range(&6, &10).is_empty());
+        assert!(bst.clone_range(&4, &2).is_empty());
+        assert_eq!(bst.size(), 5);
+    }
+
+    #[test]
+    fn successfully_zip_compare_trees() {
+        let bst = RecursiveBST::from(vec![1, 2, 4]);
+        let another_bst = RecursiveBST::from(vec![2, 3, 4]);
+        let empty_bst = RecursiveBST::new();
+
+        assert_eq!(
+            bst.zip_compare(&another_bst),
+            vec![
+                (Some(&1), None),
+                (Some(&2), Some(&2)),
+                (None, Some(&3)),
+                (Some(&4), Some(&4))
+            ]
+        );
+        assert_eq!(
+            empty_bst.zip_compare(&bst),
+            vec![(None, Some(&1)), (None, Some(&2)), (None, Some(&4))]
+        );
+        assert!(empty_bst.zip_compare(&RecursiveBST::new()).is_empty());
+    }
+
+    #[test]
+    fn successfully_get_rank_shift_on_insert() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.rank_shift_on_insert(&1), 0);
+
+        bst.insert(3);
+        bst.insert(1);
+        bst.insert(5);
+        bst.insert(2);
+        bst.insert(4);
+        assert_eq!(bst.rank_shift_on_insert(&0), 5);
+        assert_eq!(bst.rank_shift_on_insert(&1), 4);
+        assert_eq!(bst.rank_shift_on_insert(&4), 1);
+        assert_eq!(bst.rank_shift_on_insert(&6), 0);
+        assert_eq!(bst.size(), 5);
+    }
+
+    #[test]
+    fn successfully_get_fingerprint() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.fingerprint(), 0xcbf29ce484222325);
+
+        bst.insert(2);
+        bst.insert(1);
+        bst.insert(3);
+        assert_eq!(bst.fingerprint(), 0xfd1f0f4381eb0395);
+        assert_eq!(
+            RecursiveBST::from_iter(1..=3).fingerprint(),
+            0xfd1f0f4381eb0395
+        );
+
+        bst.insert(4);
+        assert_ne!(bst.fingerprint(), 0xfd1f0f4381eb0395);
+
+        let another_bst = RecursiveBST::from(vec![10, 5, 8, 2]);
+        assert_eq!(another_bst.fingerprint(), 0x13d6391cba836cd0);
+    }
+
+    #[test]
+    fn successfully_splice_sorted() {
+        let mut bst = RecursiveBST::from_iter(1..=3);
+        bst.splice_sorted(&[6, 7, 8]);
+
+        let sequential_bst = RecursiveBST::from_iter([1, 2, 3, 6, 7, 8]);
+        assert_eq!(bst, sequential_bst);
+        assert_eq!(bst.size(), 6);
+        assert_eq!(bst.height(), Some(4));
+        assert_eq!(sequential_bst.height(), Some(5));
+
+        bst.splice_sorted(&[]);
+        assert_eq!(bst.size(), 6);
+
+        let mut empty_bst = RecursiveBST::new();
+        empty_bst.splice_sorted(&[1, 2, 3]);
+        assert_eq!(empty_bst.pre_order_vec(), vec![&2, &1, &3]);
+    }
+
+    #[test]
+    fn successfully_splice_overlapping_sorted() {
+        let mut bst = RecursiveBST::from_iter(1..=3);
+        bst.splice_sorted(&[2, 5]);
+
+        assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3, &5]);
+        assert_eq!(bst.size(), 4);
+    }
+
+    #[test]
+    fn successfully_splice_unsorted() {
+        let mut bst = RecursiveBST::from_iter(1..=3);
+        bst.splice_sorted(&[8, 6, 7, 6]);
+
+        assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3, &6, &7, &8]);
+        assert_eq!(bst.size(), 6);
+    }
+
+    #[test]
+    fn successfully_get_deepest_level_width() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.deepest_level_width(), 0);
+
+        bst.insert(4);
+        assert_eq!(bst.deepest_level_width(), 1);
+
+        bst.insert(2);
+        bst.insert(6);
+        bst.insert(1);
+        bst.insert(3);
+        bst.insert(5);
+        bst.insert(7);
+        assert_eq!(bst.deepest_level_width(), 4);
+
+        bst.insert(8);
+        assert_eq!(bst.deepest_level_width(), 1);
+    }
+
+    #[test]
+    fn successfully_verify_size() {
+        let mut bst = RecursiveBST::new();
+        assert!(bst.verify_size());
+
+        bst.insert(5);
+        bst.insert(3);
+        bst.insert(8);
+        bst.insert(3);
+        bst.remove(&5);
+        bst.remove(&10);
+        bst.insert(1);
+        bst.remove_min();
+        assert!(bst.verify_size());
+        assert_eq!(bst.size(), 2);
+    }
+
+    #[test]
+    fn successfully_recount_corrupted_size() {
+        let mut bst = RecursiveBST::from_iter(1..=5);
+        bst.size = 42;
+        assert!(!bst.verify_size());
+
+        bst.recount();
+        assert!(bst.verify_size());
+        assert_eq!(bst.size(), 5);
+    }
+
+    #[test]
+    fn successfully_group_by() {
+        let bst = RecursiveBST::from(vec![4, 1, 6, 3, 2, 5]);
+        let groups = bst.group_by(|value| value % 2);
+
+        assert_eq!(
+            groups,
+            BTreeMap::from([(0, vec![&2, &4, &6]), (1, vec![&1, &3, &5])])
+        );
+        assert!(RecursiveBST::<i32>::new()
+            .group_by(|value| value % 2)
+            .is_empty());
+    }
+
+    #[test]
+    fn successfully_get_removals_to_balance() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.removals_to_balance(), 0);
+
+        bst.insert(4);
+        bst.insert(2);
+        bst.insert(6);
+        bst.insert(1);
+        bst.insert(3);
+        bst.insert(5);
+        bst.insert(7);
+        assert_eq!(bst.removals_to_balance(), 0);
+
+        let mut chained_bst = RecursiveBST::from_iter(1..=7);
+        assert_eq!(chained_bst.removals_to_balance(), 4);
+
+        chained_bst.rebalance();
+        assert_eq!(chained_bst.removals_to_balance(), 0);
+    }
+
+    #[test]
+    fn successfully_create_bst_from_generator() {
+        let mut values = 1..=5;
+        let bst = RecursiveBST::from_generator(|| values.next());
+
+        assert_eq!(bst.size(), 5);
+        assert_eq!(bst, RecursiveBST::from_iter(1..=5));
+
+        let empty_bst: RecursiveBST<i32> = RecursiveBST::from_generator(|| None);
+        assert!(empty_bst.is_empty());
+    }
+
+    #[test]
+    fn successfully_get_longest_zigzag() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.longest_zigzag(), 0);
+
+        bst.insert(50);
+        assert_eq!(bst.longest_zigzag(), 0);
+
+        bst.insert(25);
+        bst.insert(75);
+        bst.insert(10);
+        bst.insert(5);
+        assert_eq!(bst.longest_zigzag(), 1);
+
+        bst.insert(60);
+        bst.insert(70);
+        bst.insert(65);
+        bst.insert(67);
+        bst.insert(66);
+        assert_eq!(bst.longest_zigzag(), 6);
+
+        let chained_bst = RecursiveBST::from_iter(1..=10);
+        assert_eq!(chained_bst.longest_zigzag(), 1);
+    }
+
+    #[test]
+    fn successfully_compare_same_shape() {
+        let bst = RecursiveBST::from(vec![2, 1, 3]);
+        let char_bst = RecursiveBST::from(vec!['b', 'a', 'c']);
+        let sorted_bst = RecursiveBST::from(vec![1, 2, 3]);
+        let empty_bst: RecursiveBST<String> = RecursiveBST::new();
+
+        assert!(bst.same_shape(&char_bst));
+        assert!(char_bst.same_shape(&bst));
+        assert!(!bst.same_shape(&sorted_bst));
+        assert!(!bst.same_shape(&empty_bst));
+        assert!(empty_bst.same_shape(&RecursiveBST::<u8>::new()));
+    }
+
+    #[test]
+    fn successfully_get_prefix_sums() {
+        let mut bst = RecursiveBST::new();
+        assert!(bst.prefix_sums().is_empty());
+
+        bst.insert(2);
+        bst.insert(4);
+        bst.insert(1);
+        bst.insert(3);
+        assert_eq!(bst.prefix_sums(), vec![1, 3, 6, 10]);
+    }
+
+    #[test]
+    fn successfully_get_range_sum() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.range_sum(&bst.prefix_sums(), &1, &10), None);
+
+        bst.insert(2);
+        bst.insert(4);
+        bst.insert(1);
+        bst.insert(3);
+        let prefix_sums = bst.prefix_sums();
+        assert_eq!(bst.range_sum(&prefix_sums, &2, &3), Some(5));
+        assert_eq!(bst.range_sum(&prefix_sums, &1, &1), Some(1));
+        assert_eq!(bst.range_sum(&prefix_sums, &-5, &2), Some(3));
+        assert_eq!(bst.range_sum(&prefix_sums, &3, &100), Some(7));
+        assert_eq!(bst.range_sum(&prefix_sums, &5, &100), None);
+        assert_eq!(bst.range_sum(&prefix_sums, &3, &2), None);
+        assert_eq!(bst.range_sum(&prefix_sums[..2], &1, &3), None);
+    }
+
+    #[test]
+    fn successfully_get_range_sum_of_elements_without_from_u8() {
+        let bst: RecursiveBST<i8> = RecursiveBST::from(vec![-3, 5, 2, -1]);
+        let prefix_sums = bst.prefix_sums();
+
+        assert_eq!(bst.range_sum(&prefix_sums, &-3, &2), Some(-2));
+    }
+
+    #[test]
+    fn successfully_check_would_grow_height() {
+        let mut bst = RecursiveBST::new();
+        assert!(bst.would_grow_height(&1));
+
+        bst.insert(10);
+        bst.insert(5);
+        bst.insert(15);
+        bst.insert(20);
+        bst.insert(25);
+        assert!(bst.would_grow_height(&30));
+        assert!(bst.would_grow_height(&22));
+        assert!(!bst.would_grow_height(&1));
+        assert!(!bst.would_grow_height(&12));
+        assert!(!bst.would_grow_height(&25));
+        assert_eq!(bst.size(), 5);
+    }
+
+    #[test]
+    fn successfully_get_snapshot() {
+        let mut bst = RecursiveBST::new();
+        assert!(bst.snapshot().is_empty());
+
+        bst.insert(3);
+        bst.insert(1);
+        bst.insert(2);
+        let snapshot = bst.snapshot();
+        let shared_snapshot = Arc::clone(&snapshot);
+        assert!(Arc::ptr_eq(&snapshot, &shared_snapshot));
+
+        bst.remove(&1);
+        let handle = std::thread::spawn(move || shared_snapshot.iter().sum::<i32>());
+        assert_eq!(handle.join().unwrap(), 6);
+        assert_eq!(*snapshot, [1, 2, 3]);
+    }
+
+    #[test]
+    fn successfully_get_distinct_count_in_range() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.distinct_count_in_range(&0, &10), 0);
+
+        bst.insert(5);
+        bst.insert(1);
+        bst.insert(3);
+        bst.insert(3);
+        bst.insert(9);
+        bst.insert(7);
+        assert_eq!(bst.distinct_count_in_range(&0, &10), 5);
+        assert_eq!(bst.distinct_count_in_range(&3, &7), 3);
+        assert_eq!(bst.distinct_count_in_range(&4, &4), 0);
+        assert_eq!(bst.distinct_count_in_range(&9, &1), 0);
+    }
+
+    #[test]
+    fn successfully_rebalance_to_height() {
+        let mut bst = RecursiveBST::from_iter(1..=10);
+        let expected_bst = RecursiveBST::from_iter(1..=10);
+
+        for target in 3..=9 {
+            assert_eq!(bst.rebalance_to_height(target), Ok(()));
+            assert_eq!(bst.height(), Some(target as isize));
+            assert_eq!(bst, expected_bst);
+            assert!(bst.verify_size());
+        }
+
+        assert_eq!(bst.rebalance_to_height(3), Ok(()));
+        assert_eq!(bst.skew_score(), Some(1.0));
+    }
+
+    #[test]
+    fn unsuccessfully_rebalance_to_height() {
+        let mut bst = RecursiveBST::from_iter(1..=10);
+        assert_eq!(bst.rebalance_to_height(2), Err(TooTall));
+        assert_eq!(bst.rebalance_to_height(10), Err(TooTall));
+        assert_eq!(bst.height(), Some(9));
+
+        let mut empty_bst: RecursiveBST<i32> = RecursiveBST::new();
+        assert_eq!(empty_bst.rebalance_to_height(0), Err(TooTall));
+    }
+
+    #[test]
+    fn successfully_iter_annotated() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.iter_annotated().next(), None);
 
+        bst.insert(4);
+        bst.insert(2);
+        bst.insert(6);
         bst.insert(1);
-        assert!(bst.is_not_empty());
+        bst.insert(3);
+        bst.insert(5);
+        bst.insert(7);
+        assert_eq!(
+            bst.iter_annotated().collect::<Vec<(&i32, bool)>>(),
+            vec![
+                (&1, true),
+                (&2, false),
+                (&3, true),
+                (&4, false),
+                (&5, true),
+                (&6, false),
+                (&7, true)
+            ]
+        );
     }
 
     #[test]
-    fn check_if_bst_contains_elements() {
+    fn successfully_rotate() {
         let mut bst = RecursiveBST::new();
-        assert!(!bst.contains(&10));
+        assert!(!bst.rotate(&1, Rotation::Left));
+
+        bst.insert(4);
+        bst.insert(2);
+        bst.insert(6);
+        bst.insert(1);
+        bst.insert(3);
+        assert!(bst.rotate(&4, Rotation::Right));
+        assert_eq!(bst.pre_order_vec(), vec![&2, &1, &4, &3, &6]);
+
+        assert!(bst.rotate(&4, Rotation::Left));
+        assert_eq!(bst.pre_order_vec(), vec![&2, &1, &6, &4, &3]);
+
+        assert!(!bst.rotate(&1, Rotation::Right));
+        assert!(!bst.rotate(&6, Rotation::Left));
+        assert!(!bst.rotate(&5, Rotation::Left));
+        assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3, &4, &6]);
+    }
+
+    #[test]
+    fn successfully_get_rotations_to_balance() {
+        let mut bst = RecursiveBST::from_iter((1..=7).rev());
+        let mut expected_bst = RecursiveBST::from_iter((1..=7).rev());
+        expected_bst.rebalance();
+
+        let rotations = bst.rotations_to_balance();
+        assert_eq!(rotations.len(), bst.rebalance_cost());
+        assert_eq!(bst.height(), Some(6));
 
+        for (pivot, rotation) in rotations {
+            assert!(bst.rotate(&pivot, rotation));
+        }
+        assert_eq!(bst.height(), Some(2));
+        assert_eq!(bst.pre_order_vec(), expected_bst.pre_order_vec());
+        assert!(RecursiveBST::<i32>::new().rotations_to_balance().is_empty());
+    }
+
+    #[test]
+    fn successfully_find_splay() {
+        let mut bst = RecursiveBST::from_iter(1..=16);
+        let expected_bst = RecursiveBST::from_iter(1..=16);
+        assert_eq!(bst.height(), Some(15));
+
+        // Splaying the deepest element also roughly halves the depth along its path
+        assert_eq!(bst.find_splay(&16), Some(&16));
+        assert_eq!(bst.pre_order_vec()[0], &16);
+        assert_eq!(bst.height(), Some(8));
+
+        assert_eq!(bst.find_splay(&1), Some(&1));
+        assert_eq!(bst.pre_order_vec()[0], &1);
+        assert_eq!(bst.height(), Some(9));
+
+        assert_eq!(bst.find_splay(&8), Some(&8));
+        assert_eq!(bst.find_splay(&8), Some(&8));
+        assert_eq!(bst.pre_order_vec()[0], &8);
+        assert_eq!(bst.height(), Some(5));
+
+        assert_eq!(bst, expected_bst);
+        assert!(bst.verify_size());
+    }
+
+    #[test]
+    fn unsuccessfully_find_splay() {
+        let mut bst = RecursiveBST::from_iter(1..=5);
+        assert_eq!(bst.find_splay(&10), None);
+        // The largest element is the last one reached while searching for the missing element
+        assert_eq!(bst.pre_order_vec(), vec![&5, &2, &1, &4, &3]);
+        assert!(bst.verify_size());
+
+        let mut empty_bst: RecursiveBST<i32> = RecursiveBST::new();
+        assert_eq!(empty_bst.find_splay(&1), None);
+    }
+
+    #[test]
+    fn successfully_get_jaccard_similarity() {
+        let bst = RecursiveBST::from_iter(1..=3);
+        let overlapping_bst = RecursiveBST::from_iter(2..=4);
+        let disjoint_bst = RecursiveBST::from_iter(10..=12);
+        let empty_bst = RecursiveBST::new();
+
+        assert_eq!(bst.jaccard(&bst.clone()), 1.0);
+        assert_eq!(bst.jaccard(&disjoint_bst), 0.0);
+        assert_eq!(bst.jaccard(&overlapping_bst), 0.5);
+        assert_eq!(overlapping_bst.jaccard(&bst), 0.5);
+        assert_eq!(bst.jaccard(&empty_bst), 0.0);
+        assert_eq!(empty_bst.jaccard(&RecursiveBST::new()), 1.0);
+    }
+
+    #[test]
+    fn successfully_get_frozen_iter() {
+        let mut bst = RecursiveBST::from_iter(1..=5);
+        let mut frozen_iter = bst.frozen_iter();
+        assert_eq!(frozen_iter.next(), Some(1));
+
+        bst.insert(0);
+        bst.remove(&3);
+        drop(bst);
+
+        assert_eq!(frozen_iter.len(), 4);
+        assert_eq!(frozen_iter.collect::<Vec<i32>>(), vec![2, 3, 4, 5]);
+        assert_eq!(RecursiveBST::<i32>::new().frozen_iter().next(), None);
+    }
+
+    #[test]
+    fn successfully_get_quantile() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.quantile(0.5), None);
+
+        bst.insert(3);
         bst.insert(1);
         bst.insert(5);
+        bst.insert(2);
+        bst.insert(4);
 
-        assert!(!bst.contains(&10));
-        assert!(bst.contains(&1));
-        assert!(bst.contains(&5));
+        assert_eq!(bst.quantile(0.0), Some(&1));
+        assert_eq!(bst.quantile(0.25), Some(&2));
+        assert_eq!(bst.quantile(0.5), Some(&3));
+        assert_eq!(bst.quantile(1.0), Some(&5));
+        assert_eq!(bst.quantile(-1.0), Some(&1));
+        assert_eq!(bst.quantile(1.5), Some(&5));
+        assert_eq!(bst.quantile(f64::NAN), None);
     }
 
     #[test]
-    fn successfully_remove_root_node_from_bst() {
+    fn successfully_iter_after_element() {
+        let bst = RecursiveBST::from_iter(1..=5);
+        let another_bst = RecursiveBST::from(vec![40, 20, 60, 10, 30, 50, 70]);
+
+        assert_eq!(bst.iter_after(&3).collect::<Vec<&i32>>(), vec![&4, &5]);
+        assert_eq!(bst.iter_after(&5).next(), None);
+        assert_eq!(bst.iter_after(&0).count(), 5);
+        assert_eq!(
+            another_bst.iter_after(&35).collect::<Vec<&i32>>(),
+            vec![&40, &50, &60, &70]
+        );
+        assert_eq!(
+            another_bst.iter_after(&20).collect::<Vec<&i32>>(),
+            vec![&30, &40, &50, &60, &70]
+        );
+        assert_eq!(RecursiveBST::<i32>::new().iter_after(&1).next(), None);
+    }
+
+    #[test]
+    fn successfully_get_subtree_balance_scores() {
         let mut bst = RecursiveBST::new();
-        bst.insert(0);
+        assert!(bst.subtree_balance_scores().is_empty());
 
-        bst.remove(&0);
+        bst.insert(4);
+        bst.insert(2);
+        bst.insert(6);
+        bst.insert(1);
+        bst.insert(3);
+        bst.insert(5);
+        bst.insert(7);
+        assert!(bst
+            .subtree_balance_scores()
+            .iter()
+            .all(|(_, score)| *score == 1.0));
 
-        assert!(bst.is_empty());
-        assert_eq!(bst.size(), 0)
+        bst.insert(8);
+        bst.insert(9);
+        assert_eq!(
+            bst.subtree_balance_scores(),
+            vec![
+                (&1, 1.0),
+                (&2, 1.0),
+                (&3, 1.0),
+                (&4, 9.0 / 31.0),
+                (&5, 1.0),
+                (&6, 5.0 / 15.0),
+                (&7, 3.0 / 7.0),
+                (&8, 2.0 / 3.0),
+                (&9, 1.0)
+            ]
+        );
     }
 
     #[test]
-    fn successfully_remove_leaf_node() {
-        let mut expected_bst = RecursiveBST::new();
-        expected_bst.insert(5);
-        expected_bst.insert(4);
-        expected_bst.insert(6);
-        let mut actual_bst = RecursiveBST::new();
-        actual_bst.insert(5);
-        actual_bst.insert(4);
-        actual_bst.insert(6);
-        actual_bst.insert(7);
+    fn successfully_create_bst_from_strictly_increasing_elements() {
+        let bst = RecursiveBST::from_strictly_increasing(1..=15).unwrap();
+        let empty_bst = RecursiveBST::<i32>::from_strictly_increasing(Vec::new()).unwrap();
 
-        actual_bst.remove(&7);
+        assert_eq!(bst.size(), 15);
+        assert_eq!(bst.height(), Some(3));
+        assert_eq!(bst.skew_score(), Some(1.0));
+        assert_eq!(
+            bst.asc_order_vec(),
+            RecursiveBST::from_iter(1..=15).asc_order_vec()
+        );
+        assert!(empty_bst.is_empty());
+    }
 
-        assert_eq!(actual_bst.size(), 3);
-        assert_eq!(actual_bst, expected_bst);
+    #[test]
+    fn fail_to_create_bst_from_unsorted_elements() {
+        assert_eq!(
+            RecursiveBST::from_strictly_increasing(vec![1, 2, 5, 4, 6]),
+            Err(NotSorted { position: 3 })
+        );
+        assert_eq!(
+            RecursiveBST::from_strictly_increasing(vec![1, 1]),
+            Err(NotSorted { position: 1 })
+        );
     }
 
     #[test]
-    fn successfully_remove_single_right_node_with_children() {
-        let mut expected_bst = RecursiveBST::new();
-        expected_bst.insert(5);
-        expected_bst.insert(4);
-        expected_bst.insert(7);
-        expected_bst.insert(8);
-        let mut actual_bst = RecursiveBST::new();
-        actual_bst.insert(5);
-        actual_bst.insert(4);
-        actual_bst.insert(6);
-        actual_bst.insert(7);
-        actual_bst.insert(8);
+    fn successfully_get_expected_search_visits() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.expected_search_visits(), None);
 
-        actual_bst.remove(&6);
+        bst.insert(4);
+        bst.insert(2);
+        bst.insert(6);
+        bst.insert(1);
+        bst.insert(3);
+        bst.insert(5);
+        bst.insert(7);
 
-        println!("{}", actual_bst);
-        assert_eq!(actual_bst.size(), 4);
-        assert_eq!(actual_bst, expected_bst);
+        // One search visits a single node, two visit two nodes and four visit three nodes
+        assert_eq!(bst.expected_search_visits(), Some(17.0 / 7.0));
+
+        let skewed_bst = RecursiveBST::from_iter(1..=4);
+        assert_eq!(skewed_bst.expected_search_visits(), Some(10.0 / 4.0));
     }
 
     #[test]
-    fn successfully_remove_single_left_node_with_children() {
-        let mut expected_bst = RecursiveBST::new();
-        expected_bst.insert(5);
-        expected_bst.insert(3);
-        expected_bst.insert(2);
-        expected_bst.insert(6);
-        let mut actual_bst = RecursiveBST::new();
-        actual_bst.insert(5);
-        actual_bst.insert(4);
-        actual_bst.insert(6);
-        actual_bst.insert(3);
-        actual_bst.insert(2);
+    fn successfully_sample_by_step() {
+        let bst = RecursiveBST::from(vec![5, 2, 7, 1, 3, 6, 8, 4]);
 
-        actual_bst.remove(&4);
+        assert_eq!(bst.sample_by_step(3), vec![&1, &4, &7]);
+        assert_eq!(bst.sample_by_step(1), bst.asc_order_vec());
+        assert_eq!(bst.sample_by_step(8), vec![&1]);
+        assert!(RecursiveBST::<i32>::new().sample_by_step(2).is_empty());
+    }
 
-        assert_eq!(actual_bst.size(), 4);
-        assert_eq!(actual_bst, expected_bst);
+    #[test]
+    #[should_panic(expected = "step must be greater than zero")]
+    fn fail_to_sample_by_zero_step() {
+        RecursiveBST::from_iter(1..=8).sample_by_step(0);
     }
 
     #[test]
-    fn successfully_remove_node_with_two_children() {
-        let mut expected_bst = RecursiveBST::new();
-        expected_bst.insert(10);
-        expected_bst.insert(3);
-        expected_bst.insert(8);
-        expected_bst.insert(15);
-        let mut actual_bst = RecursiveBST::new();
-        actual_bst.insert(10);
-        actual_bst.insert(5);
-        actual_bst.insert(8);
-        actual_bst.insert(3);
-        actual_bst.insert(15);
+    fn check_if_bst_is_rotation_of_another() {
+        let bst = RecursiveBST::from_iter(1..=10);
+        let mut rebalanced_bst = bst.clone();
+        rebalanced_bst.rebalance();
+
+        assert!(bst.is_rotation_of(&rebalanced_bst));
+        assert!(rebalanced_bst.is_rotation_of(&bst));
+        assert!(bst.is_rotation_of(&bst));
+        assert!(!bst.is_rotation_of(&RecursiveBST::from_iter(1..=9)));
+        assert!(!bst.is_rotation_of(&RecursiveBST::from_iter(2..=11)));
+        assert!(RecursiveBST::<i32>::new().is_rotation_of(&RecursiveBST::new()));
+    }
 
-        actual_bst.remove(&5);
+    #[test]
+    fn successfully_insert_stream_with_autobalance() {
+        let mut bst = RecursiveBST::new();
+        bst.insert_stream_autobalance(1..=1000, 100);
 
-        assert_eq!(actual_bst, expected_bst);
+        assert_eq!(bst.size(), 1000);
+        assert_eq!(bst.height(), Some(9));
+        assert_eq!(
+            bst.asc_order_vec(),
+            RecursiveBST::from_iter(1..=1000).asc_order_vec()
+        );
+
+        let mut unbalanced_bst = RecursiveBST::new();
+        unbalanced_bst.insert_stream_autobalance(1..=100, 0);
+        assert_eq!(unbalanced_bst.height(), Some(99));
     }
 
     #[test]
-    fn successfully_does_not_fail_when_removing_non_existing_element() {
-        let mut expected_bst = RecursiveBST::new();
-        expected_bst.insert(10);
-        expected_bst.insert(5);
-        expected_bst.insert(8);
-        expected_bst.insert(3);
-        expected_bst.insert(15);
+    fn successfully_get_element_nearest_median() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.element_nearest_median(), None);
 
-        let mut actual_bst = RecursiveBST::new();
-        actual_bst.insert(10);
-        actual_bst.insert(5);
-        actual_bst.insert(8);
-        actual_bst.insert(3);
-        actual_bst.insert(15);
+        bst.insert(6);
+        bst.insert(2);
+        bst.insert(5);
+        bst.insert(1);
+        bst.insert(4);
+        bst.insert(3);
+        assert_eq!(bst.element_nearest_median(), Some(&3));
 
-        actual_bst.remove(&20);
+        bst.insert(7);
+        assert_eq!(bst.element_nearest_median(), Some(&4));
+    }
 
-        assert_eq!(actual_bst.size(), 5);
-        assert_eq!(actual_bst, expected_bst);
+    #[test]
+    fn successfully_get_diff_sizes() {
+        let bst = RecursiveBST::from_iter(1..=3);
+        let another_bst = RecursiveBST::from_iter(2..=4);
+        let empty_bst = RecursiveBST::new();
+
+        assert_eq!(bst.diff_sizes(&another_bst), (1, 2, 1));
+        assert_eq!(another_bst.diff_sizes(&bst), (1, 2, 1));
+        assert_eq!(bst.diff_sizes(&RecursiveBST::from_iter(1..=10)), (0, 3, 7));
+        assert_eq!(bst.diff_sizes(&empty_bst), (3, 0, 0));
+        assert_eq!(empty_bst.diff_sizes(&RecursiveBST::new()), (0, 0, 0));
     }
 
     #[test]
-    fn successfully_retrieve_element() {
+    fn successfully_remap_shape() {
+        let bst = RecursiveBST::from(vec![8, 3, 10, 1, 6, 14, 4, 7, 13]);
+        let remapped_bst = bst.remap_shape(|value| -value);
+
+        assert_eq!(remapped_bst.size(), bst.size());
+        assert_eq!(remapped_bst.height(), bst.height());
+        assert_eq!(
+            remapped_bst.pre_order_vec(),
+            vec![&-8, &-3, &-1, &-6, &-4, &-7, &-10, &-14, &-13]
+        );
+        assert_eq!(
+            remapped_bst.in_order_vec(),
+            vec![&-1, &-3, &-4, &-6, &-7, &-8, &-10, &-13, &-14]
+        );
+        assert!(RecursiveBST::<i32>::new()
+            .remap_shape(|value| -value)
+            .is_empty());
+    }
+
+    #[test]
+    fn successfully_get_k_smallest_elements() {
+        let bst = RecursiveBST::from(vec![50, 25, 75, 10, 30, 60, 90]);
+
+        assert_eq!(
+            RecursiveBST::from_iter(1..=100).into_k_smallest(5),
+            vec![1, 2, 3, 4, 5]
+        );
+        assert_eq!(bst.clone().into_k_smallest(4), vec![10, 25, 30, 50]);
+        assert_eq!(bst.clone().into_k_smallest(0), Vec::<i32>::new());
+        assert_eq!(bst.into_k_smallest(100), vec![10, 25, 30, 50, 60, 75, 90]);
+        assert!(RecursiveBST::<i32>::new().into_k_smallest(3).is_empty());
+    }
+
+    #[test]
+    fn successfully_get_common_path() {
         let mut bst = RecursiveBST::new();
+        assert!(bst.common_path(&1, &1).is_empty());
+
+        bst.insert(4);
+        bst.insert(2);
+        bst.insert(6);
+        bst.insert(1);
+        bst.insert(3);
         bst.insert(5);
-        bst.insert(10);
+        bst.insert(7);
 
-        let retrieved_value = bst.retrieve(&5);
-        let invalid_value = bst.retrieve(&15);
+        assert_eq!(bst.common_path(&1, &3), vec![&4, &2]);
+        assert_eq!(bst.common_path(&3, &1), vec![&4, &2]);
+        assert_eq!(bst.common_path(&1, &7), vec![&4]);
+        assert_eq!(bst.common_path(&2, &3), vec![&4, &2]);
+        assert_eq!(bst.common_path(&5, &5), vec![&4, &6, &5]);
+        assert!(bst.common_path(&0, &3).is_empty());
+        assert!(bst.common_path(&3, &8).is_empty());
+    }
 
-        assert_eq!(retrieved_value, Some(&5));
-        assert_eq!(invalid_value, None);
+    #[test]
+    fn successfully_convert_between_recursive_and_iterative_bst() {
+        let bst = RecursiveBST::from(vec![8, 3, 10, 1, 6, 14, 4, 7, 13]);
+        let pre_order: Vec<i32> = bst.pre_order_iter().cloned().collect();
+
+        let iterative_bst =
+            IterativeBST::from(RecursiveBST::from(vec![8, 3, 10, 1, 6, 14, 4, 7, 13]));
+        assert_eq!(iterative_bst.size(), 9);
+        assert_eq!(
+            iterative_bst
+                .pre_order_iter()
+                .cloned()
+                .collect::<Vec<i32>>(),
+            pre_order
+        );
+
+        let round_trip_bst = RecursiveBST::from(iterative_bst);
+        assert!(round_trip_bst.same_shape(&bst));
+        assert_eq!(
+            round_trip_bst
+                .pre_order_iter()
+                .cloned()
+                .collect::<Vec<i32>>(),
+            pre_order
+        );
+        assert_eq!(round_trip_bst.size(), bst.size());
+        assert!(RecursiveBST::from(IterativeBST::<i32>::new()).is_empty());
+    }
+
+    #[test]
+    fn successfully_get_full_levels() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.full_levels(), 0);
+
+        bst.insert(4);
+        bst.insert(2);
+        bst.insert(6);
+        bst.insert(1);
+        bst.insert(3);
+        bst.insert(5);
+        bst.insert(7);
+        assert_eq!(bst.full_levels(), 3);
+
+        bst.insert(8);
+        assert_eq!(bst.full_levels(), 3);
+
+        bst.remove(&3);
+        bst.insert(9);
+        assert_eq!(bst.size(), 8);
+        assert_eq!(bst.full_levels(), 2);
+
+        assert_eq!(RecursiveBST::from_iter(1..=8).full_levels(), 1);
+    }
+
+    #[test]
+    fn successfully_take_window_around_element() {
+        let bst = RecursiveBST::from(vec![4, 2, 6, 1, 3, 5, 7]);
+
+        assert_eq!(bst.take_window_around(&4, 2), vec![&2, &3, &4, &5, &6]);
+        assert_eq!(bst.take_window_around(&2, 2), vec![&1, &2, &3, &4]);
+        assert_eq!(bst.take_window_around(&7, 1), vec![&6, &7]);
+        assert_eq!(bst.take_window_around(&5, 0), vec![&5]);
+        assert_eq!(bst.take_window_around(&4, usize::MAX), bst.asc_order_vec());
+        assert!(bst.take_window_around(&8, 2).is_empty());
+    }
+
+    #[test]
+    fn successfully_count_rejected_duplicates() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.rejected_duplicates(), 0);
+
+        bst.extend(vec![1, 1, 2, 2, 2, 3]);
+        assert_eq!(bst.size(), 3);
+        assert_eq!(bst.rejected_duplicates(), 3);
+
+        assert_eq!(bst.insert_with_depth(3), None);
+        assert_eq!(bst.rejected_duplicates(), 4);
+
+        bst.remove(&3);
+        bst.insert(3);
+        assert_eq!(bst.rejected_duplicates(), 4);
     }
 
     #[test]
-    fn successfully_retrieve_element_as_mut_and_modify_bst() {
-        let mut expected_bst = RecursiveBST::new();
-        expected_bst.insert(10);
-        expected_bst.insert(2);
-
-        let mut actual_bst = RecursiveBST::new();
-        actual_bst.insert(10);
-        actual_bst.insert(5);
+    fn successfully_find_best_single_rotation() {
+        let mut bst = RecursiveBST::from_iter(1..=7);
+        let path_length = Node::iterative_internal_path_length(&bst.root);
 
-        let _retrieved_value_as_mut: &mut i32 = actual_bst.retrieve_as_mut(&5).unwrap();
-        *_retrieved_value_as_mut = 2;
+        let (pivot, rotation) = bst.best_single_rotation().unwrap();
+        assert!(bst.rotate(&pivot, rotation));
+        assert!(Node::iterative_internal_path_length(&bst.root) < path_length);
+        assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3, &4, &5, &6, &7]);
 
-        assert_eq!(actual_bst, expected_bst);
+        bst.rebalance();
+        assert_eq!(bst.best_single_rotation(), None);
     }
 
     #[test]
-    fn successfully_get_height_of_bst() {
+    fn best_single_rotation_returns_none_for_small_trees() {
         let mut bst = RecursiveBST::new();
-        assert_eq!(bst.height(), None);
+        assert_eq!(bst.best_single_rotation(), None);
 
-        bst.insert(4);
-        assert_eq!(bst.height(), Some(0));
+        bst.insert(1);
+        assert_eq!(bst.best_single_rotation(), None);
 
         bst.insert(2);
-        bst.insert(6);
-        bst.insert(1);
-        bst.insert(3);
-        bst.insert(4);
-        bst.insert(7);
-        assert_eq!(bst.height(), Some(2));
+        assert_eq!(bst.best_single_rotation(), None);
+    }
 
-        bst.insert(8);
-        assert_eq!(bst.height(), Some(3));
+    #[test]
+    fn successfully_render_rows() {
+        let bst = RecursiveBST::from(vec![8, 3, 10, 1, 6, 14, 4]);
+
+        assert_eq!(
+            bst.render_rows(),
+            Some(vec![
+                vec![(0, &8)],
+                vec![(0, &3), (1, &10)],
+                vec![(0, &1), (1, &6), (3, &14)],
+                vec![(2, &4)],
+            ])
+        );
     }
 
     #[test]
-    fn successfully_get_min_from_bst() {
-        let mut bst = RecursiveBST::new();
-        assert_eq!(bst.min(), None);
+    fn render_rows_of_empty_tree_is_empty() {
+        let bst = RecursiveBST::<i32>::new();
 
-        bst.insert(5);
-        bst.insert(3);
-        bst.insert(1);
-        bst.insert(15);
+        assert_eq!(bst.render_rows(), Some(Vec::new()));
+    }
 
-        assert_eq!(bst.min(), Some(&1));
+    #[test]
+    fn successfully_render_rows_of_sorted_insert_chain() {
+        // The deepest node of the chain sits in the last slot that still fits into a usize
+        let bst = RecursiveBST::from_iter(0..=usize::BITS);
+        let rows = bst.render_rows().unwrap();
+
+        assert_eq!(rows.len(), usize::BITS as usize + 1);
+        assert_eq!(rows[1], vec![(1, &1)]);
+        assert_eq!(rows.last(), Some(&vec![(usize::MAX, &usize::BITS)]));
     }
 
     #[test]
-    fn successfully_get_max_from_bst() {
-        let mut bst = RecursiveBST::new();
-        assert_eq!(bst.max(), None);
+    fn render_rows_with_overflowing_slots_is_none() {
+        let bst = RecursiveBST::from_iter(0..=usize::BITS + 1);
 
-        bst.insert(5);
-        bst.insert(12);
-        bst.insert(1);
-        bst.insert(15);
+        assert_eq!(bst.render_rows(), None);
+    }
 
-        assert_eq!(bst.max(), Some(&15));
+    #[test]
+    fn successfully_get_predecessor_and_successor() {
+        let bst = RecursiveBST::from(vec![8, 3, 10, 1, 6, 14, 4, 7, 13]);
+
+        assert_eq!(bst.successor(&8), Some(&10));
+        assert_eq!(bst.predecessor(&8), Some(&7));
+        assert_eq!(bst.successor(&7), Some(&8));
+        assert_eq!(bst.predecessor(&10), Some(&8));
+        assert_eq!(bst.successor(&10), Some(&13));
+        assert_eq!(bst.predecessor(&4), Some(&3));
     }
 
     #[test]
-    fn successfully_remove_min_from_bst() {
-        let mut bst = RecursiveBST::new();
-        assert_eq!(bst.remove_min(), None);
+    fn predecessor_and_successor_of_missing_value() {
+        let bst = RecursiveBST::from(vec![8, 3, 10, 1, 6, 14, 4, 7, 13]);
 
-        bst.insert(5);
-        assert_eq!(bst.remove_min(), Some(5));
-        assert_eq!(bst.size(), 0);
+        assert_eq!(bst.predecessor(&11), Some(&10));
+        assert_eq!(bst.successor(&11), Some(&13));
+        assert_eq!(bst.predecessor(&0), None);
+        assert_eq!(bst.successor(&15), None);
+    }
 
-        bst.insert(3);
-        bst.insert(1);
-        bst.insert(2);
-        bst.insert(15);
+    #[test]
+    fn predecessor_and_successor_at_the_edges() {
+        let bst = RecursiveBST::from(vec![8, 3, 10, 1, 6, 14, 4, 7, 13]);
 
-        assert_eq!(bst.remove_min(), Some(1));
-        assert!(bst.contains(&2));
-        assert_eq!(bst.size(), 3);
+        assert_eq!(bst.predecessor(&1), None);
+        assert_eq!(bst.successor(&14), None);
+        assert_eq!(RecursiveBST::<i32>::new().successor(&1), None);
+        assert_eq!(RecursiveBST::<i32>::new().predecessor(&1), None);
     }
 
     #[test]
-    fn successfully_remove_max_from_bst() {
-        let mut bst = RecursiveBST::new();
-        assert_eq!(bst.remove_max(), None);
+    fn successfully_consume_elements_greater_than_threshold() {
+        let bst = RecursiveBST::from(vec![1, 2, 3, 4, 5]);
+        assert_eq!(bst.into_greater_than(&3), vec![4, 5]);
 
-        bst.insert(5);
-        assert_eq!(bst.remove_max(), Some(5));
-        assert_eq!(bst.size(), 0);
+        let bst = RecursiveBST::from(vec![8, 3, 10, 1, 6, 14, 4, 7, 13]);
+        assert_eq!(bst.into_greater_than(&5), vec![6, 7, 8, 10, 13, 14]);
+    }
 
-        bst.insert(3);
-        bst.insert(1);
-        bst.insert(15);
-        bst.insert(10);
+    #[test]
+    fn into_greater_than_beyond_the_edges() {
+        let bst = RecursiveBST::from(vec![3, 1, 2]);
+        assert_eq!(bst.into_greater_than(&0), vec![1, 2, 3]);
 
-        assert_eq!(bst.remove_max(), Some(15));
-        assert!(bst.contains(&10));
-        assert_eq!(bst.size(), 3);
+        let bst = RecursiveBST::from(vec![3, 1, 2]);
+        assert_eq!(bst.into_greater_than(&3), Vec::<i32>::new());
+
+        let bst = RecursiveBST::<i32>::new();
+        assert_eq!(bst.into_greater_than(&3), Vec::<i32>::new());
     }
 
     #[test]
-    fn pre_order_iter() {
-        let mut bst = RecursiveBST::new();
-        bst.insert(3);
-        bst.insert(4);
-        bst.insert(5);
-        bst.insert(1);
-        bst.insert(2);
+    fn successfully_create_tree_with_build_report() {
+        let (bst, report) = RecursiveBST::from_iter_validated(vec![3, 1, 3, 2]);
 
-        let mut pre_order_iter = bst.pre_order_iter();
+        assert_eq!(bst, RecursiveBST::from(vec![1, 2, 3]));
+        assert_eq!(
+            report,
+            BuildReport {
+                total: 4,
+                duplicates: 1,
+                out_of_order: 2
+            }
+        );
+    }
 
-        assert_eq!(pre_order_iter.next(), Some(&3));
-        assert_eq!(pre_order_iter.next(), Some(&1));
-        assert_eq!(pre_order_iter.next(), Some(&2));
-        assert_eq!(pre_order_iter.next(), Some(&4));
-        assert_eq!(pre_order_iter.next(), Some(&5));
-        assert_eq!(pre_order_iter.next(), None);
+    #[test]
+    fn build_report_of_empty_input() {
+        let (bst, report) = RecursiveBST::<i32>::from_iter_validated(vec![]);
 
-        bst.insert(10);
+        assert!(bst.is_empty());
+        assert_eq!(
+            report,
+            BuildReport {
+                total: 0,
+                duplicates: 0,
+                out_of_order: 0
+            }
+        );
+    }
 
-        let mut another_pre_order_iter = bst.pre_order_iter();
+    #[test]
+    fn successfully_drop_degenerate_tree_without_overflowing() {
+        let mut bst = RecursiveBST::from_strictly_increasing(0..100_000).unwrap();
+        // Turns the tree into a linked list, as if the sequential integers were inserted one by one
+        assert!(bst.rebalance_to_height(99_999).is_ok());
+        assert_eq!(bst.size(), 100_000);
 
-        assert_eq!(another_pre_order_iter.next(), Some(&3));
-        assert_eq!(another_pre_order_iter.next(), Some(&1));
-        assert_eq!(another_pre_order_iter.next(), Some(&2));
-        assert_eq!(another_pre_order_iter.next(), Some(&4));
-        assert_eq!(another_pre_order_iter.next(), Some(&5));
-        assert_eq!(another_pre_order_iter.next(), Some(&10));
-        assert_eq!(another_pre_order_iter.next(), None);
+        drop(bst);
     }
 
     #[test]
-    fn in_order_iter() {
-        let mut bst = RecursiveBST::new();
-        bst.insert(3);
-        bst.insert(4);
-        bst.insert(5);
-        bst.insert(1);
-        bst.insert(2);
+    fn successfully_get_greater_than_from_degenerate_tree_without_overflowing() {
+        let mut bst = RecursiveBST::from_strictly_increasing(0..100_000).unwrap();
+        assert!(bst.rebalance_to_height(99_999).is_ok());
+        // Rotating every root to the left turns the tree into a chain of left children
+        for value in 0..99_999 {
+            assert!(bst.rotate(&value, Rotation::Left));
+        }
 
-        let mut in_order_iter = bst.in_order_iter();
+        assert!(bst.into_greater_than(&99_999).is_empty());
+    }
 
-        assert_eq!(in_order_iter.next(), Some(&1));
-        assert_eq!(in_order_iter.next(), Some(&2));
-        assert_eq!(in_order_iter.next(), Some(&3));
-        assert_eq!(in_order_iter.next(), Some(&4));
-        assert_eq!(in_order_iter.next(), Some(&5));
-        assert_eq!(in_order_iter.next(), None);
+    #[test]
+    fn successfully_get_k_smallest_from_degenerate_tree_without_overflowing() {
+        let mut bst = RecursiveBST::from_strictly_increasing(0..100_000).unwrap();
+        assert!(bst.rebalance_to_height(99_999).is_ok());
 
-        bst.insert(6);
+        assert_eq!(bst.into_k_smallest(1), vec![0]);
+    }
 
-        let mut another_in_order_iter = bst.in_order_iter();
+    #[test]
+    fn successfully_get_max_subtree_fraction() {
+        let mut bst = RecursiveBST::from_iter(1..=7);
+        bst.rebalance();
+        assert_eq!(bst.max_subtree_fraction(), 3.0 / 7.0);
 
-        assert_eq!(another_in_order_iter.next(), Some(&1));
-        assert_eq!(another_in_order_iter.next(), Some(&2));
-        assert_eq!(another_in_order_iter.next(), Some(&3));
-        assert_eq!(another_in_order_iter.next(), Some(&4));
-        assert_eq!(another_in_order_iter.next(), Some(&5));
-        assert_eq!(another_in_order_iter.next(), Some(&6));
-        assert_eq!(another_in_order_iter.next(), None);
+        let skewed_bst = RecursiveBST::from_iter(1..=100);
+        assert_eq!(skewed_bst.max_subtree_fraction(), 0.99);
     }
 
     #[test]
-    fn post_order_iter() {
+    fn max_subtree_fraction_of_tiny_trees_is_zero() {
         let mut bst = RecursiveBST::new();
-        bst.insert(3);
-        bst.insert(4);
-        bst.insert(5);
+        assert_eq!(bst.max_subtree_fraction(), 0.0);
+
         bst.insert(1);
-        bst.insert(2);
+        assert_eq!(bst.max_subtree_fraction(), 0.0);
+    }
 
-        let mut post_order_iter = bst.post_order_iter();
+    #[test]
+    fn successfully_pretty_print() {
+        let bst = RecursiveBST::from(vec![8, 3, 10, 1, 6, 14, 4, 7, 13]);
+
+        let expected = "\
+8
+├── 3
+│   ├── 1
+│   └── 6
+│       ├── 4
+│       └── 7
+└── 10
+    └── 14
+        └── 13";
+        assert_eq!(bst.pretty_print(), expected);
+    }
 
-        assert_eq!(post_order_iter.next(), Some(&2));
-        assert_eq!(post_order_iter.next(), Some(&1));
-        assert_eq!(post_order_iter.next(), Some(&5));
-        assert_eq!(post_order_iter.next(), Some(&4));
-        assert_eq!(post_order_iter.next(), Some(&3));
-        assert_eq!(post_order_iter.next(), None);
+    #[test]
+    fn pretty_print_empty_tree() {
+        let bst = RecursiveBST::<i32>::new();
 
-        bst.insert(10);
+        assert_eq!(bst.pretty_print(), "");
+    }
 
-        let mut another_post_order_iter = bst.post_order_iter();
+    #[test]
+    fn successfully_get_ancestors() {
+        let bst = RecursiveBST::from(vec![4, 2, 6, 1, 3, 5, 7]);
 
-        assert_eq!(another_post_order_iter.next(), Some(&2));
-        assert_eq!(another_post_order_iter.next(), Some(&1));
-        assert_eq!(another_post_order_iter.next(), Some(&10));
-        assert_eq!(another_post_order_iter.next(), Some(&5));
-        assert_eq!(another_post_order_iter.next(), Some(&4));
-        assert_eq!(another_post_order_iter.next(), Some(&3));
-        assert_eq!(another_post_order_iter.next(), None);
+        assert_eq!(bst.ancestors(&1), Some(vec![&2, &4]));
+        assert_eq!(bst.ancestors(&7), Some(vec![&6, &4]));
+        assert_eq!(bst.ancestors(&6), Some(vec![&4]));
+        assert_eq!(bst.ancestors(&4), Some(vec![]));
     }
 
     #[test]
-    fn level_order_iter() {
-        let mut bst = RecursiveBST::new();
-        bst.insert(15);
-        bst.insert(20);
-        bst.insert(10);
-        bst.insert(8);
-        bst.insert(12);
-        bst.insert(16);
-        bst.insert(25);
+    fn ancestors_of_missing_value_is_none() {
+        let bst = RecursiveBST::from(vec![4, 2, 6, 1, 3, 5, 7]);
 
-        let mut level_order_iter = bst.level_order_iter();
+        assert_eq!(bst.ancestors(&0), None);
+        assert_eq!(bst.ancestors(&8), None);
+        assert_eq!(RecursiveBST::<i32>::new().ancestors(&1), None);
+    }
 
-        assert_eq!(level_order_iter.next(), Some(&15));
-        assert_eq!(level_order_iter.next(), Some(&10));
-        assert_eq!(level_order_iter.next(), Some(&20));
-        assert_eq!(level_order_iter.next(), Some(&8));
-        assert_eq!(level_order_iter.next(), Some(&12));
-        assert_eq!(level_order_iter.next(), Some(&16));
-        assert_eq!(level_order_iter.next(), Some(&25));
-        assert_eq!(level_order_iter.next(), None);
+    #[test]
+    fn successfully_get_descendants() {
+        let bst = RecursiveBST::from(vec![4, 2, 6, 1, 3, 5, 7]);
 
-        bst.insert(4);
+        assert_eq!(bst.descendants(&2), Some(vec![&1, &3]));
+        assert_eq!(bst.descendants(&4), Some(vec![&1, &2, &3, &5, &6, &7]));
+        assert_eq!(bst.descendants(&1), Some(vec![]));
+    }
 
-        let mut another_level_order_iter = bst.level_order_iter();
+    #[test]
+    fn descendants_of_missing_value_is_none() {
+        let bst = RecursiveBST::from(vec![4, 2, 6, 1, 3, 5, 7]);
 
-        assert_eq!(another_level_order_iter.next(), Some(&15));
-        assert_eq!(another_level_order_iter.next(), Some(&10));
-        assert_eq!(another_level_order_iter.next(), Some(&20));
-        assert_eq!(another_level_order_iter.next(), Some(&8));
-        assert_eq!(another_level_order_iter.next(), Some(&12));
-        assert_eq!(another_level_order_iter.next(), Some(&16));
-        assert_eq!(another_level_order_iter.next(), Some(&25));
-        assert_eq!(another_level_order_iter.next(), Some(&4));
-        assert_eq!(another_level_order_iter.next(), None);
+        assert_eq!(bst.descendants(&0), None);
+        assert_eq!(RecursiveBST::<i32>::new().descendants(&1), None);
     }
 
     #[test]
-    fn into_pre_order_iter_with_no_elements() {
-        let bst: RecursiveBST<i32> = RecursiveBST::new();
+    fn successfully_get_rank_and_select() {
+        let bst = RecursiveBST::from(vec![8, 3, 10, 1, 6, 14, 4, 7, 13]);
+
+        assert_eq!(bst.select(0), bst.min());
+        assert_eq!(bst.select(bst.size() - 1), bst.max());
+        assert_eq!(bst.rank(bst.max().unwrap()), bst.size() - 1);
+        assert_eq!(bst.select(bst.size()), None);
+        assert_eq!(bst.rank(&0), 0);
+        assert_eq!(bst.rank(&5), 3);
+        for (k, value) in bst.asc_order_vec().into_iter().enumerate() {
+            assert_eq!(bst.select(k), Some(value));
+            assert_eq!(bst.rank(value), k);
+        }
+    }
 
-        let mut pre_order_traversal = bst.into_pre_order_iter();
+    #[test]
+    fn rank_and_select_stay_correct_after_modifications() {
+        let mut bst = RecursiveBST::from(vec![8, 3, 10, 1, 6, 14, 4, 7, 13]);
+        bst.insert(6);
+        bst.insert(5);
+        bst.remove(&3);
+        bst.remove(&8);
+        bst.remove(&99);
+        bst.remove_min();
+        bst.remove_max();
+        bst.rotate(&10, Rotation::Left);
+        bst.splice_sorted(&[11, 12]);
+
+        let expected = [4, 5, 6, 7, 10, 11, 12, 13];
+        for (k, value) in expected.iter().enumerate() {
+            assert_eq!(bst.select(k), Some(value));
+            assert_eq!(bst.rank(value), k);
+        }
+        assert_eq!(bst.select(expected.len()), None);
 
-        assert_eq!(pre_order_traversal.next(), None);
+        bst.rebalance();
+        for (k, value) in expected.iter().enumerate() {
+            assert_eq!(bst.select(k), Some(value));
+        }
+        assert!(bst.rebalance_to_height(7).is_ok());
+        for (k, value) in expected.iter().enumerate() {
+            assert_eq!(bst.select(k), Some(value));
+        }
     }
 
     #[test]
-    fn into_pre_order_iter_with_one_element() {
-        let mut bst = RecursiveBST::new();
-        bst.insert(3);
+    fn successfully_rebalance_subtree() {
+        let mut bst = RecursiveBST::from(vec![50, 25, 12, 37, 75]);
+        bst.extend(76..=85);
+        assert_eq!(bst.height(), Some(11));
+
+        assert!(bst.rebalance_subtree(&75));
+        assert_eq!(bst.height(), Some(4));
+        assert_eq!(bst.size(), 15);
+        // The root and its left subtree keep their shape
+        assert_eq!(bst.pre_order_vec()[..4], [&50, &25, &12, &37]);
+        let subtree_root = *bst.pre_order_vec()[4];
+        assert_eq!(bst.descendants(&subtree_root).unwrap().len(), 10);
+        assert!(bst.asc_order_vec().is_sorted());
+        assert_eq!(bst.rank(&80), 9);
+    }
 
-        let mut pre_order_traversal = bst.into_pre_order_iter();
+    #[test]
+    fn rebalance_subtree_of_missing_value() {
+        let mut bst = RecursiveBST::from_iter(1..=5);
 
-        assert_eq!(pre_order_traversal.next(), Some(3));
-        assert_eq!(pre_order_traversal.next(), None);
+        assert!(!bst.rebalance_subtree(&6));
+        assert_eq!(bst.height(), Some(4));
     }
 
     #[test]
-    fn into_pre_order_iter() {
-        let mut iter: IntoIter<i32> = RecursiveBST::new().into_pre_order_iter();
-        assert_eq!(iter.next(), None);
+    fn successfully_get_range() {
+        let bst = RecursiveBST::from_iter(1..=10);
 
-        let mut bst = RecursiveBST::new();
-        bst.insert(3);
-        bst.insert(4);
-        bst.insert(5);
-        bst.insert(1);
-        bst.insert(2);
+        assert_eq!(
+            bst.range(&3, &7).collect::<Vec<&i32>>(),
+            vec![&3, &4, &5, &6, &7]
+        );
+        assert_eq!(bst.range(&5, &5).collect::<Vec<&i32>>(), vec![&5]);
+        assert_eq!(
+            bst.range(&-5, &100).collect::<Vec<&i32>>(),
+            bst.asc_order_vec()
+        );
+    }
 
-        let mut pre_order_iter = bst.into_pre_order_iter();
+    #[test]
+    fn range_with_bounds_missing_from_tree() {
+        let bst = RecursiveBST::from(vec![10, 20, 30, 40, 50]);
 
-        assert_eq!(pre_order_iter.next(), Some(3));
-        assert_eq!(pre_order_iter.next(), Some(1));
-        assert_eq!(pre_order_iter.next(), Some(2));
-        assert_eq!(pre_order_iter.next(), Some(4));
-        assert_eq!(pre_order_iter.next(), Some(5));
-        assert_eq!(pre_order_iter.next(), None);
+        assert_eq!(
+            bst.range(&15, &45).collect::<Vec<&i32>>(),
+            vec![&20, &30, &40]
+        );
+        assert_eq!(bst.range(&21, &29).next(), None);
+        assert_eq!(bst.range(&40, &20).next(), None);
+        assert_eq!(RecursiveBST::<i32>::new().range(&1, &10).next(), None);
+    }
+
+    #[test]
+    fn successfully_get_bucket_counts() {
+        let bst = RecursiveBST::from(vec![1, 2, 3, 4, 5]);
+
+        assert_eq!(bst.bucket_counts(&[2, 4]), vec![1, 2, 2]);
+        assert_eq!(bst.bucket_counts(&[0, 10]), vec![0, 5, 0]);
+        assert_eq!(bst.bucket_counts(&[3, 3]), vec![2, 0, 3]);
+        assert_eq!(RecursiveBST::<i32>::new().bucket_counts(&[1]), vec![0, 0]);
+    }
+
+    #[test]
+    fn successfully_iterate_over_borrowed_tree_in_for_loop() {
+        let bst = RecursiveBST::from(vec![4, 2, 6, 1, 3, 5, 7]);
+        let mut elements = Vec::new();
+
+        for value in &bst {
+            elements.push(value);
+        }
+
+        assert_eq!(elements, vec![&1, &2, &3, &4, &5, &6, &7]);
+        assert_eq!(bst.size(), 7);
     }
 
     #[test]
-    fn into_in_order_iter_with_no_elements() {
-        let bst: RecursiveBST<i32> = RecursiveBST::new();
+    fn successfully_iterate_over_owned_tree_in_for_loop() {
+        let bst = RecursiveBST::from(vec![4, 2, 6, 1, 3, 5, 7]);
+        let mut elements = Vec::new();
 
-        let mut in_order_traversal = bst.into_in_order_iter();
+        for value in bst {
+            elements.push(value);
+        }
 
-        assert_eq!(in_order_traversal.next(), None);
+        assert_eq!(elements, vec![1, 2, 3, 4, 5, 6, 7]);
     }
 
     #[test]
-    fn into_in_order_iter_with_one_element() {
-        let mut bst = RecursiveBST::new();
-        bst.insert(3);
-
-        let mut in_order_traversal = bst.into_in_order_iter();
+    fn successfully_extract_if() {
+        let mut bst = RecursiveBST::from(vec![1, 2, 3, 4, 5, 6]);
 
-        assert_eq!(in_order_traversal.next(), Some(3));
-        assert_eq!(in_order_traversal.next(), None);
+        assert_eq!(bst.extract_if(|value| value % 2 == 0), vec![2, 4, 6]);
+        assert_eq!(bst.asc_order_vec(), vec![&1, &3, &5]);
+        assert_eq!(bst.size(), 3);
+        assert!(!bst.contains(&4));
+        assert_eq!(bst.rank(&5), 2);
     }
 
     #[test]
-    fn into_in_order_iter() {
-        let another_bst: RecursiveBST<i32> = RecursiveBST::new();
-        let mut iter = another_bst.into_in_order_iter();
-        assert_eq!(iter.next(), None);
+    fn extract_if_without_matches_leaves_elements() {
+        let mut bst = RecursiveBST::from(vec![5, 3, 8]);
 
-        let mut bst = RecursiveBST::new();
-        bst.insert(3);
-        bst.insert(4);
-        bst.insert(5);
-        bst.insert(1);
-        bst.insert(2);
+        assert_eq!(bst.extract_if(|value| *value > 10), Vec::<i32>::new());
+        assert_eq!(bst.asc_order_vec(), vec![&3, &5, &8]);
+        assert_eq!(bst.extract_if(|_| true), vec![3, 5, 8]);
+        assert!(bst.is_empty());
+        assert_eq!(bst.height(), None);
+    }
 
-        let mut in_order_iter = bst.into_in_order_iter();
+    #[derive(Debug)]
+    struct Keyed(i32, &'static str);
 
-        assert_eq!(in_order_iter.next(), Some(1));
-        assert_eq!(in_order_iter.next(), Some(2));
-        assert_eq!(in_order_iter.next(), Some(3));
-        assert_eq!(in_order_iter.next(), Some(4));
-        assert_eq!(in_order_iter.next(), Some(5));
-        assert_eq!(in_order_iter.next(), None);
+    impl PartialEq for Keyed {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
     }
 
-    #[test]
-    fn into_post_order_iter_with_no_elements() {
-        let bst: RecursiveBST<i32> = RecursiveBST::new();
+    impl Eq for Keyed {}
 
-        let mut post_order_traversal = bst.into_post_order_iter();
+    impl PartialOrd for Keyed {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
 
-        assert_eq!(post_order_traversal.next(), None);
+    impl Ord for Keyed {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.0.cmp(&other.0)
+        }
     }
 
     #[test]
-    fn into_post_order_iter_with_one_element() {
-        let mut bst = RecursiveBST::new();
-        bst.insert(3);
+    fn successfully_get_or_insert() {
+        let mut bst = RecursiveBST::from(vec![Keyed(5, "five"), Keyed(3, "three")]);
 
-        let mut post_order_traversal = bst.into_post_order_iter();
-
-        assert_eq!(post_order_traversal.next(), Some(3));
-        assert_eq!(post_order_traversal.next(), None);
+        assert_eq!(bst.get_or_insert(Keyed(7, "first")).1, "first");
+        assert_eq!(bst.get_or_insert(Keyed(7, "second")).1, "first");
+        assert_eq!(bst.get_or_insert(Keyed(3, "other")).1, "three");
+        assert_eq!(bst.size(), 3);
+        assert_eq!(bst.rejected_duplicates(), 0);
+        assert_eq!(bst.rank(&Keyed(7, "")), 2);
+        assert_eq!(bst.select(2).map(|keyed| keyed.1), Some("first"));
     }
 
     #[test]
-    fn into_post_order_iter_with_many_elements() {
+    fn successfully_get_or_insert_into_empty_tree() {
         let mut bst = RecursiveBST::new();
-        bst.insert(3);
-        bst.insert(4);
-        bst.insert(5);
-        bst.insert(1);
-        bst.insert(2);
 
-        let mut post_order_traversal = bst.into_post_order_iter();
+        assert_eq!(bst.get_or_insert(1), &1);
+        assert_eq!(bst.get_or_insert(1), &1);
+        assert_eq!(bst.size(), 1);
+        assert_eq!(bst.height(), Some(0));
+    }
 
-        assert_eq!(post_order_traversal.next(), Some(2));
-        assert_eq!(post_order_traversal.next(), Some(1));
-        assert_eq!(post_order_traversal.next(), Some(5));
-        assert_eq!(post_order_traversal.next(), Some(4));
-        assert_eq!(post_order_traversal.next(), Some(3));
-        assert_eq!(post_order_traversal.next(), None);
+    #[test]
+    fn successfully_get_zipf_expected_cost() {
+        let balanced_bst = RecursiveBST::from(vec![4, 2, 6, 1, 3, 5, 7]);
+        let skewed_bst = RecursiveBST::from(vec![1, 2, 3, 4, 6, 5, 7]);
+
+        let balanced_cost = balanced_bst.zipf_expected_cost(1.5).unwrap();
+        let skewed_cost = skewed_bst.zipf_expected_cost(1.5).unwrap();
+        assert!(skewed_cost < balanced_cost);
+
+        let uniform_cost = balanced_bst.zipf_expected_cost(0.0).unwrap();
+        let expected_visits = balanced_bst.expected_search_visits().unwrap();
+        assert!((uniform_cost - expected_visits).abs() < 1e-9);
     }
 
     #[test]
-    fn into_level_order_iter_with_no_elements() {
-        let bst: RecursiveBST<i32> = RecursiveBST::new();
+    fn zipf_expected_cost_of_empty_tree_or_nan_exponent() {
+        assert_eq!(RecursiveBST::<i32>::new().zipf_expected_cost(1.0), None);
+        assert_eq!(
+            RecursiveBST::from(vec![1]).zipf_expected_cost(f64::NAN),
+            None
+        );
+        assert_eq!(
+            RecursiveBST::from(vec![1]).zipf_expected_cost(2.0),
+            Some(1.0)
+        );
+    }
 
-        let mut level_order_traversal = bst.into_level_order_iter();
+    #[test]
+    fn successfully_get_union_intersection_and_difference() {
+        let bst = RecursiveBST::from(vec![1, 2, 3]);
+        let other_bst = RecursiveBST::from(vec![3, 4, 5]);
+
+        let union = bst.union(&other_bst);
+        assert_eq!(union.size(), 5);
+        assert_eq!(union.asc_order_vec(), vec![&1, &2, &3, &4, &5]);
+        assert_eq!(bst.intersection(&other_bst).asc_order_vec(), vec![&3]);
+        assert_eq!(bst.difference(&other_bst).asc_order_vec(), vec![&1, &2]);
+        assert_eq!(bst.size(), 3);
+        assert_eq!(other_bst.size(), 3);
+    }
 
-        assert_eq!(level_order_traversal.next(), None);
+    #[test]
+    fn set_operations_with_disjoint_and_identical_trees() {
+        let bst = RecursiveBST::from(vec![2, 1, 3]);
+        let disjoint_bst = RecursiveBST::from(vec![5, 4, 6]);
+
+        assert_eq!(bst.union(&disjoint_bst).size(), 6);
+        assert!(bst.intersection(&disjoint_bst).is_empty());
+        assert_eq!(bst.difference(&disjoint_bst), bst);
+
+        assert_eq!(bst.union(&bst), bst);
+        assert_eq!(bst.intersection(&bst), bst);
+        assert!(bst.difference(&bst).is_empty());
     }
 
     #[test]
-    fn into_level_order_iter_with_one_element() {
-        let mut bst = RecursiveBST::new();
-        bst.insert(3);
+    fn set_operations_with_empty_tree() {
+        let bst = RecursiveBST::from(vec![2, 1, 3]);
+        let empty_bst = RecursiveBST::new();
+
+        assert_eq!(bst.union(&empty_bst), bst);
+        assert_eq!(empty_bst.union(&bst), bst);
+        assert!(bst.intersection(&empty_bst).is_empty());
+        assert_eq!(bst.difference(&empty_bst), bst);
+        assert!(empty_bst.difference(&bst).is_empty());
+        assert_eq!(empty_bst.union(&empty_bst).height(), None);
+    }
 
-        let mut level_order_traversal = bst.into_level_order_iter();
+    #[test]
+    fn successfully_get_level_width_bars() {
+        let bst = RecursiveBST::from(vec![4, 2, 6, 1, 3, 5, 7]);
 
-        assert_eq!(level_order_traversal.next(), Some(3));
-        assert_eq!(level_order_traversal.next(), None);
+        assert_eq!(bst.level_width_bars(), "#\n##\n####");
     }
 
     #[test]
-    fn into_level_order_iter_with_many_elements() {
+    fn level_width_bars_of_empty_tree_and_chain() {
+        assert_eq!(RecursiveBST::<i32>::new().level_width_bars(), "");
+        assert_eq!(
+            RecursiveBST::from(vec![1, 2, 3]).level_width_bars(),
+            "#\n#\n#"
+        );
+    }
+
+    #[test]
+    fn successfully_check_is_balanced() {
         let mut bst = RecursiveBST::new();
-        bst.insert(3);
-        bst.insert(5);
-        bst.insert(4);
-        bst.insert(1);
-        bst.insert(2);
+        assert!(bst.is_balanced());
 
-        let mut level_order_traversal = bst.into_level_order_iter();
+        bst.extend(vec![4, 2, 6, 1, 3, 5]);
+        assert!(bst.is_balanced());
 
-        assert_eq!(level_order_traversal.next(), Some(3));
-        assert_eq!(level_order_traversal.next(), Some(1));
-        assert_eq!(level_order_traversal.next(), Some(5));
-        assert_eq!(level_order_traversal.next(), Some(2));
-        assert_eq!(level_order_traversal.next(), Some(4));
-        assert_eq!(level_order_traversal.next(), None);
+        bst.insert(0);
+        assert!(bst.is_balanced());
+
+        bst.insert(-1);
+        assert!(!bst.is_balanced());
     }
 
     #[test]
-    fn successfully_get_pre_order_vec() {
-        let mut bst = RecursiveBST::new();
-        assert!(bst.pre_order_vec().is_empty());
+    fn successfully_balance_tree() {
+        let mut bst = RecursiveBST::from_iter(1..=15);
+        assert_eq!(bst.height(), Some(14));
+        assert!(!bst.is_balanced());
 
-        bst.insert(3);
-        bst.insert(4);
-        bst.insert(5);
-        bst.insert(1);
-        bst.insert(2);
+        bst.balance();
 
-        assert_eq!(bst.pre_order_vec(), vec![&3, &1, &2, &4, &5]);
+        assert_eq!(bst.height(), Some(3));
+        assert!(bst.is_balanced());
+        assert_eq!(bst.size(), 15);
+        assert_eq!(
+            bst.asc_order_vec(),
+            (1..=15).collect::<Vec<i32>>().iter().collect::<Vec<&i32>>()
+        );
+        assert_eq!(bst.select(7), Some(&8));
     }
 
+    #[cfg(feature = "rayon")]
     #[test]
-    fn successfully_get_in_order_vec() {
-        let mut bst = RecursiveBST::new();
-        assert!(bst.in_order_vec().is_empty());
+    fn successfully_par_from_iter_with_duplicates() {
+        let elements: Vec<i32> = (0..10_000).map(|i| (i * 7919) % 1_000).collect();
 
-        bst.insert(3);
-        bst.insert(4);
-        bst.insert(5);
-        bst.insert(1);
-        bst.insert(2);
+        let sequential_bst = RecursiveBST::from_iter(elements.clone());
+        let parallel_bst = RecursiveBST::par_from_iter(elements);
 
-        assert_eq!(bst.in_order_vec(), vec![&1, &2, &3, &4, &5]);
+        assert_eq!(parallel_bst.size(), 1_000);
+        assert_eq!(parallel_bst.asc_order_vec(), sequential_bst.asc_order_vec());
+        assert_eq!(parallel_bst.rejected_duplicates(), 9_000);
+        assert!(parallel_bst.is_balanced());
     }
 
     #[test]
-    fn successfully_get_post_order_vec() {
-        let mut bst = RecursiveBST::new();
-        assert!(bst.post_order_vec().is_empty());
+    fn successfully_get_diameter() {
+        let balanced_bst = RecursiveBST::from(vec![4, 2, 6, 1, 3, 5, 7]);
+        let chain_bst = RecursiveBST::from(vec![1, 2, 3, 4, 5]);
+        let bst = RecursiveBST::from(vec![8, 3, 10, 1, 6, 14, 4, 7, 13]);
+
+        assert_eq!(balanced_bst.diameter(), 4);
+        assert_eq!(chain_bst.diameter(), 4);
+        assert_eq!(bst.diameter(), 6);
+    }
 
-        bst.insert(3);
-        bst.insert(4);
-        bst.insert(5);
-        bst.insert(1);
-        bst.insert(2);
+    #[test]
+    fn diameter_not_passing_through_root() {
+        let bst = RecursiveBST::from(vec![10, 5, 3, 7, 2, 4, 6, 8, 1]);
 
-        assert_eq!(bst.post_order_vec(), vec![&2, &1, &5, &4, &3]);
+        assert_eq!(bst.diameter(), 5);
+        assert_eq!(RecursiveBST::<i32>::new().diameter(), 0);
+        assert_eq!(RecursiveBST::from(vec![1]).diameter(), 0);
     }
 
     #[test]
-    fn successfully_get_level_order_vec() {
-        let mut bst = RecursiveBST::new();
-        assert!(bst.level_order_vec().is_empty());
+    fn successfully_get_lowest_common_ancestor() {
+        let bst = RecursiveBST::from(vec![8, 3, 10, 1, 6, 14]);
 
-        bst.insert(15);
-        bst.insert(20);
-        bst.insert(10);
-        bst.insert(8);
-        bst.insert(12);
-        bst.insert(16);
-        bst.insert(25);
+        assert_eq!(bst.lowest_common_ancestor(&1, &6), Some(&3));
+        assert_eq!(bst.lowest_common_ancestor(&1, &14), Some(&8));
+        assert_eq!(bst.lowest_common_ancestor(&14, &10), Some(&10));
+        assert_eq!(bst.lowest_common_ancestor(&6, &6), Some(&6));
+    }
+
+    #[test]
+    fn lowest_common_ancestor_of_missing_value_is_none() {
+        let bst = RecursiveBST::from(vec![8, 3, 10, 1, 6, 14]);
 
+        assert_eq!(bst.lowest_common_ancestor(&1, &7), None);
+        assert_eq!(bst.lowest_common_ancestor(&0, &14), None);
         assert_eq!(
-            bst.level_order_vec(),
-            vec![&15, &10, &20, &8, &12, &16, &25]
+            RecursiveBST::<i32>::new().lowest_common_ancestor(&1, &1),
+            None
         );
     }
 
     #[test]
-    fn successfully_create_bst_from_vec() {
-        let mut expected_bst = RecursiveBST::new();
-        expected_bst.insert(10);
-        expected_bst.insert(20);
-        expected_bst.insert(5);
-        expected_bst.insert(30);
+    fn successfully_remove_all() {
+        let mut bst = RecursiveBST::from(vec![1, 2, 3]);
 
-        let actual_bst = RecursiveBST::from(vec![10, 20, 5, 30]);
+        assert_eq!(bst.remove_all(&[2, 2, 99]), 1);
+        assert_eq!(bst.size(), 2);
+        assert_eq!(bst.asc_order_vec(), vec![&1, &3]);
+        assert_eq!(bst.rank(&3), 1);
 
-        assert_eq!(actual_bst, expected_bst);
+        assert_eq!(bst.remove_all(&[]), 0);
+        assert_eq!(bst.remove_all(&[3, 1]), 2);
+        assert!(bst.is_empty());
     }
 
     #[test]
-    fn successfully_create_bst_from_slice() {
-        let mut expected_bst = RecursiveBST::new();
-        expected_bst.insert(10);
-        expected_bst.insert(20);
-        expected_bst.insert(5);
-        expected_bst.insert(30);
-
-        let actual_bst = RecursiveBST::from(vec![10, 20, 5, 30].as_slice());
+    fn successfully_consume_in_order_iter_from_both_ends() {
+        let bst = RecursiveBST::from(vec![4, 2, 6, 1, 3, 5, 7]);
+        let mut in_order_iter = bst.in_order_iter();
 
-        assert_eq!(actual_bst, expected_bst);
+        assert_eq!(in_order_iter.len(), 7);
+        assert_eq!(in_order_iter.next(), Some(&1));
+        assert_eq!(in_order_iter.next_back(), Some(&7));
+        assert_eq!(in_order_iter.next_back(), Some(&6));
+        assert_eq!(in_order_iter.next(), Some(&2));
+        assert_eq!(in_order_iter.len(), 3);
+        assert_eq!(in_order_iter.next_back(), Some(&5));
+        assert_eq!(in_order_iter.next(), Some(&3));
+        assert_eq!(in_order_iter.next(), Some(&4));
+        assert_eq!(in_order_iter.next(), None);
+        assert_eq!(in_order_iter.next_back(), None);
     }
 
     #[test]
-    fn successfully_create_bst_from_into_vec() {
-        let mut expected_bst = RecursiveBST::new();
-        expected_bst.insert(10);
-        expected_bst.insert(20);
-        expected_bst.insert(5);
-        expected_bst.insert(30);
-
-        let actual_bst: RecursiveBST<i32> = vec![10, 20, 5, 30].into();
+    fn successfully_reverse_in_order_iter() {
+        let bst = RecursiveBST::from(vec![8, 3, 10, 1, 6, 14, 4, 7, 13]);
+        let mut expected = bst.asc_order_vec();
+        expected.reverse();
 
-        assert_eq!(actual_bst, expected_bst);
+        assert_eq!(bst.asc_order_iter().rev().collect::<Vec<&i32>>(), expected);
+        assert_eq!(RecursiveBST::<i32>::new().in_order_iter().next_back(), None);
     }
 
     #[test]
-    fn successfully_extend_bst_from_iter() {
-        let vec = vec![8, 1, 10];
-        let mut expected_bst = RecursiveBST::new();
-        expected_bst.insert(3);
-        expected_bst.insert(2);
-        expected_bst.insert(5);
-        expected_bst.insert(8);
-        expected_bst.insert(1);
-        expected_bst.insert(10);
-        let mut actual_bst = RecursiveBST::new();
-        actual_bst.insert(3);
-        actual_bst.insert(2);
-        actual_bst.insert(5);
+    fn successfully_try_insert() {
+        let mut bst = RecursiveBST::new();
 
-        actual_bst.extend(vec.into_iter());
+        assert_eq!(bst.try_insert(String::from("b")), Ok(()));
+        assert_eq!(bst.try_insert(String::from("a")), Ok(()));
 
-        assert_eq!(actual_bst.size(), 6);
-        assert_eq!(actual_bst, expected_bst);
+        let duplicate = bst.try_insert(String::from("b")).unwrap_err();
+        assert_eq!(duplicate, "b");
+        assert_eq!(bst.try_insert(duplicate + "c"), Ok(()));
+
+        assert_eq!(bst.size(), 3);
+        assert_eq!(bst.rejected_duplicates(), 1);
+        assert_eq!(bst.asc_order_vec(), vec!["a", "b", "bc"]);
     }
 
     #[test]
-    fn successfully_create_bst_from_iter() {
-        let mut expected_bst = RecursiveBST::new();
-        expected_bst.insert(3);
-        expected_bst.insert(2);
-        expected_bst.insert(5);
-        expected_bst.insert(8);
-        expected_bst.insert(1);
-        expected_bst.insert(10);
+    fn successfully_get_width() {
+        let balanced_bst = RecursiveBST::from(vec![4, 2, 6, 1, 3, 5, 7]);
+        let bst = RecursiveBST::from(vec![8, 3, 10, 1, 6, 14, 4, 7, 13]);
 
-        let actual_bst = RecursiveBST::from_iter(vec![3, 2, 5, 8, 1, 10].into_iter());
-
-        assert_eq!(actual_bst, expected_bst);
+        assert_eq!(balanced_bst.width(), 4);
+        assert_eq!(bst.width(), 3);
     }
 
     #[test]
-    fn successfully_clone_bst() {
-        let mut expected_bst = RecursiveBST::new();
-        expected_bst.insert(3);
-        expected_bst.insert(2);
-        expected_bst.insert(5);
-        expected_bst.insert(8);
-        expected_bst.insert(1);
-        expected_bst.insert(10);
+    fn width_of_skewed_tree() {
+        let bst = RecursiveBST::from_iter(1..=10);
 
-        let cloned_bst = expected_bst.clone();
+        assert_eq!(bst.width(), 1);
+        assert_eq!(RecursiveBST::<i32>::new().width(), 0);
+    }
 
-        assert_eq!(cloned_bst, expected_bst);
+    #[test]
+    fn successfully_mutate_elements_through_in_order_iter_mut() {
+        let mut bst = RecursiveBST::from(vec![8, 3, 10, 1, 6, 14, 4, 7, 13]);
+        let mut in_order_iter_mut = bst.in_order_iter_mut();
+        assert_eq!(in_order_iter_mut.len(), 9);
+        assert_eq!(in_order_iter_mut.next(), Some(&mut 1));
+
+        for value in bst.in_order_iter_mut() {
+            *value += 1;
+        }
+
+        assert_eq!(
+            bst.asc_order_vec(),
+            vec![&2, &4, &5, &7, &8, &9, &11, &14, &15]
+        );
+        assert!(bst.contains(&15));
+        assert!(!bst.contains(&1));
+        assert_eq!(RecursiveBST::<i32>::new().in_order_iter_mut().next(), None);
     }
 
     #[test]
-    fn successfully_clone_into_another_bst() {
-        let mut actual_bst = RecursiveBST::new();
-        actual_bst.insert(3);
-        actual_bst.insert(2);
-        let mut expected_bst = RecursiveBST::new();
-        expected_bst.insert(3);
-        expected_bst.insert(2);
-        expected_bst.insert(5);
-        expected_bst.insert(8);
-        expected_bst.insert(1);
-        expected_bst.insert(10);
-        assert_ne!(actual_bst, expected_bst);
+    fn successfully_create_bst_from_sorted_slice() {
+        let elements = (1..=7).collect::<Vec<i32>>();
+        let bst = RecursiveBST::from_sorted_slice(&elements);
 
-        actual_bst.clone_from(&expected_bst);
+        assert_eq!(bst.height(), Some(2));
+        assert_eq!(bst.size(), 7);
+        assert_eq!(bst.in_order_vec(), elements.iter().collect::<Vec<&i32>>());
+        assert_eq!(bst.select(3), Some(&4));
 
-        assert_eq!(actual_bst, expected_bst);
+        let empty_bst = RecursiveBST::<i32>::from_sorted_slice(&[]);
+        assert!(empty_bst.is_empty());
+        assert_eq!(empty_bst.height(), None);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "elements must be strictly ascending")]
+    fn from_sorted_slice_with_unsorted_elements() {
+        RecursiveBST::from_sorted_slice(&[1, 3, 2]);
     }
 }
@@ -0,0 +1,31 @@
+/// Statistics gathered while building a tree from a sequence of elements.
+///
+/// See [IterativeBST::from_iter_validated()](crate::IterativeBST::from_iter_validated()).
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::{BuildReport, IterativeBST};
+///
+/// let (_, report) = IterativeBST::from_iter_validated(vec![1, 2, 2, 3]);
+///
+/// assert_eq!(
+///     report,
+///     BuildReport {
+///         total: 4,
+///         duplicates: 1,
+///         out_of_order: 0
+///     }
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuildReport {
+    /// The number of elements that were given.
+    pub total: usize,
+    /// The number of elements that were dropped because they already existed within the tree.
+    pub duplicates: usize,
+    /// The number of elements that are smaller than the element given right before them.
+    ///
+    /// This is `0` for input given in ascending order.
+    pub out_of_order: usize,
+}
@@ -0,0 +1,12 @@
+/// The direction of a **rotation** around a pivot node.
+///
+/// Rotations restructure a tree without changing the order of its elements.
+///
+/// See [rotate()](crate::BinarySearchTree::rotate()).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rotation {
+    /// The right child of the pivot takes its place and the pivot becomes its left child.
+    Left,
+    /// The left child of the pivot takes its place and the pivot becomes its right child.
+    Right,
+}
@@ -0,0 +1,3332 @@
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Add, Sub};
+use std::sync::Arc;
+use std::vec::IntoIter;
+
+use crate::fingerprint::fnv1a;
+use crate::BinarySearchTree;
+use crate::FrozenBST;
+use crate::FrozenIter;
+use crate::HeapNode;
+use crate::InOrderIter;
+use crate::InOrderIterMut;
+use crate::IterativeBST;
+use crate::Node;
+use crate::Rotation;
+use crate::StableBytes;
+use crate::TooTall;
+
+/// Splay Tree implementation of a Binary Search Tree.
+///
+/// Every insertion, removal and [find_splay()](BinarySearchTree::find_splay()) moves the accessed
+/// element to the root through a series of **zig**, **zig-zig** and **zig-zag** rotations. This
+/// gives amortised `O(log n)` operations and keeps frequently accessed elements close to the root,
+/// which makes it well suited for skewed access patterns.
+///
+/// # Important
+///
+/// Lookups through `&self` (such as [contains()](BinarySearchTree::contains())) cannot
+/// restructure the tree and therefore do not splay.
+#[derive(Debug)]
+pub struct SplayBST<T: Ord> {
+    root: HeapNode<T>,
+    size: usize,
+    rejected_duplicates: usize,
+}
+
+impl<T: Ord> SplayBST<T> {
+    /// Creates an empty `SplayBST<T>`
+    ///
+    /// No nodes are allocated on the heap yet
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// // Empty tree is created
+    /// let mut bst: SplayBST<i32> = SplayBST::new();
+    /// assert!(bst.is_empty())
+    /// ```
+    pub fn new() -> SplayBST<T> {
+        SplayBST {
+            root: None,
+            size: 0,
+            rejected_duplicates: 0,
+        }
+    }
+}
+
+impl<T: Ord> Default for SplayBST<T> {
+    /// Creates an empty `SplayBST<T>`
+    fn default() -> SplayBST<T> {
+        SplayBST::new()
+    }
+}
+
+impl<T: Ord> PartialEq for SplayBST<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.asc_order_vec() == other.asc_order_vec()
+    }
+}
+
+impl<T: Ord> Extend<T> for SplayBST<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter.into_iter() {
+            self.insert(value)
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for SplayBST<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut bst = SplayBST::new();
+        bst.extend(iter);
+        bst
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for SplayBST<T> {
+    fn from(vec: Vec<T>) -> Self {
+        let mut bst = SplayBST::new();
+        for value in vec.into_iter() {
+            bst.insert(value);
+        }
+        bst
+    }
+}
+
+impl<T: Ord + Clone> From<&[T]> for SplayBST<T> {
+    fn from(slice: &[T]) -> Self {
+        let mut bst = SplayBST::new();
+        for value in slice {
+            bst.insert((*value).clone());
+        }
+        bst
+    }
+}
+
+impl<T: Ord> Drop for SplayBST<T> {
+    fn drop(&mut self) {
+        Node::drop_iteratively(self.root.take());
+    }
+}
+
+impl<T: Ord> IntoIterator for SplayBST<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Consumes the tree and returns an iterator over its elements in **ascending order**, the
+    /// same as [into_in_order_iter](BinarySearchTree::into_in_order_iter()).
+    fn into_iter(self) -> IntoIter<T> {
+        self.into_in_order_iter()
+    }
+}
+
+impl<'a, T: Ord> IntoIterator for &'a SplayBST<T> {
+    type Item = &'a T;
+    type IntoIter = InOrderIter<'a, T>;
+
+    /// Returns an iterator over references to the elements in **ascending order**, the same as
+    /// [in_order_iter](BinarySearchTree::in_order_iter()).
+    fn into_iter(self) -> InOrderIter<'a, T> {
+        self.in_order_iter()
+    }
+}
+
+impl<T: Ord + Clone> Clone for SplayBST<T> {
+    fn clone(&self) -> Self {
+        let mut bst = SplayBST::new();
+
+        for value in self.in_order_iter() {
+            bst.insert((*value).clone());
+        }
+
+        bst
+    }
+}
+
+impl<T: Ord + Debug> Display for SplayBST<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.asc_order_vec())
+    }
+}
+
+impl<T: Ord> BinarySearchTree<T> for SplayBST<T> {
+    /// Returns the total **number of nodes** within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(5);
+    /// bst.insert(10);
+    /// bst.insert(3);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// ```
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the binary search tree contains no nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst: SplayBST<i32> = SplayBST::new();
+    /// assert!(bst.is_empty());
+    /// ```
+    fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Returns `true` if the binary search tree contains one or more nodes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// assert!(bst.is_empty());
+    ///
+    /// bst.insert(2);
+    ///
+    /// assert!(bst.is_not_empty());
+    /// ```
+    fn is_not_empty(&self) -> bool {
+        self.size != 0
+    }
+
+    /// Inserts given value as a node and splays it to the root.
+    ///
+    /// **Duplicate values are _not allowed_**.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    ///
+    /// bst.insert(10);
+    /// bst.insert(10);   // Element is not inserted
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(15);
+    /// bst.insert(25);
+    ///
+    /// assert_eq!(bst.size(), 5);
+    /// ```
+    fn insert(&mut self, value: T) {
+        if Node::splay_insert(&mut self.root, value).is_ok() {
+            self.size += 1;
+        } else {
+            self.rejected_duplicates += 1;
+        }
+    }
+
+    /// Returns `true` if the binary search tree contains an element with the given value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert!(bst.contains(&5));
+    /// assert!(!bst.contains(&10));
+    /// ```
+    fn contains(&self, value: &T) -> bool {
+        Node::iterative_contains(&self.root, value)
+    }
+
+    /// Removes the given value after splaying it to the root.
+    ///
+    /// If the element does not exist, the last element accessed during the search is splayed to
+    /// the root instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// assert_eq!(bst.size(), 3);
+    ///
+    /// bst.remove(&5);
+    /// bst.remove(&10); // Element is not removed
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove(&mut self, value: &T) {
+        if Node::splay_remove(&mut self.root, value).is_ok() {
+            self.size -= 1;
+        }
+    }
+
+    /// Returns a reference to the element or `None` if element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert_eq!(bst.retrieve(&5), Some(&5));
+    /// assert_eq!(bst.retrieve(&10), None);
+    /// ```
+    fn retrieve(&self, value: &T) -> Option<&T> {
+        Node::iterative_retrieve(&self.root, value)
+    }
+
+    /// Returns a mutable reference to the element (see [SplayBST::retrieve()])
+    /// or `None` if element does not exist.
+    ///
+    /// The element is splayed to the root, therefore changing it in a way that breaks the
+    /// ordering of the tree is a logic error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(10);
+    /// bst.insert(5);
+    ///
+    /// let optional_retrieved_value_as_mut = bst.retrieve_as_mut(&5);
+    /// assert_eq!(optional_retrieved_value_as_mut, Some(&mut 5));
+    ///
+    /// let mut retrieved_value = optional_retrieved_value_as_mut.unwrap();
+    /// *retrieved_value = 2; // Change value inside tree to '2'
+    ///
+    /// assert_eq!(bst.retrieve_as_mut(&5), None); // 5 does not exist anymore
+    /// assert_eq!(bst.retrieve_as_mut(&2), Some(&mut 2));
+    /// ```
+    fn retrieve_as_mut(&mut self, value: &T) -> Option<&mut T> {
+        Node::splay_retrieve_as_mut(&mut self.root, value)
+    }
+
+    /// Returns the **height** or `None` if tree is empty.
+    ///
+    /// The height is the number of edges between the root and it's furthest leaf node.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = SplayBST::new();
+    /// assert_eq!(bst.height(), None);
+    ///
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    /// bst.rebalance();
+    ///
+    /// // The height is 2.
+    /// assert_eq!(bst.height(), Some(2));
+    /// ```
+    fn height(&self) -> Option<isize> {
+        self.root
+            .as_ref()
+            .map(|_| Node::iterative_height(&self.root))
+    }
+
+    /// Returns a reference to the minimum element of the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// assert_eq!(bst.min(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.min(), Some(&2));
+    /// ```
+    fn min(&self) -> Option<&T> {
+        Node::iterative_min(&self.root)
+    }
+
+    /// Returns a reference to the maximum element of the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// assert_eq!(bst.max(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.max(), Some(&10));
+    /// ```
+    fn max(&self) -> Option<&T> {
+        Node::iterative_max(&self.root)
+    }
+
+    /// Removes and returns the minimum element from the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// assert_eq!(bst.remove_min(), None);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.remove_min(), Some(2));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove_min(&mut self) -> Option<T> {
+        let removed_min = Node::iterative_remove_min(&mut self.root);
+        if removed_min.is_some() {
+            self.size -= 1;
+        }
+        removed_min
+    }
+
+    /// Removes and returns the maximum element from the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// assert_eq!(bst.remove_max(), None);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.remove_max(), Some(10));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove_max(&mut self) -> Option<T> {
+        let removed_max = Node::iterative_remove_max(&mut self.root);
+        if removed_max.is_some() {
+            self.size -= 1;
+        }
+        removed_max
+    }
+
+    /// Returns references to the elements of the tree in **ascending order.**`
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [SplayBST::in_order_vec()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    /// bst.rebalance();
+    ///
+    /// assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3, &4, &5, &6, &7]);
+    /// ```
+    fn asc_order_vec(&self) -> Vec<&T> {
+        self.in_order_vec()
+    }
+
+    /// Returns references to the elements of the tree in the order of a **pre-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    /// bst.rebalance();
+    ///
+    /// // The pre_order_vec is: [&4, &2, &1, &3, &6, &5, &7]
+    /// assert_eq!(bst.pre_order_vec(), vec![&4, &2, &1, &3, &6, &5, &7]);
+    /// ```
+    fn pre_order_vec(&self) -> Vec<&T> {
+        Node::iterative_pre_order_vec(&self.root)
+    }
+
+    /// Returns references to the elements of the tree in the order of an **in-order traversal.**
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [SplayBST::asc_order_vec()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    /// bst.rebalance();
+    ///
+    /// // The in_order_vec is: [&1, &2, &3, &4, &5, &6, &7]
+    /// assert_eq!(bst.in_order_vec(), vec![&1, &2, &3, &4, &5, &6, &7]);
+    /// ```
+    fn in_order_vec(&self) -> Vec<&T> {
+        Node::iterative_in_order_vec(&self.root)
+    }
+
+    /// Returns references to the elements of the tree in the order of a **post-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    /// bst.rebalance();
+    ///
+    /// // The post_order_vec is: [&1, &3, &2, &5, &7, &6, &4]
+    /// assert_eq!(bst.post_order_vec(), vec![&1, &3, &2, &5, &7, &6, &4]);
+    /// ```
+    fn post_order_vec(&self) -> Vec<&T> {
+        Node::iterative_post_order_vec(&self.root)
+    }
+
+    /// Returns references to the elements of the tree in the order of a **level-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    /// bst.rebalance();
+    ///
+    /// // The level_order_vec is: [&4, &2, &6, &1, &3, &5, &7]
+    /// assert_eq!(bst.level_order_vec(), vec![&4, &2, &6, &1, &3, &5, &7]);
+    /// ```
+    fn level_order_vec(&self) -> Vec<&T> {
+        Node::iterative_level_order_vec(&self.root)
+    }
+
+    /// Returns an iterator over [SplayBST::asc_order_vec()].
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [SplayBST::in_order_iter()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut asc_order_iter = bst.asc_order_iter();
+    ///
+    /// assert_eq!(asc_order_iter.next(), Some(&1));
+    /// assert_eq!(asc_order_iter.next(), Some(&2));
+    /// assert_eq!(asc_order_iter.next(), Some(&3));
+    /// assert_eq!(asc_order_iter.next(), Some(&4));
+    /// assert_eq!(asc_order_iter.next(), Some(&5));
+    /// assert_eq!(asc_order_iter.next(), None);
+    /// ```
+    fn asc_order_iter(&self) -> InOrderIter<'_, T> {
+        self.in_order_iter()
+    }
+
+    /// Returns an iterator over [SplayBST::pre_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut pre_order_iter = bst.pre_order_iter();
+    ///
+    /// assert_eq!(pre_order_iter.next(), Some(&2));
+    /// assert_eq!(pre_order_iter.next(), Some(&1));
+    /// assert_eq!(pre_order_iter.next(), Some(&3));
+    /// assert_eq!(pre_order_iter.next(), Some(&4));
+    /// assert_eq!(pre_order_iter.next(), Some(&5));
+    /// assert_eq!(pre_order_iter.next(), None);
+    /// ```
+    fn pre_order_iter(&self) -> IntoIter<&T> {
+        Node::iterative_pre_order_vec(&self.root).into_iter()
+    }
+
+    /// Returns an iterator over [SplayBST::in_order_vec()].
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [SplayBST::asc_order_iter()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut in_order_iter = bst.in_order_iter();
+    ///
+    /// assert_eq!(in_order_iter.next(), Some(&1));
+    /// assert_eq!(in_order_iter.next(), Some(&2));
+    /// assert_eq!(in_order_iter.next(), Some(&3));
+    /// assert_eq!(in_order_iter.next(), Some(&4));
+    /// assert_eq!(in_order_iter.next(), Some(&5));
+    /// assert_eq!(in_order_iter.next(), None);
+    /// ```
+    fn in_order_iter(&self) -> InOrderIter<'_, T> {
+        InOrderIter::new(&self.root)
+    }
+
+    /// Returns an iterator over [SplayBST::post_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut post_order_iter = bst.post_order_iter();
+    ///
+    /// assert_eq!(post_order_iter.next(), Some(&1));
+    /// assert_eq!(post_order_iter.next(), Some(&5));
+    /// assert_eq!(post_order_iter.next(), Some(&4));
+    /// assert_eq!(post_order_iter.next(), Some(&3));
+    /// assert_eq!(post_order_iter.next(), Some(&2));
+    /// assert_eq!(post_order_iter.next(), None);
+    /// ```
+    fn post_order_iter(&self) -> IntoIter<&T> {
+        Node::iterative_post_order_vec(&self.root).into_iter()
+    }
+
+    /// Returns an iterator over [SplayBST::level_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut level_order_iter = bst.level_order_iter();
+    ///
+    /// assert_eq!(level_order_iter.next(), Some(&2));
+    /// assert_eq!(level_order_iter.next(), Some(&1));
+    /// assert_eq!(level_order_iter.next(), Some(&3));
+    /// assert_eq!(level_order_iter.next(), Some(&4));
+    /// assert_eq!(level_order_iter.next(), Some(&5));
+    /// assert_eq!(level_order_iter.next(), None);
+    /// ```
+    fn level_order_iter(&self) -> IntoIter<&T> {
+        Node::iterative_level_order_vec(&self.root).into_iter()
+    }
+
+    /// Returns [SplayBST::asc_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [SplayBST::into_in_order_iter()] as the
+    /// underlying behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_asc_order_iter = bst.into_asc_order_iter();
+    ///
+    /// assert_eq!(into_asc_order_iter.next(), Some(1));
+    /// assert_eq!(into_asc_order_iter.next(), Some(2));
+    /// assert_eq!(into_asc_order_iter.next(), Some(3));
+    /// assert_eq!(into_asc_order_iter.next(), Some(4));
+    /// assert_eq!(into_asc_order_iter.next(), Some(5));
+    /// assert_eq!(into_asc_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_asc_order_iter(self) -> IntoIter<T> {
+        self.into_in_order_iter()
+    }
+
+    /// Returns [SplayBST::pre_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_pre_order_iter = bst.into_pre_order_iter();
+    ///
+    /// assert_eq!(into_pre_order_iter.next(), Some(2));
+    /// assert_eq!(into_pre_order_iter.next(), Some(1));
+    /// assert_eq!(into_pre_order_iter.next(), Some(3));
+    /// assert_eq!(into_pre_order_iter.next(), Some(4));
+    /// assert_eq!(into_pre_order_iter.next(), Some(5));
+    /// assert_eq!(into_pre_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_pre_order_iter(mut self) -> IntoIter<T> {
+        Node::iterative_consume_pre_order_vec(self.root.take()).into_iter()
+    }
+
+    /// Returns [SplayBST::in_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [SplayBST::asc_order_iter()] as the
+    /// underlying behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_in_order_iter = bst.into_in_order_iter();
+    ///
+    /// assert_eq!(into_in_order_iter.next(), Some(1));
+    /// assert_eq!(into_in_order_iter.next(), Some(2));
+    /// assert_eq!(into_in_order_iter.next(), Some(3));
+    /// assert_eq!(into_in_order_iter.next(), Some(4));
+    /// assert_eq!(into_in_order_iter.next(), Some(5));
+    /// assert_eq!(into_in_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_in_order_iter(mut self) -> IntoIter<T> {
+        Node::iterative_consume_in_order_vec(self.root.take()).into_iter()
+    }
+
+    /// Returns [SplayBST::post_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_post_order_iter = bst.into_post_order_iter();
+    ///
+    /// assert_eq!(into_post_order_iter.next(), Some(1));
+    /// assert_eq!(into_post_order_iter.next(), Some(5));
+    /// assert_eq!(into_post_order_iter.next(), Some(4));
+    /// assert_eq!(into_post_order_iter.next(), Some(3));
+    /// assert_eq!(into_post_order_iter.next(), Some(2));
+    /// assert_eq!(into_post_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_post_order_iter(mut self) -> IntoIter<T> {
+        Node::iterative_consume_post_order_vec(self.root.take()).into_iter()
+    }
+
+    /// Returns [SplayBST::level_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_level_order_iter = bst.into_level_order_iter();
+    ///
+    /// assert_eq!(into_level_order_iter.next(), Some(2));
+    /// assert_eq!(into_level_order_iter.next(), Some(1));
+    /// assert_eq!(into_level_order_iter.next(), Some(3));
+    /// assert_eq!(into_level_order_iter.next(), Some(4));
+    /// assert_eq!(into_level_order_iter.next(), Some(5));
+    /// assert_eq!(into_level_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_level_order_iter(mut self) -> IntoIter<T> {
+        Node::iterative_consume_level_order_vec(self.root.take()).into_iter()
+    }
+
+    /// Returns the **canonical key** of the tree, which is the sorted sequence of its elements.
+    ///
+    /// Two trees are considered equal if and only if their canonical keys are equal, regardless
+    /// of the order in which elements were inserted or the resulting shape of the tree.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [SplayBST::asc_order_vec()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![3, 1, 2]);
+    /// let another_bst = SplayBST::from(vec![1, 2, 3]);
+    ///
+    /// assert_eq!(bst.canonical_key(), vec![&1, &2, &3]);
+    /// assert_eq!(bst.canonical_key(), another_bst.canonical_key());
+    /// ```
+    fn canonical_key(&self) -> Vec<&T> {
+        self.asc_order_vec()
+    }
+
+    /// Inserts given value as a node and returns the **depth** at which the new node was placed
+    /// or `None` if the value already exists.
+    ///
+    /// As every inserted element is splayed to the root, the depth is always `0`.
+    ///
+    /// **Duplicate values are _not allowed_**.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    ///
+    /// assert_eq!(bst.insert_with_depth(10), Some(0));
+    /// assert_eq!(bst.insert_with_depth(5), Some(0));
+    /// assert_eq!(bst.insert_with_depth(5), None); // Element is not inserted
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn insert_with_depth(&mut self, value: T) -> Option<usize> {
+        if Node::splay_insert(&mut self.root, value).is_err() {
+            self.rejected_duplicates += 1;
+            return None;
+        }
+        self.size += 1;
+        Some(0)
+    }
+
+    /// Returns the **average depth** of all nodes or `None` if tree is empty.
+    ///
+    /// This is the internal path length (the sum of the depths of all nodes) divided by the
+    /// [size](SplayBST::size()) of the tree, which predicts the average cost of a successful
+    /// search.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = SplayBST::new();
+    /// assert_eq!(bst.average_search_depth(), None);
+    ///
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    /// bst.rebalance();
+    ///
+    /// // The average search depth is 10 / 7.
+    /// assert_eq!(bst.average_search_depth(), Some(10.0 / 7.0));
+    /// ```
+    fn average_search_depth(&self) -> Option<f64> {
+        match self.size {
+            0 => None,
+            size => Some(Node::iterative_internal_path_length(&self.root) as f64 / size as f64),
+        }
+    }
+
+    /// Returns `true` if **every** element of the tree satisfies the given predicate.
+    ///
+    /// The traversal stops as soon as an element that does not satisfy the predicate is found.
+    /// An empty tree always returns `true`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert!(bst.all(|value| *value > 0));
+    /// assert!(!bst.all(|value| *value > 2));
+    /// ```
+    fn all<F: Fn(&T) -> bool>(&self, predicate: F) -> bool {
+        Node::iterative_all(&self.root, predicate)
+    }
+
+    /// Returns `true` if **any** element of the tree satisfies the given predicate.
+    ///
+    /// The traversal stops as soon as an element that satisfies the predicate is found.
+    /// An empty tree always returns `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert!(bst.any(|value| *value == 7));
+    /// assert!(!bst.any(|value| *value > 100));
+    /// ```
+    fn any<F: Fn(&T) -> bool>(&self, predicate: F) -> bool {
+        Node::iterative_any(&self.root, predicate)
+    }
+
+    /// Returns the running `(minimum, maximum)` for every prefix of the elements in
+    /// **ascending order.**
+    ///
+    /// The element at position `i` is the minimum and maximum over the first `i + 1` elements.
+    /// As the elements are sorted, this doubles as a sanity check on the ordering of the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(4);
+    /// bst.insert(2);
+    /// bst.insert(6);
+    ///
+    /// assert_eq!(bst.prefix_extremes(), vec![(&2, &2), (&2, &4), (&2, &6)]);
+    /// ```
+    fn prefix_extremes(&self) -> Vec<(&T, &T)> {
+        Node::prefix_extremes(self.in_order_vec())
+    }
+
+    /// Rebalances the tree **in-place** so that it has the minimum possible height.
+    ///
+    /// This uses the [Day-Stout-Warren](https://en.wikipedia.org/wiki/Day%E2%80%93Stout%E2%80%93Warren_algorithm)
+    /// algorithm which first flattens the tree into a sorted "vine" and then rotates the vine
+    /// back into a balanced tree. No nodes are allocated or de-allocated during this process.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(1);
+    /// bst.insert(2);
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(6);
+    /// bst.insert(7);
+    /// assert_eq!(bst.height(), Some(6));
+    ///
+    /// bst.rebalance();
+    ///
+    /// assert_eq!(bst.height(), Some(2));
+    /// assert_eq!(bst.level_order_vec(), vec![&4, &2, &6, &1, &3, &5, &7]);
+    /// ```
+    fn rebalance(&mut self) {
+        Node::dsw_rebalance(&mut self.root, self.size);
+    }
+
+    /// Returns the number of **rotations** that [SplayBST::rebalance()] would perform
+    /// on the tree **without** modifying it.
+    ///
+    /// The count consists of the right rotations needed to flatten the tree into a vine (every
+    /// node that is not already on the rightmost path from the root) plus the left rotations
+    /// needed to turn the vine back into a balanced tree (which only depends on the size).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(1);
+    /// bst.insert(2);
+    /// bst.insert(3);
+    ///
+    /// // Splaying ascending values builds a left-leaning chain which is first turned into a vine
+    /// assert_eq!(bst.rebalance_cost(), 3);
+    /// ```
+    fn rebalance_cost(&self) -> usize {
+        Node::dsw_rotation_count(&self.root, self.size)
+    }
+
+    /// Returns an **indented outline** of the tree with one element per line in the order of a
+    /// **pre-order traversal.**
+    ///
+    /// Every element is indented by two spaces per level of depth. An empty tree returns an
+    /// empty string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    /// bst.rebalance();
+    ///
+    /// assert_eq!(bst.to_outline(), "4\n  2\n    1\n    3\n  6\n    5\n    7");
+    /// ```
+    fn to_outline(&self) -> String
+    where
+        T: Display,
+    {
+        Node::iterative_outline(&self.root)
+    }
+
+    /// Returns the number of elements `(less than, greater than)` the given value or `None` if
+    /// the value does not exist within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(3);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(4);
+    ///
+    /// assert_eq!(bst.side_counts(&3), Some((2, 2)));
+    /// assert_eq!(bst.side_counts(&5), Some((4, 0)));
+    /// assert_eq!(bst.side_counts(&10), None);
+    /// ```
+    fn side_counts(&self, value: &T) -> Option<(usize, usize)> {
+        if !self.contains(value) {
+            return None;
+        }
+
+        let rank = Node::iterative_rank(&self.root, value);
+        Some((rank, self.size - rank - 1))
+    }
+
+    /// Returns an iterator over the elements of **both** trees in **ascending order.**
+    ///
+    /// The trees are merged lazily and elements that exist within both trees are only
+    /// yielded once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![1, 3, 5]);
+    /// let another_bst = SplayBST::from(vec![2, 3, 4]);
+    ///
+    /// let merged: Vec<&i32> = bst.merge_iter(&another_bst).collect();
+    ///
+    /// assert_eq!(merged, vec![&1, &2, &3, &4, &5]);
+    /// ```
+    fn merge_iter<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        Node::merge_iter(self.in_order_iter(), other.in_order_iter())
+    }
+
+    /// Returns a histogram mapping every **balance factor** within the tree to the number of
+    /// nodes with that balance factor.
+    ///
+    /// The balance factor of a node is the height of its left subtree minus the height of its
+    /// right subtree (where an empty subtree has a height of `-1`). A well balanced tree only
+    /// contains balance factors of `-1`, `0` and `1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         3
+    ///  //       /  \
+    ///  //      2    4
+    ///  //     /
+    ///  //    1
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(1);
+    /// bst.insert(2);
+    /// bst.insert(4);
+    /// bst.insert(3);
+    ///
+    /// assert_eq!(bst.balance_factor_histogram(), HashMap::from([(0, 2), (1, 2)]));
+    /// ```
+    fn balance_factor_histogram(&self) -> HashMap<isize, usize> {
+        Node::iterative_balance_factor_histogram(&self.root)
+    }
+
+    /// Returns the tree as a reference to [Any] so that it can be downcast back into an
+    /// `SplayBST<T>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst: Box<dyn BinarySearchTree<i32>> = Box::new(SplayBST::from(vec![1, 2, 3]));
+    ///
+    /// let iterative_bst = bst.as_any().downcast_ref::<SplayBST<i32>>().unwrap();
+    /// assert_eq!(iterative_bst, &SplayBST::from(vec![1, 2, 3]));
+    /// ```
+    fn as_any(&self) -> &dyn Any
+    where
+        T: 'static,
+    {
+        self
+    }
+
+    /// Returns the ratio between the actual **height** and the optimal height of the tree or
+    /// `None` if tree is empty.
+    ///
+    /// The optimal height of a tree with `n` nodes is `floor(log2(n))`, therefore a score of
+    /// `1.0` means the tree is perfectly efficient and larger scores mean the tree is
+    /// degenerating into a linked list. A tree with a single node always has a score of `1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// assert_eq!(bst.skew_score(), None);
+    ///
+    /// bst.insert(1);
+    /// bst.insert(2);
+    /// bst.insert(3);
+    ///
+    /// // The height is 2 whereas the optimal height is 1
+    /// assert_eq!(bst.skew_score(), Some(2.0));
+    /// ```
+    fn skew_score(&self) -> Option<f64> {
+        self.height()
+            .map(|height| match Node::<T>::optimal_height(self.size) {
+                0 => 1.0,
+                optimal_height => height as f64 / optimal_height as f64,
+            })
+    }
+
+    /// Returns the number of nodes with **exactly one** child.
+    ///
+    /// These are neither leaves nor full internal nodes; a tree with many of them is on its way
+    /// to degenerating into a linked list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(1);
+    /// bst.insert(2);
+    /// bst.insert(4);
+    /// bst.insert(3);
+    ///
+    /// // Only 2 has a single child
+    /// assert_eq!(bst.half_nodes(), 1);
+    /// ```
+    fn half_nodes(&self) -> usize {
+        Node::iterative_half_nodes(&self.root)
+    }
+
+    /// Returns references to the elements on the **boundary** of the tree in anti-clockwise order.
+    ///
+    /// The boundary starts at the root, followed by the left boundary top-down (excluding leaves),
+    /// then all leaves from left to right and finally the right boundary bottom-up (excluding
+    /// leaves). Every element appears exactly once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(4);
+    /// bst.insert(2);
+    /// bst.insert(6);
+    /// bst.insert(1);
+    /// bst.insert(3);
+    /// bst.insert(5);
+    /// bst.insert(7);
+    /// bst.rebalance();
+    ///
+    /// assert_eq!(bst.boundary_vec(), vec![&4, &2, &1, &3, &5, &7, &6]);
+    /// ```
+    fn boundary_vec(&self) -> Vec<&T> {
+        Node::iterative_boundary_vec(&self.root)
+    }
+
+    /// Returns references to the elements grouped by **vertical column** from left to right.
+    ///
+    /// The root is in column `0`, a left child is one column to the left of its parent and a
+    /// right child is one column to the right. Each column lists its elements top-to-bottom, with
+    /// elements on the same row ordered from left to right.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(4);
+    /// bst.insert(2);
+    /// bst.insert(6);
+    /// bst.insert(1);
+    /// bst.insert(3);
+    /// bst.insert(5);
+    /// bst.insert(7);
+    /// bst.rebalance();
+    ///
+    /// assert_eq!(
+    ///     bst.vertical_order(),
+    ///     vec![vec![&1], vec![&2], vec![&4, &3, &5], vec![&6], vec![&7]]
+    /// );
+    /// ```
+    fn vertical_order(&self) -> Vec<Vec<&T>> {
+        Node::iterative_vertical_order(&self.root)
+    }
+
+    /// Returns references to the elements grouped by **diagonal**.
+    ///
+    /// Moving to a right child stays on the same diagonal whereas moving to a left child starts
+    /// the next diagonal. The first diagonal therefore contains the root and its chain of right
+    /// children.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(4);
+    /// bst.insert(2);
+    /// bst.insert(6);
+    /// bst.insert(1);
+    /// bst.insert(3);
+    /// bst.insert(5);
+    /// bst.insert(7);
+    /// bst.rebalance();
+    ///
+    /// assert_eq!(bst.diagonal_vec(), vec![vec![&4, &6, &7], vec![&2, &3, &5], vec![&1]]);
+    /// ```
+    fn diagonal_vec(&self) -> Vec<Vec<&T>> {
+        Node::iterative_diagonal_vec(&self.root)
+    }
+
+    /// Returns the **maximum sum** over all root-to-leaf paths together with the elements on that
+    /// path or `None` if tree is empty.
+    ///
+    /// If several paths share the maximum sum, the leftmost one is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// assert_eq!(bst.max_path_sum_to_leaf(), None);
+    ///
+    /// bst.insert(1);
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(2);
+    ///
+    /// assert_eq!(bst.max_path_sum_to_leaf(), Some((9, vec![&2, &3, &4])));
+    /// ```
+    fn max_path_sum_to_leaf(&self) -> Option<(T, Vec<&T>)>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        Node::iterative_max_path_sum_to_leaf(&self.root)
+    }
+
+    /// Returns a reference to the element at the **center** of the tree or `None` if tree is empty.
+    ///
+    /// The center is the element with the smallest eccentricity, i.e. the smallest greatest
+    /// distance to any other element when edges are treated as undirected. A tree has at most two
+    /// such elements, in which case the smaller one is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// assert_eq!(bst.center(), None);
+    ///
+    /// bst.insert(1);
+    /// bst.insert(2);
+    /// bst.insert(3);
+    ///
+    /// assert_eq!(bst.center(), Some(&2));
+    /// ```
+    fn center(&self) -> Option<&T> {
+        Node::center(&self.root)
+    }
+
+    /// Returns a new **balanced** tree containing clones of the elements within the inclusive range
+    /// `[low, high]`.
+    ///
+    /// An empty tree is returned if `low` is greater than `high`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{bst, BinarySearchTree, SplayBST};
+    ///
+    /// let bst = bst![1, 2, 3, 4, 5];
+    /// let window = bst.clone_range(&2, &4);
+    ///
+    /// assert_eq!(window.asc_order_vec(), vec![&2, &3, &4]);
+    /// assert_eq!(window.height(), Some(1));
+    /// ```
+    fn clone_range(&self, low: &T, high: &T) -> SplayBST<T>
+    where
+        T: Clone,
+    {
+        let elements = Node::iterative_range_vec(&self.root, low, high);
+        let size = elements.len();
+        SplayBST {
+            root: Node::build_balanced(&mut elements.into_iter().cloned(), size),
+            size,
+            rejected_duplicates: 0,
+        }
+    }
+
+    /// Returns the elements of **both** trees aligned by value in **ascending order.**
+    ///
+    /// Elements that exist within both trees are paired as `(Some, Some)` whereas elements that
+    /// only exist within one tree are paired with `None` on the side of the other tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![1, 2, 4]);
+    /// let another_bst = SplayBST::from(vec![2, 3, 4]);
+    ///
+    /// assert_eq!(
+    ///     bst.zip_compare(&another_bst),
+    ///     vec![
+    ///         (Some(&1), None),
+    ///         (Some(&2), Some(&2)),
+    ///         (None, Some(&3)),
+    ///         (Some(&4), Some(&4))
+    ///     ]
+    /// );
+    /// ```
+    fn zip_compare<'a>(&'a self, other: &'a Self) -> Vec<(Option<&'a T>, Option<&'a T>)> {
+        Node::zip_compare(self.in_order_iter(), other.in_order_iter())
+    }
+
+    /// Consumes the tree and returns a read-only [FrozenBST] storing all elements within a
+    /// single contiguous array in **Eytzinger** layout.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(1);
+    /// bst.insert(2);
+    /// bst.insert(3);
+    ///
+    /// let frozen_bst = bst.freeze();
+    ///
+    /// assert!(frozen_bst.contains(&2));
+    /// assert_eq!(frozen_bst.level_order_iter().collect::<Vec<&i32>>(), vec![&2, &1, &3]);
+    /// ```
+    fn freeze(self) -> FrozenBST<T> {
+        FrozenBST::from_sorted(self.into_asc_order_iter().collect())
+    }
+
+    /// Returns the number of elements whose rank would **increase by one** if the given value was
+    /// inserted, i.e. the number of elements strictly greater than the value.
+    ///
+    /// The tree is not modified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(bst.rank_shift_on_insert(&0), 5);
+    /// assert_eq!(bst.rank_shift_on_insert(&3), 2);
+    /// assert_eq!(bst.rank_shift_on_insert(&6), 0);
+    /// ```
+    fn rank_shift_on_insert(&self, value: &T) -> usize {
+        Node::iterative_count_greater(&self.root, value)
+    }
+
+    /// Returns a **deterministic fingerprint** of the elements within the tree.
+    ///
+    /// The fingerprint is computed with FNV-1a over the [StableBytes] of every element in
+    /// ascending order, therefore it only depends on the contents (not the shape) of the tree and
+    /// is stable across process runs and machines.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![1, 2, 3]);
+    /// let another_bst = SplayBST::from(vec![3, 2, 1]);
+    ///
+    /// assert_eq!(bst.fingerprint(), another_bst.fingerprint());
+    /// ```
+    fn fingerprint(&self) -> u64
+    where
+        T: StableBytes,
+    {
+        fnv1a(self.in_order_iter())
+    }
+
+    /// Inserts the elements of a **sorted** slice by linking them into the tree as a single
+    /// balanced subtree.
+    ///
+    /// The subtree can only be linked in if the slice is in strictly ascending order and no
+    /// existing element lies within the range of the slice. Neither is required though: if the
+    /// slice is not strictly ascending or overlaps with the tree, the elements are inserted one at
+    /// a time instead, in both debug and release builds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::from_iter(1..=3);
+    /// bst.splice_sorted(&[6, 7, 8]);
+    ///
+    /// assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3, &6, &7, &8]);
+    /// assert_eq!(bst.pre_order_vec(), vec![&3, &2, &1, &7, &6, &8]);
+    /// ```
+    fn splice_sorted(&mut self, sorted: &[T])
+    where
+        T: Clone,
+    {
+        let is_sorted = sorted.windows(2).all(|pair| pair[0] < pair[1]);
+
+        let (first, last) = match (sorted.first(), sorted.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return,
+        };
+        if !is_sorted || !Node::iterative_range_vec(&self.root, first, last).is_empty() {
+            for value in sorted {
+                self.insert(value.clone());
+            }
+            return;
+        }
+
+        if let Some(subtree) = Node::build_balanced(&mut sorted.iter().cloned(), sorted.len()) {
+            Node::iterative_splice(&mut self.root, subtree);
+            self.size += sorted.len();
+        }
+    }
+
+    /// Returns the number of nodes at the **deepest level** of the tree.
+    ///
+    /// This indicates how filled out the bottom level of the tree is, an empty tree returns `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(4);
+    /// bst.insert(2);
+    /// bst.insert(6);
+    /// bst.insert(1);
+    /// bst.insert(3);
+    ///
+    /// assert_eq!(bst.deepest_level_width(), 2);
+    /// ```
+    fn deepest_level_width(&self) -> usize {
+        Node::iterative_deepest_level_width(&self.root)
+    }
+
+    /// Returns `true` if the cached [size](Self::size()) matches the actual number of nodes
+    /// within the tree.
+    ///
+    /// The nodes are counted through a full traversal, therefore this is intended as a defensive
+    /// check within tests and debug builds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(1);
+    /// bst.insert(2);
+    /// bst.remove(&1);
+    ///
+    /// assert!(bst.verify_size());
+    /// ```
+    fn verify_size(&self) -> bool {
+        Node::iterative_count(&self.root) == self.size
+    }
+
+    /// Counts the actual number of nodes within the tree and repairs the cached
+    /// [size](Self::size()) if it has drifted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::from_iter(1..=5);
+    /// bst.recount();
+    ///
+    /// assert_eq!(bst.size(), 5);
+    /// ```
+    fn recount(&mut self) {
+        self.size = Node::iterative_count(&self.root);
+    }
+
+    /// Returns references to the elements grouped into buckets by the result of the given `key`
+    /// function.
+    ///
+    /// The elements within every bucket are in **ascending order.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![4, 2, 1, 3]);
+    /// let groups = bst.group_by(|value| *value > 2);
+    ///
+    /// assert_eq!(groups[&false], vec![&1, &2]);
+    /// assert_eq!(groups[&true], vec![&3, &4]);
+    /// ```
+    fn group_by<K: Ord, F: Fn(&T) -> K>(&self, key: F) -> BTreeMap<K, Vec<&T>> {
+        self.in_order_iter()
+            .fold(BTreeMap::new(), |mut groups, value| {
+                groups.entry(key(value)).or_default().push(value);
+                groups
+            })
+    }
+
+    /// Returns the number of element removals needed to bring the **height** of the tree down
+    /// to its optimal height, given the current shape of the tree.
+    ///
+    /// This is the number of nodes beyond the optimal-height frontier, i.e. nodes with a depth
+    /// greater than `floor(log2(n))`. Removing exactly these nodes (deepest first) leaves a tree
+    /// whose height is the optimal height of the current tree, whereas a balanced tree requires
+    /// no removals.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from_iter(1..=5);
+    ///
+    /// // The optimal height is 2, therefore 4 and 5 are beyond the frontier
+    /// assert_eq!(bst.removals_to_balance(), 2);
+    /// ```
+    fn removals_to_balance(&self) -> usize {
+        let optimal_height = Node::<T>::optimal_height(self.size);
+        Node::iterative_count_deeper_than(&self.root, optimal_height)
+    }
+
+    /// Returns the number of edges on the **longest zigzag path** within the tree.
+    ///
+    /// A zigzag path travels downwards and alternates between left and right children at
+    /// every step. An empty tree or a tree with a single node returns `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(1);
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(2);
+    ///
+    /// // 2 -> 3 -> 4 never changes direction
+    /// assert_eq!(bst.longest_zigzag(), 1);
+    /// ```
+    fn longest_zigzag(&self) -> usize {
+        Node::iterative_longest_zigzag(&self.root)
+    }
+
+    /// Returns the **cumulative sums** of the elements in ascending order.
+    ///
+    /// The element at index `i` is the sum of the `i + 1` smallest elements within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![3, 1, 4, 2]);
+    ///
+    /// assert_eq!(bst.prefix_sums(), vec![1, 3, 6, 10]);
+    /// ```
+    fn prefix_sums(&self) -> Vec<T>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        Node::prefix_sums(self.in_order_iter())
+    }
+
+    /// Returns the **sum** of all elements within the inclusive range `[low, high]`, given the
+    /// [prefix_sums](Self::prefix_sums()) of the tree.
+    ///
+    /// The bounds of the range are located through rank lookups and the sum is taken as the
+    /// difference between two of the prefix sums, so every query only takes `O(h)` once the prefix
+    /// sums have been computed. `None` is returned if the range is empty or if `prefix_sums` is
+    /// too short to cover it, e.g. because it was computed before elements were inserted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![3, 1, 4, 2]);
+    ///
+    /// let prefix_sums = bst.prefix_sums();
+    ///
+    /// assert_eq!(bst.range_sum(&prefix_sums, &2, &3), Some(5));
+    /// assert_eq!(bst.range_sum(&prefix_sums, &0, &10), Some(10));
+    /// assert_eq!(bst.range_sum(&prefix_sums, &5, &10), None);
+    /// ```
+    fn range_sum(&self, prefix_sums: &[T], low: &T, high: &T) -> Option<T>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        let start = Node::iterative_rank(&self.root, low);
+        let end = self.size - Node::iterative_count_greater(&self.root, high);
+        Node::range_sum(prefix_sums, start, end)
+    }
+
+    /// Returns `true` if inserting the given value would **increase the height** of the tree.
+    ///
+    /// The depth at which the value would be inserted is found through a dry-run descent and
+    /// compared against the current height, without modifying the tree. Inserting into an empty
+    /// tree counts as an increase whereas a value that already exists never increases the height.
+    ///
+    /// The descent does not account for the splaying performed by [insert()](SplayBST::insert()),
+    /// which may restructure the tree in a way that changes the height differently.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(1);
+    /// bst.insert(3);
+    /// bst.insert(2);
+    ///
+    /// assert!(bst.would_grow_height(&4));
+    /// assert!(!bst.would_grow_height(&2)); // Element already exists
+    /// ```
+    fn would_grow_height(&self, value: &T) -> bool {
+        match Node::iterative_insertion_depth(&self.root, value) {
+            None => false,
+            Some(depth) => self.height().is_none_or(|height| depth as isize > height),
+        }
+    }
+
+    /// Returns a **read-only snapshot** of the elements in ascending order.
+    ///
+    /// The elements are cloned once into an `Arc<[T]>` which can be shared (and cheaply cloned)
+    /// across threads, independently of any later changes to the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::from(vec![3, 1, 2]);
+    /// let snapshot = bst.snapshot();
+    ///
+    /// bst.insert(4);
+    ///
+    /// assert_eq!(*snapshot, [1, 2, 3]);
+    /// ```
+    fn snapshot(&self) -> Arc<[T]>
+    where
+        T: Clone,
+    {
+        self.in_order_iter().cloned().collect()
+    }
+
+    /// Returns the number of **distinct** elements within the inclusive range `[low, high]`.
+    ///
+    /// As duplicates are not stored, this is the number of elements within the range, found in
+    /// `O(h)` as the difference between two rank lookups. See
+    /// [CountingBST::distinct_count_in_range()](crate::CountingBST::distinct_count_in_range())
+    /// for a tree that keeps track of repeated elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![5, 1, 3, 9, 7]);
+    ///
+    /// assert_eq!(bst.distinct_count_in_range(&2, &7), 3);
+    /// assert_eq!(bst.distinct_count_in_range(&7, &2), 0);
+    /// ```
+    fn distinct_count_in_range(&self, low: &T, high: &T) -> usize {
+        // Both lookups count the elements outside of the range, which overlap once low > high
+        (self.size - Node::iterative_count_greater(&self.root, high))
+            .saturating_sub(Node::iterative_rank(&self.root, low))
+    }
+
+    /// Rebuilds the tree so that it has **exactly** the given height.
+    ///
+    /// A tree with `n` elements can only have a height between `floor(log2(n))` and `n - 1`,
+    /// [TooTall] is returned (and the tree is left untouched) for any other target.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST, TooTall};
+    ///
+    /// let mut bst = SplayBST::from_iter(1..=7);
+    ///
+    /// assert_eq!(bst.rebalance_to_height(4), Ok(()));
+    /// assert_eq!(bst.height(), Some(4));
+    ///
+    /// assert_eq!(bst.rebalance_to_height(1), Err(TooTall));
+    /// assert_eq!(bst.height(), Some(4));
+    /// ```
+    fn rebalance_to_height(&mut self, target: usize) -> Result<(), TooTall> {
+        if self.size == 0 || target < Node::<T>::optimal_height(self.size) || target > self.size - 1
+        {
+            return Err(TooTall);
+        }
+
+        let elements = Node::iterative_consume_in_order_vec(self.root.take());
+        self.root = Node::build_with_height(&mut elements.into_iter(), self.size, target);
+        Ok(())
+    }
+
+    /// Returns an iterator over the elements in **in-order** paired with `true` if the element
+    /// is a leaf (a node without any children).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(1);
+    /// bst.insert(3);
+    /// bst.insert(2);
+    ///
+    /// let annotated: Vec<(&i32, bool)> = bst.iter_annotated().collect();
+    ///
+    /// assert_eq!(annotated, vec![(&1, true), (&2, false), (&3, true)]);
+    /// ```
+    fn iter_annotated<'a>(&'a self) -> impl Iterator<Item = (&'a T, bool)>
+    where
+        T: 'a,
+    {
+        Node::iterative_iter_annotated(&self.root)
+    }
+
+    /// Rotates the subtree rooted at the given **pivot** element in the given direction.
+    ///
+    /// Returns `true` if the rotation was applied or `false` if the pivot does not exist or it
+    /// lacks the child that would take its place.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST, Rotation};
+    ///
+    /// let mut bst = SplayBST::from_iter(1..=3);
+    ///
+    /// assert!(bst.rotate(&3, Rotation::Right));
+    /// assert_eq!(bst.pre_order_vec(), vec![&2, &1, &3]);
+    ///
+    /// assert!(!bst.rotate(&3, Rotation::Right));
+    /// assert!(!bst.rotate(&5, Rotation::Right));
+    /// ```
+    fn rotate(&mut self, pivot: &T, rotation: Rotation) -> bool {
+        Node::iterative_rotate(&mut self.root, pivot, rotation)
+    }
+
+    /// Returns the ordered list of `(pivot, direction)` rotations that
+    /// [rebalance](Self::rebalance()) would apply, without modifying the tree.
+    ///
+    /// Applying these rotations in order through [rotate](Self::rotate()) balances the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST, Rotation};
+    ///
+    /// let bst = SplayBST::from_iter(1..=3);
+    ///
+    /// assert_eq!(
+    ///     bst.rotations_to_balance(),
+    ///     vec![(3, Rotation::Right), (2, Rotation::Right), (1, Rotation::Left)]
+    /// );
+    /// ```
+    fn rotations_to_balance(&self) -> Vec<(T, Rotation)>
+    where
+        T: Clone,
+    {
+        let mut root = None;
+        for value in self.pre_order_iter() {
+            let _ = Node::iterative_insert(&mut root, value.clone());
+        }
+
+        let mut rotations = Vec::new();
+        Node::dsw_rebalance_with(&mut root, self.size, &mut |pivot: &T, rotation| {
+            rotations.push((pivot.clone(), rotation))
+        });
+        rotations
+    }
+
+    /// Returns a reference to the element or `None` if element does not exist.
+    ///
+    /// The element is **splayed** to the root through rotations, so frequently accessed elements
+    /// become shallow over time. If the element does not exist, the last node reached while
+    /// searching for it is splayed instead, as for every other access of a `SplayBST`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::from_iter(1..=5);
+    ///
+    /// assert_eq!(bst.find_splay(&5), Some(&5));
+    /// assert_eq!(bst.pre_order_vec()[0], &5);
+    ///
+    /// assert_eq!(bst.find_splay(&10), None);
+    /// ```
+    fn find_splay(&mut self, value: &T) -> Option<&T> {
+        Node::iterative_find_splay(&mut self.root, value)
+    }
+
+    /// Returns the **Jaccard similarity** between the elements of both trees.
+    ///
+    /// This is the size of the intersection divided by the size of the union, a value within
+    /// `[0.0, 1.0]`. Both sizes are counted within a single pass over the ascending elements.
+    /// Two empty trees are considered identical and therefore have a similarity of `1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![1, 2, 3]);
+    /// let another_bst = SplayBST::from(vec![2, 3, 4]);
+    ///
+    /// assert_eq!(bst.jaccard(&another_bst), 0.5);
+    /// assert_eq!(SplayBST::<i32>::new().jaccard(&SplayBST::new()), 1.0);
+    /// ```
+    fn jaccard(&self, other: &Self) -> f64 {
+        Node::jaccard(self.in_order_iter(), other.in_order_iter())
+    }
+
+    /// Returns an **owning iterator** over a copy of the elements in ascending order.
+    ///
+    /// The elements are cloned once into a buffer owned by the [FrozenIter], which therefore
+    /// keeps yielding the original elements even if the tree is later modified or dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::from(vec![3, 1, 2]);
+    /// let frozen_iter = bst.frozen_iter();
+    ///
+    /// bst.insert(4);
+    /// bst.remove(&1);
+    ///
+    /// assert_eq!(frozen_iter.collect::<Vec<i32>>(), vec![1, 2, 3]);
+    /// ```
+    fn frozen_iter(&self) -> FrozenIter<T>
+    where
+        T: Clone,
+    {
+        FrozenIter::new(self.in_order_iter().cloned().collect())
+    }
+
+    /// Returns a reference to the element at the given **quantile** or `None` if tree is empty.
+    ///
+    /// The quantile `q` is converted into the rank `round(q * (size - 1))`, so `0.0` returns the
+    /// minimum, `0.5` the median and `1.0` the maximum. Quantiles outside of `[0.0, 1.0]` are
+    /// clamped into the range whereas `NaN` returns `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![30, 10, 50, 20, 40]);
+    ///
+    /// assert_eq!(bst.quantile(0.0), Some(&10));
+    /// assert_eq!(bst.quantile(0.5), Some(&30));
+    /// assert_eq!(bst.quantile(1.0), Some(&50));
+    /// assert_eq!(bst.quantile(2.0), Some(&50)); // Clamped to 1.0
+    /// ```
+    fn quantile(&self, q: f64) -> Option<&T> {
+        if self.is_empty() || q.is_nan() {
+            return None;
+        }
+
+        let rank = (q.clamp(0.0, 1.0) * (self.size - 1) as f64).round() as usize;
+        self.select(rank)
+    }
+
+    /// Returns an iterator over the elements **strictly greater** than the given value in
+    /// **ascending order.**
+    ///
+    /// The iterator starts at the successor of the value, which does not need to exist within
+    /// the tree, making it suitable for paging through the elements after the last one seen.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![3, 1, 4, 2, 5]);
+    ///
+    /// assert_eq!(bst.iter_after(&3).collect::<Vec<&i32>>(), vec![&4, &5]);
+    /// assert_eq!(bst.iter_after(&0).count(), 5);
+    /// assert_eq!(bst.iter_after(&5).next(), None);
+    /// ```
+    fn iter_after<'a>(&'a self, value: &T) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        Node::iterative_iter_after(&self.root, value)
+    }
+
+    /// Returns every element paired with the **fill ratio** of its subtree in ascending order.
+    ///
+    /// The fill ratio is the number of elements within the subtree divided by the number of
+    /// elements a perfect tree of the same height could hold, so `1.0` marks a perfectly dense
+    /// subtree (such as a leaf) and lower scores mark increasingly sparse or skewed subtrees.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from_iter(1..=3);
+    ///
+    /// assert_eq!(bst.subtree_balance_scores(), vec![(&1, 1.0), (&2, 2.0 / 3.0), (&3, 3.0 / 7.0)]);
+    /// ```
+    fn subtree_balance_scores(&self) -> Vec<(&T, f64)> {
+        Node::iterative_subtree_balance_scores(&self.root)
+    }
+
+    /// Returns the **expected number of nodes visited** by a successful search or `None` if tree
+    /// is empty.
+    ///
+    /// Every element is assumed to be searched for with the same probability. As finding a node
+    /// visits the node itself and all of its ancestors, this is the
+    /// [average search depth](SplayBST::average_search_depth()) plus one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// assert_eq!(bst.expected_search_visits(), None);
+    ///
+    /// bst.insert(1);
+    /// assert_eq!(bst.expected_search_visits(), Some(1.0));
+    /// ```
+    fn expected_search_visits(&self) -> Option<f64> {
+        match self.size {
+            0 => None,
+            size => {
+                Some((Node::iterative_internal_path_length(&self.root) + size) as f64 / size as f64)
+            }
+        }
+    }
+
+    /// Returns every `step`-th element by rank (ranks `0`, `step`, `2 * step`, ...) in
+    /// ascending order, giving an evenly spaced sample of the tree.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from_iter(1..=8);
+    ///
+    /// assert_eq!(bst.sample_by_step(3), vec![&1, &4, &7]);
+    /// assert_eq!(bst.sample_by_step(10), vec![&1]);
+    /// ```
+    fn sample_by_step(&self, step: usize) -> Vec<&T> {
+        assert!(step > 0, "step must be greater than zero");
+        self.in_order_iter().step_by(step).collect()
+    }
+
+    /// Returns `true` if `other` can be obtained from this tree through a sequence of
+    /// **rotations** only.
+    ///
+    /// Rotations preserve the in-order sequence of a binary search tree, so this holds exactly
+    /// when both trees are valid binary search trees containing the same elements, regardless
+    /// of their shapes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![1, 2, 3]);
+    /// let another_bst = SplayBST::from(vec![2, 1, 3]);
+    ///
+    /// assert!(bst.is_rotation_of(&another_bst));
+    /// assert!(!bst.is_rotation_of(&SplayBST::from(vec![1, 2, 4])));
+    /// ```
+    fn is_rotation_of(&self, other: &Self) -> bool {
+        // A strictly ascending in-order sequence is equivalent to a valid binary search tree
+        self.in_order_iter().is_sorted_by(|a, b| a < b)
+            && self.in_order_iter().eq(other.in_order_iter())
+    }
+
+    /// Inserts every value of the given iterator, [rebalancing](SplayBST::rebalance()) the tree
+    /// after every `rebalance_every` insertions.
+    ///
+    /// This prevents the tree from degenerating into a vine while loading long (for example
+    /// sorted) streams of elements. A `rebalance_every` of `0` never rebalances.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert_stream_autobalance(1..=7, 7);
+    ///
+    /// assert_eq!(bst.size(), 7);
+    /// assert_eq!(bst.height(), Some(2));
+    /// ```
+    fn insert_stream_autobalance<I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+        rebalance_every: usize,
+    ) {
+        for (count, value) in iter.into_iter().enumerate() {
+            self.insert(value);
+            if rebalance_every > 0 && (count + 1) % rebalance_every == 0 {
+                self.rebalance();
+            }
+        }
+    }
+
+    /// Returns a reference to the **positional median** of the tree or `None` if tree is empty.
+    ///
+    /// For an odd number of elements this is the middle element by rank, whereas for an even
+    /// number of elements the **lower** of both middle elements is returned, i.e. the element
+    /// at rank `(size - 1) / 2`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::from(vec![10, 40, 20]);
+    /// assert_eq!(bst.element_nearest_median(), Some(&20));
+    ///
+    /// bst.insert(30);
+    /// assert_eq!(bst.element_nearest_median(), Some(&20));
+    /// ```
+    fn element_nearest_median(&self) -> Option<&T> {
+        match self.size {
+            0 => None,
+            size => self.select((size - 1) / 2),
+        }
+    }
+
+    /// Returns the number of elements `(only_in_self, in_both, only_in_other)` when comparing
+    /// the elements of both trees.
+    ///
+    /// All three counts are computed within a single pass over the ascending elements of both
+    /// trees, without building any intermediate trees.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![1, 2, 3]);
+    /// let another_bst = SplayBST::from(vec![2, 3, 4]);
+    ///
+    /// assert_eq!(bst.diff_sizes(&another_bst), (1, 2, 1));
+    /// ```
+    fn diff_sizes(&self, other: &Self) -> (usize, usize, usize) {
+        Node::diff_sizes(self.in_order_iter(), other.in_order_iter())
+    }
+
+    /// Returns an [IterativeBST] with the **exact same structure** as this tree, where every
+    /// element has been replaced by the result of calling the given function on it.
+    ///
+    /// **The function is _not_ required to preserve the ordering of the elements, therefore the
+    /// returned tree may violate the ordering of a binary search tree.** Such a tree is only
+    /// suitable for inspecting its structure (for example through traversals), as searching
+    /// within it or modifying it gives unspecified results.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![1, 3, 2]);
+    /// let remapped_bst = bst.remap_shape(|value| value * -10);
+    ///
+    /// // The ordering is reversed but the structure is kept
+    /// assert_eq!(remapped_bst.pre_order_vec(), vec![&-20, &-10, &-30]);
+    /// ```
+    fn remap_shape<U: Ord, F: FnMut(&T) -> U>(&self, f: F) -> IterativeBST<U> {
+        IterativeBST::from_parts(Node::iterative_remap_shape(&self.root, f), self.size)
+    }
+
+    /// Consumes the tree and returns the `k` **smallest** elements in ascending order.
+    ///
+    /// The consuming in-order traversal stops as soon as `k` elements have been collected
+    /// instead of draining the whole tree. All elements are returned if `k` is greater than the
+    /// size of the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![5, 2, 8, 1, 9]);
+    ///
+    /// assert_eq!(bst.into_k_smallest(3), vec![1, 2, 5]);
+    /// ```
+    fn into_k_smallest(mut self, k: usize) -> Vec<T> {
+        Node::iterative_consume_k_smallest(self.root.take(), k)
+    }
+
+    /// Returns the **shared prefix** of the paths from the root to both given values, which ends
+    /// at their lowest common ancestor.
+    ///
+    /// An empty vector is returned if either value does not exist within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::from_iter(1..=7);
+    /// bst.rebalance();
+    ///
+    /// assert_eq!(bst.common_path(&1, &3), vec![&4, &2]);
+    /// assert_eq!(bst.common_path(&1, &7), vec![&4]);
+    /// assert!(bst.common_path(&1, &8).is_empty());
+    /// ```
+    fn common_path(&self, a: &T, b: &T) -> Vec<&T> {
+        if !self.contains(a) || !self.contains(b) {
+            return Vec::new();
+        }
+
+        Node::iterative_common_path(&self.root, a, b)
+    }
+
+    /// Returns the number of **completely filled** levels, counting downwards from the root.
+    ///
+    /// The level at depth `d` is full when it contains exactly `2^d` nodes and the count stops
+    /// at the first level that is not full. An empty tree has no full levels.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::from_iter(1..=4);
+    /// assert_eq!(bst.full_levels(), 1);
+    ///
+    /// bst.rebalance();
+    /// assert_eq!(bst.full_levels(), 2);
+    /// ```
+    fn full_levels(&self) -> usize {
+        Node::<T>::full_levels(Node::iterative_depth_histogram(&self.root))
+    }
+
+    /// Returns up to `radius` elements on **each side** of the given value by rank, alongside
+    /// the value itself, in ascending order.
+    ///
+    /// Fewer elements are returned on one side when the value is close to the minimum or the
+    /// maximum of the tree. An empty vector is returned if the value does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from_iter(1..=7);
+    ///
+    /// assert_eq!(bst.take_window_around(&4, 2), vec![&2, &3, &4, &5, &6]);
+    /// assert_eq!(bst.take_window_around(&1, 2), vec![&1, &2, &3]);
+    /// ```
+    fn take_window_around(&self, center: &T, radius: usize) -> Vec<&T> {
+        if !self.contains(center) {
+            return Vec::new();
+        }
+
+        let rank = Node::iterative_rank(&self.root, center);
+        let start = rank.saturating_sub(radius);
+        self.in_order_iter()
+            .skip(start)
+            .take((rank - start + 1).saturating_add(radius))
+            .collect()
+    }
+
+    /// Returns the number of **duplicate insertions** that have been rejected since the tree
+    /// was created.
+    ///
+    /// Every call to [insert()](SplayBST::insert()),
+    /// [insert_with_depth()](SplayBST::insert_with_depth()) or
+    /// [try_insert()](SplayBST::try_insert()) with a value that already exists increments this
+    /// counter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// bst.insert(1);
+    /// bst.insert(1); // Element is not inserted
+    ///
+    /// assert_eq!(bst.rejected_duplicates(), 1);
+    /// ```
+    fn rejected_duplicates(&self) -> usize {
+        self.rejected_duplicates
+    }
+
+    /// Returns the single `(pivot, direction)` rotation that would reduce the **internal path
+    /// length** of the tree the most, or `None` if no rotation would reduce it.
+    ///
+    /// This allows a tree to be improved incrementally, one [rotate](SplayBST::rotate()) at a
+    /// time, instead of through a full [rebalance](SplayBST::rebalance()).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::from_iter(1..=3);
+    /// let (pivot, rotation) = bst.best_single_rotation().unwrap();
+    /// bst.rotate(&pivot, rotation);
+    ///
+    /// assert_eq!(bst.height(), Some(1));
+    /// assert_eq!(bst.best_single_rotation(), None);
+    /// ```
+    fn best_single_rotation(&self) -> Option<(T, Rotation)>
+    where
+        T: Clone,
+    {
+        Node::iterative_best_single_rotation(&self.root)
+            .map(|(pivot, rotation)| (pivot.clone(), rotation))
+    }
+
+    /// Returns the elements grouped into one row per level, each paired with its **positional
+    /// slot** within that level.
+    ///
+    /// The level `l` has `2^l` slots, so that the children of the node at slot `i` are always
+    /// found at slots `2i` and `2i + 1` of the next row. Only the slots holding a node are
+    /// returned, in ascending order, therefore a gap between two consecutive slots marks missing
+    /// nodes and every row is only as long as the number of nodes on its level.
+    ///
+    /// `None` is returned if a slot does not fit into a `usize`, which can only happen for nodes
+    /// at a depth greater than `usize::BITS`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![1, 2, 4, 3]);
+    ///
+    /// assert_eq!(
+    ///     bst.render_rows(),
+    ///     Some(vec![
+    ///         vec![(0, &3)],
+    ///         vec![(0, &2), (1, &4)],
+    ///         vec![(0, &1)]
+    ///     ])
+    /// );
+    /// ```
+    fn render_rows(&self) -> Option<Vec<Vec<(usize, &T)>>> {
+        Node::iterative_render_rows(&self.root)
+    }
+
+    /// Returns a reference to the **in-order predecessor** of the given value, the largest
+    /// element strictly smaller than it, or `None` if there is no such element.
+    ///
+    /// The value itself does not need to exist within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![8, 3, 10, 1, 6]);
+    ///
+    /// assert_eq!(bst.predecessor(&8), Some(&6));
+    /// assert_eq!(bst.predecessor(&9), Some(&8));
+    /// assert_eq!(bst.predecessor(&1), None);
+    /// ```
+    fn predecessor(&self, value: &T) -> Option<&T> {
+        Node::iterative_predecessor(&self.root, value)
+    }
+
+    /// Returns a reference to the **in-order successor** of the given value, the smallest
+    /// element strictly greater than it, or `None` if there is no such element.
+    ///
+    /// The value itself does not need to exist within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![8, 3, 10, 1, 6]);
+    ///
+    /// assert_eq!(bst.successor(&6), Some(&8));
+    /// assert_eq!(bst.successor(&4), Some(&6));
+    /// assert_eq!(bst.successor(&10), None);
+    /// ```
+    fn successor(&self, value: &T) -> Option<&T> {
+        Node::iterative_successor(&self.root, value)
+    }
+
+    /// Consumes the tree and returns the elements **strictly greater** than the threshold in
+    /// ascending order.
+    ///
+    /// Subtrees that only hold elements smaller than or equal to the threshold are dropped
+    /// during the consuming in-order traversal without being collected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![5, 2, 8, 1, 9]);
+    ///
+    /// assert_eq!(bst.into_greater_than(&2), vec![5, 8, 9]);
+    /// ```
+    fn into_greater_than(mut self, threshold: &T) -> Vec<T> {
+        Node::iterative_consume_greater_than(self.root.take(), threshold)
+    }
+
+    /// Returns the size of the **largest subtree** directly below the root as a fraction of the
+    /// size of the tree.
+    ///
+    /// A value close to `0.5` means removing the root splits the tree evenly, whereas a value
+    /// close to `1.0` means the tree hangs off to one side. Empty and single element trees
+    /// return `0.0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::from_iter(1..=4);
+    /// assert_eq!(bst.max_subtree_fraction(), 0.75);
+    ///
+    /// bst.rebalance();
+    /// assert_eq!(bst.max_subtree_fraction(), 0.5);
+    /// ```
+    fn max_subtree_fraction(&self) -> f64 {
+        match self.size {
+            0 => 0.0,
+            size => Node::iterative_largest_child_subtree(&self.root) as f64 / size as f64,
+        }
+    }
+
+    /// Returns a **multi-line diagram** of the tree, connecting every element to its children
+    /// through `├──` and `└──` branches.
+    ///
+    /// Children are listed left before right, a node with a single child connects it through
+    /// `└──`. An empty tree returns an empty string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![1, 2, 4, 3]);
+    ///
+    /// assert_eq!(bst.pretty_print(), "3\n├── 2\n│   └── 1\n└── 4");
+    /// ```
+    fn pretty_print(&self) -> String
+    where
+        T: Display,
+    {
+        Node::pretty_print(&self.root)
+    }
+
+    /// Returns the **ancestors** of the given value, ordered from its parent up to the root, or
+    /// `None` if the value does not exist within the tree.
+    ///
+    /// The root has no ancestors, so an empty vector is returned for it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::from_iter(1..=7);
+    /// bst.rebalance();
+    ///
+    /// assert_eq!(bst.ancestors(&5), Some(vec![&6, &4]));
+    /// assert_eq!(bst.ancestors(&4), Some(vec![]));
+    /// assert_eq!(bst.ancestors(&8), None);
+    /// ```
+    fn ancestors(&self, value: &T) -> Option<Vec<&T>> {
+        Node::iterative_ancestors(&self.root, value)
+    }
+
+    /// Returns the **descendants** of the given value in ascending order, every element within
+    /// the subtree rooted at it apart from the value itself, or `None` if the value does not
+    /// exist within the tree.
+    ///
+    /// A leaf has no descendants, so an empty vector is returned for it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::from_iter(1..=7);
+    /// bst.rebalance();
+    ///
+    /// assert_eq!(bst.descendants(&6), Some(vec![&5, &7]));
+    /// assert_eq!(bst.descendants(&7), Some(vec![]));
+    /// assert_eq!(bst.descendants(&8), None);
+    /// ```
+    fn descendants(&self, value: &T) -> Option<Vec<&T>> {
+        Node::iterative_descendants(&self.root, value)
+    }
+
+    /// Returns the **rank** of the given value, the number of elements strictly smaller than it.
+    ///
+    /// The value itself does not need to exist within the tree. As every node keeps track of the
+    /// size of its subtree, this only walks a single path down from the root.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![10, 20, 30]);
+    ///
+    /// assert_eq!(bst.rank(&10), 0);
+    /// assert_eq!(bst.rank(&25), 2);
+    /// assert_eq!(bst.rank(&99), 3);
+    /// ```
+    fn rank(&self, value: &T) -> usize {
+        Node::iterative_rank(&self.root, value)
+    }
+
+    /// Returns a reference to the `k`-th **smallest** element (starting from `0`) or `None` if
+    /// `k` is not smaller than the size of the tree.
+    ///
+    /// As every node keeps track of the size of its subtree, this only walks a single path down
+    /// from the root.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![10, 30, 20]);
+    ///
+    /// assert_eq!(bst.select(0), Some(&10));
+    /// assert_eq!(bst.select(2), Some(&30));
+    /// assert_eq!(bst.select(3), None);
+    /// ```
+    fn select(&self, k: usize) -> Option<&T> {
+        Node::iterative_select(&self.root, k)
+    }
+
+    /// Rebalances only the **subtree** rooted at the given value, leaving the rest of the tree
+    /// untouched. Returns `true` if the value exists within the tree.
+    ///
+    /// This is cheaper than [rebalance](SplayBST::rebalance()) when the imbalance is known to be
+    /// local to a single subtree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::from_iter(1..=7);
+    /// bst.rebalance();
+    /// bst.extend(vec![8, 9, 10]);
+    /// assert_eq!(bst.height(), Some(7));
+    ///
+    /// // Splaying moved the last inserted elements above the balanced part
+    /// assert!(bst.rebalance_subtree(&9));
+    /// assert_eq!(bst.height(), Some(4));
+    /// assert!(!bst.rebalance_subtree(&11));
+    /// ```
+    fn rebalance_subtree(&mut self, value: &T) -> bool {
+        Node::iterative_rebalance_subtree(&mut self.root, value)
+    }
+
+    /// Returns an iterator over the elements within the inclusive range `[low, high]` in
+    /// **ascending order.**
+    ///
+    /// Subtrees lying entirely outside the range are never visited. Neither bound needs to exist
+    /// within the tree and an empty iterator is returned if `low` is greater than `high`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from_iter(1..=10);
+    ///
+    /// assert_eq!(bst.range(&3, &7).collect::<Vec<&i32>>(), vec![&3, &4, &5, &6, &7]);
+    /// assert_eq!(bst.range(&0, &2).collect::<Vec<&i32>>(), vec![&1, &2]);
+    /// assert_eq!(bst.range(&7, &3).next(), None);
+    /// ```
+    fn range(&self, low: &T, high: &T) -> IntoIter<&T> {
+        Node::iterative_range_vec(&self.root, low, high).into_iter()
+    }
+
+    /// Returns the number of elements falling into each of the **buckets** separated by the
+    /// given sorted boundaries.
+    ///
+    /// For `k` boundaries `k + 1` counts are returned, one for each of the half-open buckets
+    /// `(-inf, b0), [b0, b1), ..., [b(k-1), +inf)`. The counts are gathered within a single
+    /// in-order sweep of the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from_iter(1..=10);
+    ///
+    /// assert_eq!(bst.bucket_counts(&[3, 8]), vec![2, 5, 3]);
+    /// assert_eq!(bst.bucket_counts(&[]), vec![10]);
+    /// ```
+    fn bucket_counts(&self, boundaries: &[T]) -> Vec<usize> {
+        Node::bucket_counts(self.in_order_iter(), boundaries)
+    }
+
+    /// Removes and returns every element satisfying the given predicate in **ascending order**,
+    /// similar to `Vec::extract_if`.
+    ///
+    /// The tree is drained, split by the predicate and rebuilt as a **balanced** tree from the
+    /// remaining elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::from_iter(1..=7);
+    ///
+    /// assert_eq!(bst.extract_if(|value| value % 3 == 0), vec![3, 6]);
+    /// assert_eq!(bst.asc_order_vec(), vec![&1, &2, &4, &5, &7]);
+    /// assert_eq!(bst.size(), 5);
+    /// ```
+    fn extract_if<F: FnMut(&T) -> bool>(&mut self, predicate: F) -> Vec<T> {
+        let elements = Node::iterative_consume_in_order_vec(self.root.take());
+        let (extracted, remaining): (Vec<T>, Vec<T>) = elements.into_iter().partition(predicate);
+        self.size = remaining.len();
+        self.root = Node::build_balanced(&mut remaining.into_iter(), self.size);
+        extracted
+    }
+
+    /// Returns a reference to the element equal to the given value, inserting the value first
+    /// if it does not exist within the tree yet.
+    ///
+    /// The element is splayed to the root, whether it already existed or was just inserted.
+    /// Unlike [insert()](SplayBST::insert()), finding an existing element does not count towards
+    /// [rejected_duplicates()](SplayBST::rejected_duplicates()).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::from(vec![(2, 'a')]);
+    ///
+    /// assert_eq!(bst.get_or_insert((2, 'a')), &(2, 'a'));
+    /// assert_eq!(bst.get_or_insert((1, 'b')), &(1, 'b'));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn get_or_insert(&mut self, value: T) -> &T {
+        let (element, inserted) = Node::splay_get_or_insert(&mut self.root, value);
+        if inserted {
+            self.size += 1;
+        }
+        element
+    }
+
+    /// Returns the **expected number of nodes visited** by a successful search when the elements
+    /// are accessed according to a Zipf distribution with exponent `s`, or `None` if the tree is
+    /// empty or `s` is `NaN`.
+    ///
+    /// The element of rank `r` (starting from `1` for the minimum) is accessed with a weight of
+    /// `1 / r^s`, so smaller elements are accessed more often. A lower cost means frequently
+    /// accessed elements sit closer to the root, whereas an exponent of `0` weights every element
+    /// equally and matches [expected_search_visits()](SplayBST::expected_search_visits()).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// assert_eq!(bst.zipf_expected_cost(1.0), None);
+    ///
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// // Splaying moved the last inserted element to the root
+    /// assert_eq!(bst.zipf_expected_cost(1.0), Some(5.0 / 3.0));
+    /// ```
+    fn zipf_expected_cost(&self, s: f64) -> Option<f64> {
+        let depths = Node::iterative_in_order_depths(&self.root);
+        Node::<T>::zipf_expected_cost(depths, s)
+    }
+
+    /// Returns a new **balanced** tree containing clones of the elements that exist within
+    /// **either** tree.
+    ///
+    /// As both trees are traversed in ascending order, the elements are merged in a single pass
+    /// without any comparisons against the new tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![1, 2, 3]);
+    /// let other_bst = SplayBST::from(vec![3, 4, 5]);
+    ///
+    /// let union = bst.union(&other_bst);
+    /// assert_eq!(union.asc_order_vec(), vec![&1, &2, &3, &4, &5]);
+    /// assert_eq!(union.height(), Some(2));
+    /// ```
+    fn union(&self, other: &Self) -> SplayBST<T>
+    where
+        T: Clone,
+    {
+        let elements: Vec<&T> =
+            Node::merge_iter(self.in_order_iter(), other.in_order_iter()).collect();
+        let size = elements.len();
+        SplayBST {
+            root: Node::build_balanced(&mut elements.into_iter().cloned(), size),
+            size,
+            rejected_duplicates: 0,
+        }
+    }
+
+    /// Returns a new **balanced** tree containing clones of the elements that exist within
+    /// **both** trees.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![1, 2, 3]);
+    /// let other_bst = SplayBST::from(vec![3, 4, 5]);
+    ///
+    /// assert_eq!(bst.intersection(&other_bst).asc_order_vec(), vec![&3]);
+    /// ```
+    fn intersection(&self, other: &Self) -> SplayBST<T>
+    where
+        T: Clone,
+    {
+        let elements: Vec<&T> = Node::zip_compare(self.in_order_iter(), other.in_order_iter())
+            .into_iter()
+            .filter_map(|pair| match pair {
+                (Some(value), Some(_)) => Some(value),
+                _ => None,
+            })
+            .collect();
+        let size = elements.len();
+        SplayBST {
+            root: Node::build_balanced(&mut elements.into_iter().cloned(), size),
+            size,
+            rejected_duplicates: 0,
+        }
+    }
+
+    /// Returns a new **balanced** tree containing clones of the elements that exist within this
+    /// tree but **not** within `other`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![1, 2, 3]);
+    /// let other_bst = SplayBST::from(vec![3, 4, 5]);
+    ///
+    /// assert_eq!(bst.difference(&other_bst).asc_order_vec(), vec![&1, &2]);
+    /// assert_eq!(other_bst.difference(&bst).asc_order_vec(), vec![&4, &5]);
+    /// ```
+    fn difference(&self, other: &Self) -> SplayBST<T>
+    where
+        T: Clone,
+    {
+        let elements: Vec<&T> = Node::zip_compare(self.in_order_iter(), other.in_order_iter())
+            .into_iter()
+            .filter_map(|pair| match pair {
+                (Some(value), None) => Some(value),
+                _ => None,
+            })
+            .collect();
+        let size = elements.len();
+        SplayBST {
+            root: Node::build_balanced(&mut elements.into_iter().cloned(), size),
+            size,
+            rejected_duplicates: 0,
+        }
+    }
+
+    /// Returns a compact **histogram** of the number of nodes on every level of the tree.
+    ///
+    /// Every level is rendered on its own line, starting from the root, as a bar containing one
+    /// `#` per node on that level. An empty string is returned for an empty tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let bst = SplayBST::from(vec![1, 2, 4, 3]);
+    ///
+    /// assert_eq!(bst.level_width_bars(), "#\n##\n#");
+    /// ```
+    fn level_width_bars(&self) -> String {
+        let histogram = Node::iterative_depth_histogram(&self.root);
+        Node::<T>::level_width_bars(histogram)
+    }
+
+    /// Returns `true` if the tree is **height-balanced**, i.e. the heights of the left and right
+    /// subtrees of every node differ by at most `1`.
+    ///
+    /// An empty tree is always balanced.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::from(vec![1, 3, 2]);
+    /// assert!(bst.is_balanced());
+    ///
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// assert!(!bst.is_balanced());
+    /// ```
+    fn is_balanced(&self) -> bool {
+        self.balance_factor_histogram()
+            .keys()
+            .all(|balance_factor| balance_factor.abs() <= 1)
+    }
+
+    /// Rebuilds the tree into a **height-balanced** shape, keeping the same elements.
+    ///
+    /// Unlike [rebalance](SplayBST::rebalance()), the nodes are drained into a sorted list and
+    /// the tree is rebuilt by recursively picking the median of every sublist as the root of its
+    /// subtree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::from_iter(1..=7);
+    /// assert_eq!(bst.height(), Some(6));
+    ///
+    /// bst.balance();
+    /// assert_eq!(bst.height(), Some(2));
+    /// assert!(bst.is_balanced());
+    /// ```
+    fn balance(&mut self) {
+        let elements = Node::iterative_consume_in_order_vec(self.root.take());
+        self.root = Node::build_balanced(&mut elements.into_iter(), self.size);
+    }
+
+    /// Returns the **diameter** of the tree, the number of edges on the longest path between any
+    /// two nodes.
+    ///
+    /// The longest path does not necessarily pass through the root. An empty tree or a tree with
+    /// a single element has a diameter of `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// assert_eq!(bst.diameter(), 0);
+    ///
+    /// bst.insert(1);
+    /// assert_eq!(bst.diameter(), 0);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(3);
+    /// assert_eq!(bst.diameter(), 2);
+    /// ```
+    fn diameter(&self) -> usize {
+        Node::iterative_height_and_diameter(&self.root).1
+    }
+
+    /// Returns a reference to the **lowest common ancestor** of both given values or `None` if
+    /// either value does not exist within the tree.
+    ///
+    /// The lowest common ancestor is the deepest element that has both values within its
+    /// subtree, where an element counts as being within its own subtree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::from_iter(1..=7);
+    /// bst.rebalance();
+    ///
+    /// assert_eq!(bst.lowest_common_ancestor(&1, &3), Some(&2));
+    /// assert_eq!(bst.lowest_common_ancestor(&3, &5), Some(&4));
+    /// assert_eq!(bst.lowest_common_ancestor(&6, &7), Some(&6));
+    /// assert_eq!(bst.lowest_common_ancestor(&1, &8), None);
+    /// ```
+    fn lowest_common_ancestor(&self, a: &T, b: &T) -> Option<&T> {
+        if !self.contains(a) || !self.contains(b) {
+            return None;
+        }
+
+        Node::iterative_lowest_common_ancestor(&self.root, a, b)
+    }
+
+    /// Removes every given value from the tree and returns the number of elements that were
+    /// actually removed.
+    ///
+    /// Values that do not exist within the tree are ignored and a value given more than once is
+    /// only removed (and counted) once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::from_iter(1..=5);
+    ///
+    /// assert_eq!(bst.remove_all(&[1, 3, 3, 6]), 2);
+    /// assert_eq!(bst.asc_order_vec(), vec![&2, &4, &5]);
+    /// ```
+    fn remove_all(&mut self, values: &[T]) -> usize {
+        let removed = values
+            .iter()
+            .filter(|value| Node::splay_remove(&mut self.root, value).is_ok())
+            .count();
+        self.size -= removed;
+        removed
+    }
+
+    /// Inserts the given value into the tree, returning `Ok(())` if it was newly inserted.
+    ///
+    /// Unlike [insert()](SplayBST::insert()), an `Err` handing the value back is returned if an
+    /// equal element already exists, so the value can be reused. Rejected values count towards
+    /// [rejected_duplicates()](SplayBST::rejected_duplicates()).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    ///
+    /// assert_eq!(bst.try_insert(1), Ok(()));
+    /// assert_eq!(bst.try_insert(1), Err(1));
+    /// assert_eq!(bst.size(), 1);
+    /// ```
+    fn try_insert(&mut self, value: T) -> Result<(), T> {
+        match Node::splay_insert(&mut self.root, value) {
+            Ok(_) => {
+                self.size += 1;
+                Ok(())
+            }
+            Err(value) => {
+                self.rejected_duplicates += 1;
+                Err(value)
+            }
+        }
+    }
+
+    /// Returns the **width** of the tree, the largest number of nodes on any single level.
+    ///
+    /// An empty tree has a width of `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::new();
+    /// assert_eq!(bst.width(), 0);
+    ///
+    /// bst.insert(1);
+    /// assert_eq!(bst.width(), 1);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(3);
+    /// assert_eq!(bst.width(), 1);
+    /// ```
+    fn width(&self) -> usize {
+        Node::iterative_width(&self.root)
+    }
+
+    /// Returns a lazy iterator over **mutable** references to the elements in ascending order.
+    ///
+    /// # Important
+    ///
+    /// The elements are modified in place without being moved within the tree, so it is the
+    /// responsibility of the caller to keep them in the same relative order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, SplayBST};
+    ///
+    /// let mut bst = SplayBST::from(vec![(1, 'a'), (2, 'b')]);
+    /// for (_, letter) in bst.in_order_iter_mut() {
+    ///     *letter = letter.to_ascii_uppercase();
+    /// }
+    ///
+    /// assert_eq!(bst.asc_order_vec(), vec![&(1, 'A'), &(2, 'B')]);
+    /// ```
+    fn in_order_iter_mut(&mut self) -> InOrderIterMut<'_, T> {
+        InOrderIterMut::new(&mut self.root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::{BinarySearchTree, Rotation, SplayBST};
+
+    #[test]
+    fn successfully_insert_elements_into_bst() {
+        let mut bst = SplayBST::new();
+        bst.insert(0);
+        bst.insert(1);
+        bst.insert(1);
+        bst.insert(2);
+        bst.insert(-20);
+
+        assert_eq!(bst.size(), 4);
+        assert_eq!(bst.asc_order_vec(), vec![&-20, &0, &1, &2]);
+        assert_eq!(bst.pre_order_vec()[0], &-20);
+        assert!(bst.verify_size());
+    }
+
+    #[test]
+    fn successfully_match_reference_set() {
+        let mut bst = SplayBST::new();
+        let mut expected = BTreeSet::new();
+
+        for (step, value) in [15, 3, 42, 8, 23, 4, 16, 1, 99, 50, 7, 8, 42, 0]
+            .into_iter()
+            .enumerate()
+        {
+            if step % 3 == 2 {
+                bst.remove(&value);
+                expected.remove(&value);
+            } else {
+                bst.insert(value);
+                expected.insert(value);
+            }
+
+            assert_eq!(bst.size(), expected.len());
+            assert_eq!(bst.asc_order_vec(), expected.iter().collect::<Vec<&i32>>());
+        }
+
+        for value in 0..=100 {
+            assert_eq!(bst.contains(&value), expected.contains(&value));
+            assert_eq!(bst.find_splay(&value), expected.get(&value));
+            assert_eq!(bst.asc_order_vec(), expected.iter().collect::<Vec<&i32>>());
+        }
+        assert!(bst.verify_size());
+    }
+
+    #[test]
+    fn successfully_splay_accessed_element_to_root() {
+        let mut bst = SplayBST::from_iter(1..=10);
+        assert_eq!(bst.pre_order_vec()[0], &10);
+
+        for _ in 0..3 {
+            assert_eq!(bst.find_splay(&4), Some(&4));
+            assert_eq!(bst.pre_order_vec()[0], &4);
+        }
+
+        *bst.retrieve_as_mut(&7).unwrap() = 7;
+        assert_eq!(bst.pre_order_vec()[0], &7);
+        assert_eq!(
+            bst.asc_order_vec(),
+            (1..=10).collect::<Vec<i32>>().iter().collect::<Vec<&i32>>()
+        );
+    }
+
+    #[test]
+    fn successfully_remove_elements_from_bst() {
+        let mut bst = SplayBST::from_iter(1..=10);
+
+        bst.remove(&5);
+        bst.remove(&1);
+        bst.remove(&10);
+        bst.remove(&42);
+
+        assert_eq!(bst.size(), 7);
+        assert_eq!(bst.asc_order_vec(), vec![&2, &3, &4, &6, &7, &8, &9]);
+        assert!(bst.verify_size());
+
+        for value in 1..=10 {
+            bst.remove(&value);
+        }
+        assert!(bst.is_empty());
+        assert_eq!(bst.height(), None);
+    }
+
+    #[test]
+    fn successfully_get_k_smallest_from_degenerate_tree_without_overflowing() {
+        // Inserting descending elements leaves every previous root as the right child
+        let bst = SplayBST::from_iter((0..100_000).rev());
+
+        assert_eq!(bst.into_k_smallest(1), vec![0]);
+    }
+
+    #[test]
+    fn successfully_get_greater_than_from_degenerate_tree_without_overflowing() {
+        // Inserting ascending elements leaves every previous root as the left child
+        let bst = SplayBST::from_iter(0..100_000);
+
+        assert!(bst.into_greater_than(&99_999).is_empty());
+    }
+
+    #[test]
+    fn successfully_splay_deepest_element_of_degenerate_tree_without_overflowing() {
+        let mut bst = SplayBST::from_iter(0..100_000);
+        assert_eq!(bst.height(), Some(99_999));
+
+        assert_eq!(bst.find_splay(&0), Some(&0));
+        assert_eq!(bst.pre_order_vec()[0], &0);
+        assert!(bst.height().unwrap() < 99_999);
+        assert!(bst.verify_size());
+        assert_eq!(bst.select(50_000), Some(&50_000));
+    }
+
+    #[test]
+    fn successfully_zig_zig_on_insert() {
+        let mut bst = SplayBST::from(vec![10, 20, 40, 30]);
+        assert_eq!(bst.pre_order_vec(), vec![&30, &20, &10, &40]);
+
+        // 10 is reached through two left children, so the grandparent rotates before the parent
+        bst.insert(5);
+
+        assert_eq!(bst.pre_order_vec(), vec![&5, &10, &20, &30, &40]);
+        assert!(bst.verify_size());
+    }
+
+    #[test]
+    fn successfully_zig_zag_on_insert() {
+        let mut bst = SplayBST::from(vec![10, 20, 30]);
+        assert!(bst.rotate(&20, Rotation::Right));
+        assert_eq!(bst.pre_order_vec(), vec![&30, &10, &20]);
+
+        // 20 is the right child of a left child, so it rotates above its parent and grandparent
+        bst.insert(15);
+
+        assert_eq!(bst.pre_order_vec(), vec![&15, &10, &20, &30]);
+        assert!(bst.verify_size());
+    }
+
+    #[test]
+    fn successfully_zig_zig_on_remove() {
+        let mut bst = SplayBST::from_iter(1..=4);
+        assert_eq!(bst.pre_order_vec(), vec![&4, &3, &2, &1]);
+
+        bst.remove(&2);
+
+        assert_eq!(bst.pre_order_vec(), vec![&1, &3, &4]);
+        assert_eq!(bst.size(), 3);
+        assert!(bst.verify_size());
+    }
+
+    #[test]
+    fn successfully_zig_zag_on_remove() {
+        let mut bst = SplayBST::from(vec![10, 20, 30]);
+        assert!(bst.rotate(&20, Rotation::Right));
+
+        bst.remove(&20);
+
+        assert_eq!(bst.pre_order_vec(), vec![&10, &30]);
+        assert_eq!(bst.size(), 2);
+        assert!(bst.verify_size());
+    }
+
+    #[test]
+    fn successfully_remove_root_with_two_children() {
+        let mut bst = SplayBST::from(vec![1, 2, 4, 3]);
+        assert_eq!(bst.pre_order_vec(), vec![&3, &2, &1, &4]);
+
+        // The largest element on the left is splayed to the top and adopts the right subtree
+        bst.remove(&3);
+
+        assert_eq!(bst.pre_order_vec(), vec![&2, &1, &4]);
+        assert_eq!(bst.size(), 3);
+        assert!(bst.verify_size());
+    }
+
+    #[test]
+    fn successfully_get_rank_and_select_after_splaying() {
+        let mut bst = SplayBST::from_iter(1..=10);
+
+        bst.find_splay(&4);
+        bst.insert(0);
+        bst.remove(&7);
+        bst.find_splay(&9);
+        assert_eq!(bst.pre_order_vec()[0], &9);
+        assert!(bst.verify_size());
+
+        assert_eq!(bst.rank(&0), 0);
+        assert_eq!(bst.rank(&7), 7);
+        assert_eq!(bst.rank(&11), 10);
+        assert_eq!(bst.select(0), Some(&0));
+        assert_eq!(bst.select(6), Some(&6));
+        assert_eq!(bst.select(7), Some(&8));
+        assert_eq!(bst.select(9), Some(&10));
+        assert_eq!(bst.select(10), None);
+    }
+
+    #[test]
+    fn select_stays_correct_while_splaying() {
+        let mut bst = SplayBST::new();
+        let mut expected = BTreeSet::new();
+
+        for (step, value) in [15, 3, 42, 8, 23, 4, 16, 1, 99, 50, 7, 8, 42, 0]
+            .into_iter()
+            .enumerate()
+        {
+            if step % 3 == 2 {
+                bst.remove(&value);
+                expected.remove(&value);
+            } else {
+                bst.insert(value);
+                expected.insert(value);
+            }
+            bst.find_splay(&16);
+
+            for (k, value) in expected.iter().enumerate() {
+                assert_eq!(bst.select(k), Some(value));
+                assert_eq!(bst.rank(value), k);
+            }
+        }
+    }
+}